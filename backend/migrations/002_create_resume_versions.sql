@@ -0,0 +1,54 @@
+-- 简历版本历史
+
+-- 1. 简历表（每份简历指向当前生效的版本）
+CREATE TABLE IF NOT EXISTS resumes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    title VARCHAR(200) NOT NULL,
+    current_version_id UUID,
+    created_at TIMESTAMP DEFAULT NOW(),
+    updated_at TIMESTAMP DEFAULT NOW()
+);
+
+-- 2. 简历版本表（只追加，不可修改）
+CREATE TABLE IF NOT EXISTS resume_versions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    resume_id UUID NOT NULL REFERENCES resumes(id) ON DELETE CASCADE,
+    parent_id UUID REFERENCES resume_versions(id),
+    version_number INTEGER NOT NULL,
+
+    -- 来源: manual_edit, optimize, restore
+    source VARCHAR(20) NOT NULL,
+    content TEXT NOT NULL,
+    note TEXT,
+    created_at TIMESTAMP DEFAULT NOW(),
+
+    UNIQUE (resume_id, version_number)
+);
+
+ALTER TABLE resumes
+    ADD CONSTRAINT fk_resumes_current_version
+    FOREIGN KEY (current_version_id) REFERENCES resume_versions(id);
+
+-- 索引
+CREATE INDEX idx_resumes_user_id ON resumes(user_id);
+CREATE INDEX idx_resume_versions_resume_id ON resume_versions(resume_id);
+
+-- 更新触发器
+CREATE TRIGGER update_resumes_updated_at
+    BEFORE UPDATE ON resumes
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at();
+
+-- 版本不可变：禁止修改已保存的版本
+CREATE OR REPLACE FUNCTION forbid_resume_version_update()
+RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'resume_versions are immutable';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER resume_versions_immutable
+    BEFORE UPDATE ON resume_versions
+    FOR EACH ROW
+    EXECUTE FUNCTION forbid_resume_version_update();
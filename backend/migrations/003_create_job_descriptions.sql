@@ -0,0 +1,26 @@
+-- 职位描述库
+
+CREATE TABLE IF NOT EXISTS job_descriptions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    raw_text TEXT NOT NULL,
+
+    -- 解析结果
+    company VARCHAR(200),
+    title VARCHAR(200),
+    location VARCHAR(100),
+    seniority VARCHAR(20),  -- intern, junior, mid, senior, staff, principal, lead, manager
+    salary_min BIGINT,
+    salary_max BIGINT,
+    salary_currency VARCHAR(3),
+    salary_period VARCHAR(10),  -- month, year
+    required_skills JSONB NOT NULL DEFAULT '[]',
+    preferred_skills JSONB NOT NULL DEFAULT '[]',
+    responsibilities JSONB NOT NULL DEFAULT '[]',
+
+    created_at TIMESTAMP DEFAULT NOW()
+);
+
+-- 索引
+CREATE INDEX idx_job_descriptions_user_id ON job_descriptions(user_id);
+CREATE INDEX idx_job_descriptions_created_at ON job_descriptions(created_at);
@@ -0,0 +1,46 @@
+-- 求职申请跟踪
+
+-- 1. 申请表
+CREATE TABLE IF NOT EXISTS applications (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    resume_version_id UUID REFERENCES resume_versions(id) ON DELETE SET NULL,
+    job_description_id UUID REFERENCES job_descriptions(id) ON DELETE SET NULL,
+    cover_letter TEXT,
+
+    -- 状态: saved, applied, interviewing, offer, rejected
+    status VARCHAR(20) NOT NULL DEFAULT 'saved',
+    status_changed_at TIMESTAMP DEFAULT NOW(),
+    applied_at TIMESTAMP,
+
+    notes TEXT,
+
+    -- 提醒
+    remind_at TIMESTAMP,
+    reminder_note TEXT,
+
+    created_at TIMESTAMP DEFAULT NOW(),
+    updated_at TIMESTAMP DEFAULT NOW()
+);
+
+-- 2. 状态变更历史
+CREATE TABLE IF NOT EXISTS application_status_history (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    application_id UUID NOT NULL REFERENCES applications(id) ON DELETE CASCADE,
+    from_status VARCHAR(20),
+    to_status VARCHAR(20) NOT NULL,
+    note TEXT,
+    changed_at TIMESTAMP DEFAULT NOW()
+);
+
+-- 索引
+CREATE INDEX idx_applications_user_id ON applications(user_id);
+CREATE INDEX idx_applications_status ON applications(user_id, status);
+CREATE INDEX idx_applications_remind_at ON applications(remind_at);
+CREATE INDEX idx_application_status_history_application_id ON application_status_history(application_id);
+
+-- 更新触发器
+CREATE TRIGGER update_applications_updated_at
+    BEFORE UPDATE ON applications
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at();
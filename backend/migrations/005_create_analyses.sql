@@ -0,0 +1,28 @@
+-- 分析结果持久化
+
+CREATE TABLE IF NOT EXISTS analyses (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+
+    -- 类型: optimize, score, cover_letter, keywords
+    kind VARCHAR(20) NOT NULL,
+
+    -- 输入（引用已保存的简历版本和 JD）
+    resume_version_id UUID REFERENCES resume_versions(id) ON DELETE SET NULL,
+    job_description_id UUID REFERENCES job_descriptions(id) ON DELETE SET NULL,
+
+    -- 输出
+    output JSONB NOT NULL,
+
+    -- 运行信息
+    provider VARCHAR(50) NOT NULL,
+    model VARCHAR(100) NOT NULL,
+    latency_ms INTEGER NOT NULL,
+    credits_charged INTEGER NOT NULL DEFAULT 0,
+
+    created_at TIMESTAMP DEFAULT NOW()
+);
+
+-- 索引
+CREATE INDEX idx_analyses_user_id_created_at ON analyses(user_id, created_at DESC);
+CREATE INDEX idx_analyses_kind ON analyses(kind);
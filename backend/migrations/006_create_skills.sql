@@ -0,0 +1,49 @@
+-- 技能词典（覆盖内置的 data/skills.json）
+
+-- 1. 技能分类
+CREATE TABLE IF NOT EXISTS skill_categories (
+    key VARCHAR(50) PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    -- 是否为技术类（非技术类不计入 JD 技能要求）
+    technical BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP DEFAULT NOW(),
+    updated_at TIMESTAMP DEFAULT NOW()
+);
+
+-- 2. 技能（按名称覆盖内置技能，disabled 可屏蔽内置技能）
+CREATE TABLE IF NOT EXISTS skills (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name VARCHAR(100) NOT NULL,
+    category VARCHAR(50) NOT NULL,
+    -- 父技能名称，如 React -> JavaScript
+    parent VARCHAR(100),
+    importance SMALLINT NOT NULL DEFAULT 60 CHECK (importance BETWEEN 0 AND 100),
+    case_sensitive BOOLEAN NOT NULL DEFAULT FALSE,
+    disabled BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP DEFAULT NOW(),
+    updated_at TIMESTAMP DEFAULT NOW()
+);
+
+-- 3. 技能别名（中英文写法、缩写）
+CREATE TABLE IF NOT EXISTS skill_aliases (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    skill_id UUID NOT NULL REFERENCES skills(id) ON DELETE CASCADE,
+    alias VARCHAR(100) NOT NULL,
+    created_at TIMESTAMP DEFAULT NOW()
+);
+
+-- 索引
+CREATE UNIQUE INDEX idx_skills_name ON skills(LOWER(name));
+CREATE UNIQUE INDEX idx_skill_aliases_alias ON skill_aliases(LOWER(alias));
+CREATE INDEX idx_skill_aliases_skill_id ON skill_aliases(skill_id);
+
+-- 更新触发器
+CREATE TRIGGER update_skill_categories_updated_at
+    BEFORE UPDATE ON skill_categories
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at();
+
+CREATE TRIGGER update_skills_updated_at
+    BEFORE UPDATE ON skills
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at();
@@ -0,0 +1,3 @@
+-- 管理员标记（用于维护技能词典等后台数据）
+
+ALTER TABLE users ADD COLUMN IF NOT EXISTS is_admin BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,26 @@
+-- 批量筛选简历（招聘方上传简历压缩包，按一个 JD 排序）
+
+CREATE TABLE IF NOT EXISTS screening_jobs (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    job_description_id UUID REFERENCES job_descriptions(id) ON DELETE SET NULL,
+    job_title VARCHAR(200),
+
+    -- 状态: pending, running, completed, failed
+    status VARCHAR(20) NOT NULL DEFAULT 'pending',
+
+    -- 进度
+    total INTEGER NOT NULL DEFAULT 0,
+    processed INTEGER NOT NULL DEFAULT 0,
+
+    -- 结果（按排名排序的候选人）
+    candidates JSONB NOT NULL DEFAULT '[]',
+    error TEXT,
+
+    created_at TIMESTAMP DEFAULT NOW(),
+    updated_at TIMESTAMP DEFAULT NOW(),
+    completed_at TIMESTAMP
+);
+
+-- 索引
+CREATE INDEX idx_screening_jobs_user_id_created_at ON screening_jobs(user_id, created_at DESC);
@@ -1,5 +1,6 @@
 use axum::{
-    routing::{get, post},
+    extract::DefaultBodyLimit,
+    routing::{delete, get, patch, post},
     Router,
 };
 use sqlx::postgres::PgPoolOptions;
@@ -35,6 +36,19 @@ async fn main() -> anyhow::Result<()> {
 
     tracing::info!("✅ Database pool created");
 
+    // 上次进程未完成的筛选任务无法继续
+    match services::screening::fail_interrupted_jobs(&db_pool).await {
+        Ok(0) => {}
+        Ok(count) => tracing::warn!("⚠️ Marked {} interrupted screening jobs as failed", count),
+        Err(e) => tracing::warn!("⚠️ Failed to clean up interrupted screening jobs: {}", e),
+    }
+
+    // 加载技能词典（数据库覆盖内置词典）
+    match services::taxonomy::reload_taxonomy(&db_pool).await {
+        Ok(taxonomy) => tracing::info!("✅ Skill taxonomy loaded ({} skills)", taxonomy.skills().len()),
+        Err(e) => tracing::warn!("⚠️ Failed to load skill overrides, using bundled taxonomy: {}", e),
+    }
+
     let redis_url = std::env::var("REDIS_URL")
         .expect("REDIS_URL must be set");
     
@@ -47,8 +61,98 @@ async fn main() -> anyhow::Result<()> {
         // Resume routes
         .route("/api/resume/optimize", post(routes::resume::optimize))
         .route("/api/resume/score", post(routes::resume::score))
+        .route(
+            "/api/resume/score-file",
+            post(routes::resume::score_file).layer(DefaultBodyLimit::max(routes::resume::MAX_UPLOAD_BYTES)),
+        )
+        .route("/api/resume/score-batch", post(routes::resume::score_batch))
+        .route("/api/resume/relevance", post(routes::resume::relevance))
+        .route("/api/resume/timeline", post(routes::resume::timeline))
+        .route("/api/resume/bullets", post(routes::resume::analyze_bullets))
+        .route("/api/resume/rewrite-bullets", post(routes::resume::rewrite_bullets))
+        .route("/api/resume/proofread", post(routes::resume::proofread))
+        .route("/api/resume/lint", post(routes::resume::lint))
+        .route("/api/resume/redact", post(routes::resume::redact))
+        .route("/api/resume/restore", post(routes::resume::restore))
+        .route("/api/resume/translate", post(routes::resume::translate))
         .route("/api/resume/cover-letter", post(routes::resume::generate_cover_letter))
         .route("/api/resume/keywords", post(routes::resume::extract_keywords))
+        // Resume version routes
+        .route("/api/resume", post(routes::versions::create_resume).get(routes::versions::list_resumes))
+        .route("/api/resume/:id", get(routes::versions::get_resume))
+        .route(
+            "/api/resume/:id/versions",
+            post(routes::versions::save_version).get(routes::versions::list_versions),
+        )
+        .route("/api/resume/:id/versions/:version_id", get(routes::versions::get_version))
+        .route(
+            "/api/resume/:id/versions/:version_id/restore",
+            post(routes::versions::restore_version),
+        )
+        .route("/api/resume/:id/diff", get(routes::versions::diff_versions))
+        .route("/api/resume/:id/gaps", get(routes::resume::skill_gaps))
+        // Job description routes
+        .route(
+            "/api/job-descriptions",
+            post(routes::job_descriptions::create).get(routes::job_descriptions::list),
+        )
+        .route("/api/job-descriptions/parse", post(routes::job_descriptions::parse))
+        .route(
+            "/api/job-descriptions/:id",
+            get(routes::job_descriptions::get).delete(routes::job_descriptions::delete),
+        )
+        // Application tracker routes
+        .route(
+            "/api/applications",
+            post(routes::applications::create).get(routes::applications::list),
+        )
+        .route("/api/applications/stats", get(routes::applications::stats))
+        .route(
+            "/api/applications/:id",
+            get(routes::applications::get)
+                .patch(routes::applications::update)
+                .delete(routes::applications::delete),
+        )
+        .route("/api/applications/:id/status", post(routes::applications::update_status))
+        // Analysis history routes
+        .route("/api/analyses", get(routes::analyses::list))
+        .route("/api/analyses/:id", get(routes::analyses::get))
+        // Recruiter screening routes
+        .route(
+            "/api/screenings",
+            post(routes::screenings::create)
+                .layer(DefaultBodyLimit::max(routes::screenings::MAX_ARCHIVE_BYTES))
+                .get(routes::screenings::list),
+        )
+        .route("/api/screenings/:id", get(routes::screenings::get))
+        .route("/api/screenings/:id/export", get(routes::screenings::export))
+        // Admin routes
+        .route(
+            "/api/admin/skills",
+            post(routes::admin::create_skill).get(routes::admin::list_skills),
+        )
+        .route("/api/admin/skills/export", get(routes::admin::export_dictionary))
+        .route("/api/admin/skills/import", post(routes::admin::import_dictionary_file))
+        .route("/api/admin/skills/reload", post(routes::admin::reload_dictionary))
+        .route(
+            "/api/admin/skills/:id",
+            get(routes::admin::get_skill)
+                .patch(routes::admin::update_skill)
+                .delete(routes::admin::delete_skill),
+        )
+        .route(
+            "/api/admin/skills/:id/aliases",
+            post(routes::admin::add_alias).get(routes::admin::list_aliases),
+        )
+        .route("/api/admin/skills/:id/aliases/:alias_id", delete(routes::admin::delete_alias))
+        .route(
+            "/api/admin/skill-categories",
+            post(routes::admin::create_category).get(routes::admin::list_categories),
+        )
+        .route(
+            "/api/admin/skill-categories/:key",
+            patch(routes::admin::update_category).delete(routes::admin::delete_category),
+        )
         .layer(
             CorsLayer::new()
                 .allow_origin(Any)
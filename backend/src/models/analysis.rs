@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::NaiveDateTime;
+
+// ========== 分析记录 ==========
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnalysisKind {
+    Optimize,
+    Score,
+    CoverLetter,
+    Keywords,
+    Relevance,
+    Timeline,
+    Bullets,
+    Rewrite,
+    Proofread,
+    Lint,
+    Gaps,
+    BatchScore,
+    Translate,
+}
+
+impl AnalysisKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnalysisKind::Optimize => "optimize",
+            AnalysisKind::Score => "score",
+            AnalysisKind::CoverLetter => "cover_letter",
+            AnalysisKind::Keywords => "keywords",
+            AnalysisKind::Relevance => "relevance",
+            AnalysisKind::Timeline => "timeline",
+            AnalysisKind::Bullets => "bullets",
+            AnalysisKind::Rewrite => "rewrite",
+            AnalysisKind::Proofread => "proofread",
+            AnalysisKind::Lint => "lint",
+            AnalysisKind::Gaps => "gaps",
+            AnalysisKind::BatchScore => "batch_score",
+            AnalysisKind::Translate => "translate",
+        }
+    }
+
+    /// 每次运行消耗的次数
+    pub fn credits(&self) -> i32 {
+        match self {
+            AnalysisKind::Keywords
+            | AnalysisKind::Relevance
+            | AnalysisKind::Timeline
+            | AnalysisKind::Bullets
+            | AnalysisKind::Proofread
+            | AnalysisKind::Lint
+            | AnalysisKind::Gaps => 0,
+            _ => 1,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct Analysis {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub kind: String,
+    pub resume_version_id: Option<Uuid>,
+    pub job_description_id: Option<Uuid>,
+    pub output: Json<serde_json::Value>,
+    pub provider: String,
+    pub model: String,
+    pub latency_ms: i32,
+    pub credits_charged: i32,
+    pub created_at: NaiveDateTime,
+}
+
+/// 列表中不返回完整输出
+#[derive(Debug, Serialize, FromRow)]
+pub struct AnalysisSummary {
+    pub id: Uuid,
+    pub kind: String,
+    pub resume_version_id: Option<Uuid>,
+    pub job_description_id: Option<Uuid>,
+    pub provider: String,
+    pub model: String,
+    pub latency_ms: i32,
+    pub credits_charged: i32,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AnalysisListQuery {
+    pub kind: Option<AnalysisKind>,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaginatedResponse<T> {
+    pub items: Vec<T>,
+    pub page: u32,
+    pub per_page: u32,
+    pub total: i64,
+}
@@ -0,0 +1,143 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::NaiveDateTime;
+
+// ========== 求职申请 ==========
+
+/// 申请状态流转：saved → applied → interviewing → offer / rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApplicationStatus {
+    Saved,
+    Applied,
+    Interviewing,
+    Offer,
+    Rejected,
+}
+
+impl ApplicationStatus {
+    pub const ALL: [ApplicationStatus; 5] = [
+        ApplicationStatus::Saved,
+        ApplicationStatus::Applied,
+        ApplicationStatus::Interviewing,
+        ApplicationStatus::Offer,
+        ApplicationStatus::Rejected,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApplicationStatus::Saved => "saved",
+            ApplicationStatus::Applied => "applied",
+            ApplicationStatus::Interviewing => "interviewing",
+            ApplicationStatus::Offer => "offer",
+            ApplicationStatus::Rejected => "rejected",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct Application {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub resume_version_id: Option<Uuid>,
+    pub job_description_id: Option<Uuid>,
+    pub cover_letter: Option<String>,
+    pub status: String,
+    pub status_changed_at: NaiveDateTime,
+    pub applied_at: Option<NaiveDateTime>,
+    pub notes: Option<String>,
+    pub remind_at: Option<NaiveDateTime>,
+    pub reminder_note: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    /// 关联 JD 的公司和职位，便于列表展示
+    pub job_company: Option<String>,
+    pub job_title: Option<String>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct ApplicationStatusChange {
+    pub id: Uuid,
+    pub application_id: Uuid,
+    pub from_status: Option<String>,
+    pub to_status: String,
+    pub note: Option<String>,
+    pub changed_at: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ApplicationDetailResponse {
+    pub application: Application,
+    pub history: Vec<ApplicationStatusChange>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateApplicationRequest {
+    pub resume_version_id: Option<Uuid>,
+    pub job_description_id: Option<Uuid>,
+    pub cover_letter: Option<String>,
+    /// 未提供求职信时，根据简历版本和 JD 自动生成
+    #[serde(default)]
+    pub generate_cover_letter: bool,
+    pub status: Option<ApplicationStatus>,
+    pub applied_at: Option<NaiveDateTime>,
+    pub notes: Option<String>,
+    pub remind_at: Option<NaiveDateTime>,
+    pub reminder_note: Option<String>,
+}
+
+/// 只更新提供的字段；notes、remind_at、reminder_note 传 null 时清空
+#[derive(Debug, Deserialize)]
+pub struct UpdateApplicationRequest {
+    pub resume_version_id: Option<Uuid>,
+    pub job_description_id: Option<Uuid>,
+    pub cover_letter: Option<String>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub notes: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub remind_at: Option<Option<NaiveDateTime>>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub reminder_note: Option<Option<String>>,
+}
+
+/// 区分未提供的字段（None）和显式传 null 的字段（Some(None)）
+fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateApplicationStatusRequest {
+    pub status: ApplicationStatus,
+    pub note: Option<String>,
+    /// 默认为当前时间
+    pub changed_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApplicationListQuery {
+    pub status: Option<ApplicationStatus>,
+    pub job_description_id: Option<Uuid>,
+    /// 按公司、职位或备注搜索
+    pub q: Option<String>,
+    /// 只返回提醒时间已到的申请
+    #[serde(default)]
+    pub reminders_due: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StageCount {
+    pub status: ApplicationStatus,
+    pub count: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ApplicationStatsResponse {
+    pub total: i64,
+    pub stages: Vec<StageCount>,
+    pub reminders_due: i64,
+}
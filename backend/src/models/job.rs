@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::NaiveDateTime;
+
+// ========== 职位描述库 ==========
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct JobDescription {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub raw_text: String,
+    pub company: Option<String>,
+    pub title: Option<String>,
+    pub location: Option<String>,
+    pub seniority: Option<String>,
+    pub salary_min: Option<i64>,
+    pub salary_max: Option<i64>,
+    pub salary_currency: Option<String>,
+    pub salary_period: Option<String>,
+    pub required_skills: Json<Vec<String>>,
+    pub preferred_skills: Json<Vec<String>>,
+    pub responsibilities: Json<Vec<String>>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SaveJobDescriptionRequest {
+    pub text: String,
+    /// 手动指定的公司名，优先于解析结果
+    #[serde(default)]
+    pub company: Option<String>,
+    /// 手动指定的职位名，优先于解析结果
+    #[serde(default)]
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ParseJobDescriptionRequest {
+    pub text: String,
+}
+
+// ========== JD 解析结果 ==========
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ParsedJobDescription {
+    pub company: Option<String>,
+    pub title: Option<String>,
+    pub location: Option<String>,
+    pub seniority: Option<String>,
+    pub salary: Option<SalaryRange>,
+    pub required_skills: Vec<String>,
+    pub preferred_skills: Vec<String>,
+    pub responsibilities: Vec<String>,
+    /// 逐条分类的要求（技能、年限、学历）
+    pub requirements: Vec<JdRequirement>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SalaryRange {
+    pub min: i64,
+    pub max: i64,
+    /// CNY, USD 等
+    pub currency: String,
+    /// month 或 year
+    pub period: String,
+}
+
+// ========== JD 要求 ==========
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RequirementPriority {
+    Must,
+    Preferred,
+}
+
+impl RequirementPriority {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RequirementPriority::Must => "必备",
+            RequirementPriority::Preferred => "加分",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RequirementKind {
+    Skill,
+    /// 总工作年限
+    Experience,
+    Education,
+}
+
+/// 学历，由低到高
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EducationLevel {
+    HighSchool,
+    Associate,
+    Bachelor,
+    Master,
+    Doctorate,
+}
+
+impl EducationLevel {
+    pub fn label(&self) -> &'static str {
+        match self {
+            EducationLevel::HighSchool => "高中/中专",
+            EducationLevel::Associate => "大专",
+            EducationLevel::Bachelor => "本科",
+            EducationLevel::Master => "硕士",
+            EducationLevel::Doctorate => "博士",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JdRequirement {
+    pub kind: RequirementKind,
+    pub priority: RequirementPriority,
+    /// 技能的标准名称
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skill: Option<String>,
+    /// 年限要求；kind 为 skill 时表示该技能的使用年限
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_years: Option<u32>,
+    /// 最低学历
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub education: Option<EducationLevel>,
+    /// 相对权重：必备高于加分，技能按词典重要程度调整
+    pub weight: f32,
+    /// 要求所在的 JD 原文
+    pub source: String,
+}
@@ -1,7 +1,19 @@
 pub mod user;
 pub mod resume;
+pub mod version;
+pub mod job;
+pub mod application;
+pub mod analysis;
+pub mod skill;
+pub mod screening;
 
 pub use user::*;
 pub use resume::*;
+pub use version::*;
+pub use job::*;
+pub use application::*;
+pub use analysis::*;
+pub use skill::*;
+pub use screening::*;
 
 pub use user::*;
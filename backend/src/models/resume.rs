@@ -1,26 +1,193 @@
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{JdRequirement, RequirementPriority};
 
 // ========== 简历优化 ==========
 
 #[derive(Debug, Deserialize)]
 pub struct OptimizeResumeRequest {
+    #[serde(default)]
     pub resume: String,
+    #[serde(default)]
     pub job_description: String,
+    /// 引用已保存的简历版本，代替 resume 文本
+    #[serde(default)]
+    pub resume_version_id: Option<Uuid>,
+    /// 引用已保存的 JD，代替 job_description 文本
+    #[serde(default)]
+    pub job_description_id: Option<Uuid>,
+    /// 指定后，优化结果会作为该简历的新版本保存
+    #[serde(default)]
+    pub resume_id: Option<Uuid>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct OptimizeResumeResponse {
     pub optimized_resume: String,
-    pub changes: Vec<String>,
+    /// 对简历所做的修改，按应用顺序排列
+    pub changes: Vec<ResumeChange>,
+    /// 需要本人补充或确认的建议，如缺少的技能
+    pub suggestions: Vec<String>,
+    /// 优化结果中原简历没有的事实，已去掉
+    pub violations: Vec<GuardViolation>,
+    /// 相关度得分取整，兼容旧客户端
     pub match_score: u8,
+    pub relevance: RelevanceReport,
+    /// JD 要求逐条核对结果，按权重从高到低
+    pub requirement_gaps: Vec<RequirementGap>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analysis_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    /// 调整小节顺序
+    ReorderSections,
+    /// 调整小节内经历的顺序
+    ReorderEntries,
+    RewriteSummary,
+    /// 补充简历已体现但未写明的 JD 关键词
+    AddKeywords,
+    RewriteBullet,
+}
+
+/// 一处修改；新增内容的 original 为空
+#[derive(Debug, Clone, Serialize)]
+pub struct ResumeChange {
+    pub kind: ChangeKind,
+    /// 所在小节的标题
+    pub section: String,
+    pub original: Option<String>,
+    pub replacement: String,
+    pub reason: String,
+}
+
+// ========== 事实校验 ==========
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityKind {
+    Company,
+    /// 职位名称
+    Title,
+    Date,
+    Degree,
+    Certification,
+    Number,
+}
+
+/// 生成内容中出现、原简历没有的事实
+#[derive(Debug, Clone, Serialize)]
+pub struct GuardViolation {
+    pub kind: EntityKind,
+    pub value: String,
+    /// 在生成内容中的行号（从 1 开始）
+    pub line: usize,
+    /// 已从生成内容中去掉：数字替换为占位，其他整行删除
+    pub stripped: bool,
+}
+
+// ========== 隐私脱敏 ==========
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PiiKind {
+    Name,
+    Phone,
+    Email,
+    /// 身份证号
+    IdCard,
+    Address,
+    /// 出生日期和年龄
+    Birthdate,
+    /// 照片、头像及图片文件
+    Photo,
+    Link,
+}
+
+impl PiiKind {
+    pub const ALL: [PiiKind; 8] = [
+        PiiKind::Name,
+        PiiKind::Phone,
+        PiiKind::Email,
+        PiiKind::IdCard,
+        PiiKind::Address,
+        PiiKind::Birthdate,
+        PiiKind::Photo,
+        PiiKind::Link,
+    ];
+
+    /// 替换标记的前缀，如 [PHONE_1]
+    pub fn token_prefix(&self) -> &'static str {
+        match self {
+            PiiKind::Name => "NAME",
+            PiiKind::Phone => "PHONE",
+            PiiKind::Email => "EMAIL",
+            PiiKind::IdCard => "ID_CARD",
+            PiiKind::Address => "ADDRESS",
+            PiiKind::Birthdate => "BIRTHDATE",
+            PiiKind::Photo => "PHOTO",
+            PiiKind::Link => "LINK",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RedactRequest {
+    #[serde(default)]
+    pub resume: String,
+    #[serde(default)]
+    pub resume_version_id: Option<Uuid>,
+    /// 需要脱敏的类型，为空时全部脱敏
+    #[serde(default)]
+    pub kinds: Vec<PiiKind>,
+}
+
+/// 一个替换标记及其原文，同一原文使用同一标记
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedactionEntry {
+    pub token: String,
+    pub kind: PiiKind,
+    pub value: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RedactResponse {
+    /// 脱敏后的简历
+    pub text: String,
+    /// 还原所需的映射，仅返回给调用方，不在服务端保存
+    pub mapping: Vec<RedactionEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RestoreRequest {
+    pub text: String,
+    pub mapping: Vec<RedactionEntry>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RestoreResponse {
+    pub text: String,
+    /// 文本中出现但映射里没有的标记
+    pub unresolved: Vec<String>,
 }
 
 // ========== 简历评分 ==========
 
 #[derive(Debug, Deserialize)]
 pub struct ScoreResumeRequest {
+    #[serde(default)]
     pub resume: String,
+    #[serde(default)]
     pub job_description: String,
+    #[serde(default)]
+    pub resume_version_id: Option<Uuid>,
+    #[serde(default)]
+    pub job_description_id: Option<Uuid>,
 }
 
 #[derive(Debug, Serialize)]
@@ -28,38 +195,866 @@ pub struct ScoreResumeResponse {
     pub overall_score: u8,
     pub categories: Vec<ScoreCategory>,
     pub suggestions: Vec<String>,
+    pub timeline: ExperienceTimeline,
+    /// 逐条经历要点的分析，用于高亮问题行
+    pub bullets: Vec<BulletAnalysis>,
+    /// 标准小节和联系方式的检查结果
+    pub completeness: CompletenessReport,
+    /// 简历与职位的职级推断及依据
+    pub seniority: SeniorityReport,
+    /// 上传文件时的 ATS 解析检查结果
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ats: Option<AtsReport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analysis_id: Option<Uuid>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct ScoreCategory {
+    /// 稳定的英文标识，如 keyword_coverage
+    pub key: String,
     pub name: String,
     pub score: u8,
+    /// 在总分中的权重
+    pub weight: f32,
     pub feedback: String,
+    /// 支撑该得分的简历片段
+    pub evidence: Vec<EvidenceSpan>,
+    pub suggestions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EvidenceSpan {
+    /// 行号（从 1 开始）
+    pub line: usize,
+    /// 在简历全文中的字符偏移
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+// ========== 多职位对比 ==========
+
+#[derive(Debug, Deserialize)]
+pub struct BatchScoreRequest {
+    #[serde(default)]
+    pub resume: String,
+    #[serde(default)]
+    pub resume_version_id: Option<Uuid>,
+    /// 已保存的 JD（需登录）
+    #[serde(default)]
+    pub job_description_ids: Vec<Uuid>,
+    /// 直接提交的 JD 文本
+    #[serde(default)]
+    pub job_descriptions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchCategoryScore {
+    pub key: String,
+    pub score: u8,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchScoreItem {
+    /// 按总分排序后的名次（从 1 开始）
+    pub rank: usize,
+    /// 已保存 JD 的 ID；直接提交的 JD 为空
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_description_id: Option<Uuid>,
+    /// 在 job_descriptions 中的下标；已保存的 JD 为空
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+    pub title: Option<String>,
+    pub company: Option<String>,
+    pub overall_score: u8,
+    pub categories: Vec<BatchCategoryScore>,
+    /// 简历中缺少的 JD 关键词，按权重排序
+    pub missing_keywords: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SkillImpactGain {
+    /// 对应 results 中的名次
+    pub rank: usize,
+    pub gain: u8,
+}
+
+/// 补上某个技能后各职位总分的变化
+#[derive(Debug, Clone, Serialize)]
+pub struct SkillImpact {
+    pub skill: String,
+    /// 总分会提高的职位数
+    pub jobs_improved: usize,
+    pub total_gain: u32,
+    pub gains: Vec<SkillImpactGain>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchScoreReport {
+    pub results: Vec<BatchScoreItem>,
+    /// 同时提高最多职位得分的技能，按总提升排序
+    pub skill_impact: Vec<SkillImpact>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchScoreResponse {
+    #[serde(flatten)]
+    pub report: BatchScoreReport,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analysis_id: Option<Uuid>,
+}
+
+// ========== 结构完整度 ==========
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContactKind {
+    Email,
+    Phone,
+    Linkedin,
+    Github,
+}
+
+/// 识别到的一项联系方式
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactItem {
+    pub kind: ContactKind,
+    pub value: String,
+    pub valid: bool,
+    /// 格式问题及修改方法
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    pub span: EvidenceSpan,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SectionCheck {
+    /// 稳定的英文标识，如 experience
+    pub key: String,
+    pub name: String,
+    pub present: bool,
+    /// 小节标题或识别到的内容
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<EvidenceSpan>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CompletenessReport {
+    /// 0-100，按小节权重计算，联系方式按有效的邮箱和手机号计分
+    pub score: u8,
+    pub sections: Vec<SectionCheck>,
+    pub contacts: Vec<ContactItem>,
+    /// 缺失或格式有误的项目及修改方法
+    pub issues: Vec<String>,
+}
+
+// ========== 职级推断 ==========
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SenioritySignalKind {
+    /// 职位名称中的职级词
+    Title,
+    /// 工作年限或 JD 的年限要求
+    Years,
+    /// 主导、负责、参与等表示职责范围的词
+    Scope,
+    /// 带领或管理的团队人数
+    TeamSize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SenioritySignal {
+    pub kind: SenioritySignalKind,
+    /// 该依据对应的职级
+    pub level: String,
+    pub detail: String,
+    /// 简历依据为简历中的位置，JD 依据为 JD 中的位置
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<EvidenceSpan>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SeniorityAssessment {
+    /// intern / junior / mid / senior / lead / staff / manager / principal；依据不足时为空
+    pub level: Option<String>,
+    pub signals: Vec<SenioritySignal>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SeniorityReport {
+    pub resume: SeniorityAssessment,
+    pub job: SeniorityAssessment,
+    /// 职位要求比简历高出的级数，负数表示简历高于要求
+    pub gap: i32,
+    pub suggestions: Vec<String>,
+}
+
+// ========== ATS 解析检查 ==========
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResumeFileKind {
+    Pdf,
+    Docx,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AtsIssueKind {
+    /// 加密或无法打开
+    Encrypted,
+    /// 文字在图片中（扫描件、截图）
+    ImageText,
+    /// 提取出的文字为乱码
+    UnreadableText,
+    Table,
+    MultiColumn,
+    TextBox,
+    /// 联系方式写在页眉页脚中
+    HeaderFooterContact,
+    UnusualFont,
+    NonStandardHeading,
+    ExcessiveLength,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AtsIssue {
+    pub kind: AtsIssueKind,
+    pub severity: FindingSeverity,
+    /// 问题说明及修改方法
+    pub message: String,
+    /// 所在页（从 1 开始），DOCX 不区分页
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    /// 相关内容，如字体名称、小节标题
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AtsReport {
+    pub file_kind: ResumeFileKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pages: Option<u32>,
+    /// 可提取的文字数（不含空白）
+    pub characters: usize,
+    /// 0-100，按问题严重程度扣分
+    pub score: u8,
+    pub issues: Vec<AtsIssue>,
+}
+
+// ========== 相关度 ==========
+
+#[derive(Debug, Deserialize)]
+pub struct RelevanceRequest {
+    #[serde(default)]
+    pub resume: String,
+    #[serde(default)]
+    pub job_description: String,
+    #[serde(default)]
+    pub resume_version_id: Option<Uuid>,
+    #[serde(default)]
+    pub job_description_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RelevanceReport {
+    /// 0-100，按词项权重加权的覆盖程度
+    pub score: f32,
+    /// 对得分贡献最大的词
+    pub top_terms: Vec<RelevanceTerm>,
+    /// 权重最高但简历中缺失的词
+    pub missing_terms: Vec<RelevanceTerm>,
+    /// 计算 IDF 使用的 JD 数量
+    pub corpus_size: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RelevanceTerm {
+    pub term: String,
+    /// 在 JD 全部词项权重中的占比
+    pub weight: f32,
+    /// 对总分的贡献（分）
+    pub contribution: f32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RelevanceResponse {
+    #[serde(flatten)]
+    pub report: RelevanceReport,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analysis_id: Option<Uuid>,
+}
+
+// ========== 要求差距 ==========
+
+/// 简历对照单条 JD 要求的结果
+#[derive(Debug, Clone, Serialize)]
+pub struct RequirementGap {
+    #[serde(flatten)]
+    pub requirement: JdRequirement,
+    pub met: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<EvidenceSpan>,
+    /// 差距说明，如 “要求 5 年以上，简历约 3.0 年”
+    pub detail: String,
+}
+
+// ========== 技能差距 ==========
+
+#[derive(Debug, Deserialize)]
+pub struct SkillGapQuery {
+    /// 按该 JD 的要求权重排序；为空时综合所有已保存的 JD
+    #[serde(default)]
+    pub job_description_id: Option<Uuid>,
+    /// 为空时使用简历的当前版本
+    #[serde(default)]
+    pub version_id: Option<Uuid>,
+}
+
+/// 学习资源目录中的一项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LearningResource {
+    pub skill: String,
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// docs / course / book / practice
+    #[serde(default)]
+    pub kind: String,
+    /// 预计学习时长（小时）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hours: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SkillGap {
+    pub skill: String,
+    pub category: String,
+    pub priority: RequirementPriority,
+    /// 在目标 JD（未指定时为所有已保存 JD）中的最高要求权重
+    pub importance: f32,
+    /// 要求该技能的已保存 JD 数
+    pub job_count: usize,
+    /// 要求该技能的已保存 JD 占比
+    pub frequency: f32,
+    /// 排序得分，综合权重和出现频率
+    pub score: f32,
+    pub detail: String,
+    pub resources: Vec<LearningResource>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SkillGapResponse {
+    pub resume_id: Uuid,
+    pub version_id: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_description_id: Option<Uuid>,
+    /// 参与统计的已保存 JD 数
+    pub saved_jobs: usize,
+    pub gaps: Vec<SkillGap>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analysis_id: Option<Uuid>,
+}
+
+// ========== 经历时间线 ==========
+
+#[derive(Debug, Deserialize)]
+pub struct TimelineRequest {
+    #[serde(default)]
+    pub resume: String,
+    #[serde(default)]
+    pub resume_version_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ExperienceTimeline {
+    /// 按开始时间排序的工作经历
+    pub entries: Vec<TimelineEntry>,
+    /// 总工作年限，重叠部分只计一次
+    pub total_years: f32,
+    /// 各技能在经历中的使用年限，从高到低
+    pub skill_years: Vec<SkillYears>,
+    pub gaps: Vec<TimelineGap>,
+    pub overlaps: Vec<TimelineOverlap>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TimelineEntry {
+    /// 日期所在行去掉日期后的内容，如公司和职位
+    pub title: String,
+    /// YYYY-MM
+    pub start: String,
+    /// YYYY-MM；至今时为空
+    pub end: Option<String>,
+    pub current: bool,
+    pub months: u32,
+    /// 该段经历描述中出现的技能
+    pub skills: Vec<String>,
+    pub evidence: EvidenceSpan,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SkillYears {
+    pub skill: String,
+    pub years: f32,
+}
+
+/// 两段经历之间的空档
+#[derive(Debug, Clone, Serialize)]
+pub struct TimelineGap {
+    pub start: String,
+    pub end: String,
+    pub months: u32,
+}
+
+/// 两段时间重叠的经历
+#[derive(Debug, Clone, Serialize)]
+pub struct TimelineOverlap {
+    pub first: String,
+    pub second: String,
+    pub start: String,
+    pub end: String,
+    pub months: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TimelineResponse {
+    #[serde(flatten)]
+    pub timeline: ExperienceTimeline,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analysis_id: Option<Uuid>,
+}
+
+// ========== 经历要点分析 ==========
+
+#[derive(Debug, Deserialize)]
+pub struct BulletAnalysisRequest {
+    #[serde(default)]
+    pub resume: String,
+    #[serde(default)]
+    pub resume_version_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricKind {
+    Percentage,
+    Money,
+    /// 倍数，如 3 倍、10x
+    Multiplier,
+    /// 时长，如 800ms、2 周
+    Duration,
+    /// 数量和规模，如 5 人、10 万用户
+    Count,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerbStrength {
+    /// 体现主导和结果的动词，如 主导、设计、Led、Built
+    Strong,
+    /// 只体现参与的动词，如 负责、参与、Helped
+    Weak,
+    /// 未以动词开头
+    Missing,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulletIssue {
+    MissingMetric,
+    WeakVerb,
+    MissingVerb,
+    PassiveVoice,
+    VaguePhrase,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BulletMetric {
+    pub kind: MetricKind,
+    pub span: EvidenceSpan,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BulletFinding {
+    pub issue: BulletIssue,
+    pub severity: FindingSeverity,
+    pub message: String,
+    /// 问题所在的片段；缺少内容类的问题为空
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span: Option<EvidenceSpan>,
+}
+
+/// 单条经历要点的分析结果
+#[derive(Debug, Clone, Serialize)]
+pub struct BulletAnalysis {
+    pub line: EvidenceSpan,
+    pub metrics: Vec<BulletMetric>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verb: Option<String>,
+    pub verb_strength: VerbStrength,
+    pub findings: Vec<BulletFinding>,
+    /// 各问题中最严重的级别；没有问题时为空
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<FindingSeverity>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BulletReport {
+    pub bullets: Vec<BulletAnalysis>,
+    /// 包含量化数据的要点数
+    pub quantified: usize,
+    /// 以有力动词开头的要点数
+    pub strong_verbs: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BulletAnalysisResponse {
+    #[serde(flatten)]
+    pub report: BulletReport,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analysis_id: Option<Uuid>,
+}
+
+// ========== 拼写与语法检查 ==========
+
+#[derive(Debug, Deserialize)]
+pub struct ProofreadRequest {
+    #[serde(default)]
+    pub resume: String,
+    #[serde(default)]
+    pub resume_version_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProofreadKind {
+    /// 英文拼写错误
+    Spelling,
+    /// 中文错别字，包括 “的地得” 误用
+    Confusable,
+    /// 英文时态不一致
+    Tense,
+    /// 全角半角标点混用、重复标点
+    Punctuation,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProofreadFinding {
+    pub kind: ProofreadKind,
+    pub severity: FindingSeverity,
+    pub message: String,
+    pub span: EvidenceSpan,
+    /// 建议替换为的内容
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProofreadResponse {
+    pub findings: Vec<ProofreadFinding>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analysis_id: Option<Uuid>,
+}
+
+// ========== 格式一致性检查 ==========
+
+/// 日期写法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DateStyle {
+    /// 2019.03
+    Dotted,
+    /// 2019-03
+    Dashed,
+    /// 2019/03
+    Slashed,
+    /// 03/2019
+    MonthFirst,
+    /// 2019年3月
+    Chinese,
+    /// Mar 2019
+    MonthName,
+}
+
+/// 要点句末标点
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrailingPunctuation {
+    /// 以句号结尾
+    Always,
+    /// 不加句末标点
+    Never,
+}
+
+/// 检查规则；写法类选项为空时以简历中最常用的写法为准
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct LintRules {
+    pub date_format: bool,
+    pub date_style: Option<DateStyle>,
+    pub bullet_style: bool,
+    /// 统一使用的项目符号，如 "•"
+    pub bullet_marker: Option<char>,
+    /// 技术名词大小写，如 javascript → JavaScript
+    pub tech_capitalization: bool,
+    pub trailing_punctuation: bool,
+    pub trailing_style: Option<TrailingPunctuation>,
+    /// 中文内容用全角标点，英文内容用半角标点
+    pub punctuation_width: bool,
+}
+
+impl Default for LintRules {
+    fn default() -> Self {
+        Self {
+            date_format: true,
+            date_style: None,
+            bullet_style: true,
+            bullet_marker: None,
+            tech_capitalization: true,
+            trailing_punctuation: true,
+            trailing_style: None,
+            punctuation_width: true,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LintRequest {
+    #[serde(default)]
+    pub resume: String,
+    #[serde(default)]
+    pub resume_version_id: Option<Uuid>,
+    #[serde(default)]
+    pub rules: LintRules,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LintRule {
+    DateFormat,
+    BulletStyle,
+    TechCapitalization,
+    TrailingPunctuation,
+    PunctuationWidth,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LintFinding {
+    pub rule: LintRule,
+    pub message: String,
+    pub span: EvidenceSpan,
+    /// 自动修复时替换为的内容，为空表示删除
+    pub replacement: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LintReport {
+    pub findings: Vec<LintFinding>,
+    /// 按规则统一格式后的全文
+    pub fixed: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LintResponse {
+    #[serde(flatten)]
+    pub report: LintReport,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analysis_id: Option<Uuid>,
+}
+
+// ========== 经历要点改写 ==========
+
+#[derive(Debug, Deserialize)]
+pub struct RewriteBulletsRequest {
+    #[serde(default)]
+    pub resume: String,
+    #[serde(default)]
+    pub job_description: String,
+    #[serde(default)]
+    pub resume_version_id: Option<Uuid>,
+    #[serde(default)]
+    pub job_description_id: Option<Uuid>,
+    /// 要改写的行号；为空时改写存在问题的要点
+    #[serde(default)]
+    pub lines: Vec<usize>,
+    /// 每条要点的候选数，默认 3
+    #[serde(default)]
+    pub alternatives: Option<usize>,
+}
+
+/// STAR 结构：情境、任务、行动、结果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StarParts {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub situation: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task: Option<String>,
+    #[serde(default)]
+    pub action: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RewriteAlternative {
+    pub text: String,
+    pub star: StarParts,
+    /// 改写后新出现的 JD 关键词
+    pub keywords_added: Vec<String>,
+    /// 需要本人补充的占位内容，如 【X%】
+    pub placeholders: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BulletRewrite {
+    pub line: EvidenceSpan,
+    /// 原要点存在的问题
+    pub issues: Vec<BulletIssue>,
+    pub alternatives: Vec<RewriteAlternative>,
+    /// 候选中原简历没有的事实，数字已替换为占位，其他所在的候选已去掉
+    pub violations: Vec<GuardViolation>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RewriteBulletsResponse {
+    pub bullets: Vec<BulletRewrite>,
+    /// 用于改写的 JD 关键词，按要求权重排序
+    pub keywords: Vec<String>,
+    pub provider: String,
+    pub model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analysis_id: Option<Uuid>,
+}
+
+// ========== 中英互译 ==========
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+    #[serde(rename = "zh-CN")]
+    ZhCn,
+    #[serde(rename = "en-US")]
+    EnUs,
+}
+
+/// 译名的来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TermKind {
+    Heading,
+    Title,
+    Degree,
+    University,
+    Major,
+    Company,
+    /// “电话：”等字段名
+    Label,
+    /// 锁定不翻译的术语，如产品名
+    Locked,
+    /// 请求中指定的译名
+    Custom,
+}
+
+/// 请求中指定的译名，优先于内置词表
+#[derive(Debug, Clone, Deserialize)]
+pub struct GlossaryTerm {
+    pub source: String,
+    pub target: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TranslateRequest {
+    #[serde(default)]
+    pub resume: String,
+    #[serde(default)]
+    pub resume_version_id: Option<Uuid>,
+    pub target: Language,
+    /// 保持原文、不翻译的术语
+    #[serde(default)]
+    pub locked_terms: Vec<String>,
+    #[serde(default)]
+    pub glossary: Vec<GlossaryTerm>,
+}
+
+/// 按词表或锁定规则处理的术语
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TranslatedTerm {
+    pub source: String,
+    pub target: String,
+    pub kind: TermKind,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TranslationResult {
+    pub text: String,
+    pub source: Language,
+    pub target: Language,
+    pub terms: Vec<TranslatedTerm>,
+    /// 未能翻译、保留原文的行号（从 1 开始）
+    pub untranslated: Vec<usize>,
+    pub provider: String,
+    pub model: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TranslateResponse {
+    #[serde(flatten)]
+    pub result: TranslationResult,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analysis_id: Option<Uuid>,
 }
 
 // ========== 求职信生成 ==========
 
 #[derive(Debug, Deserialize)]
 pub struct CoverLetterRequest {
+    #[serde(default)]
     pub resume: String,
+    #[serde(default)]
     pub job_description: String,
+    #[serde(default)]
+    pub resume_version_id: Option<Uuid>,
+    #[serde(default)]
+    pub job_description_id: Option<Uuid>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct CoverLetterResponse {
     pub cover_letter: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analysis_id: Option<Uuid>,
 }
 
 // ========== 关键词提取 ==========
 
 #[derive(Debug, Deserialize)]
 pub struct ExtractKeywordsRequest {
+    #[serde(default)]
     pub text: String,
+    /// 从已保存的简历版本中提取
+    #[serde(default)]
+    pub resume_version_id: Option<Uuid>,
+    /// 从已保存的 JD 中提取
+    #[serde(default)]
+    pub job_description_id: Option<Uuid>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct ExtractKeywordsResponse {
     pub keywords: Vec<Keyword>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analysis_id: Option<Uuid>,
 }
 
 #[derive(Debug, Serialize)]
@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::NaiveDateTime;
+
+use super::BatchCategoryScore;
+
+// ========== 批量筛选简历 ==========
+
+/// 筛选任务状态流转：pending → running → completed / failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScreeningStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+impl ScreeningStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScreeningStatus::Pending => "pending",
+            ScreeningStatus::Running => "running",
+            ScreeningStatus::Completed => "completed",
+            ScreeningStatus::Failed => "failed",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct ScreeningJob {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub job_description_id: Option<Uuid>,
+    pub job_title: Option<String>,
+    pub status: String,
+    /// 压缩包中的简历数
+    pub total: i32,
+    /// 已处理的简历数
+    pub processed: i32,
+    /// 按排名排序，任务完成后写入
+    pub candidates: Json<Vec<ScreeningCandidate>>,
+    pub error: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub completed_at: Option<NaiveDateTime>,
+}
+
+/// 列表中不返回候选人结果
+#[derive(Debug, Serialize, FromRow)]
+pub struct ScreeningJobSummary {
+    pub id: Uuid,
+    pub job_description_id: Option<Uuid>,
+    pub job_title: Option<String>,
+    pub status: String,
+    pub total: i32,
+    pub processed: i32,
+    pub error: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub completed_at: Option<NaiveDateTime>,
+}
+
+/// 一条必备要求的核对结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MustHaveCheck {
+    /// 技能名称、年限或学历要求
+    pub requirement: String,
+    pub met: bool,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScreeningCandidate {
+    /// 排名（从 1 开始）：通过全部必备要求的在前，再按总分排序
+    pub rank: usize,
+    /// 压缩包中的文件路径
+    pub file_name: String,
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub overall_score: u8,
+    pub must_have_passed: bool,
+    pub must_haves: Vec<MustHaveCheck>,
+    pub categories: Vec<BatchCategoryScore>,
+    pub total_years: f32,
+    /// 简历中体现的 JD 技能
+    pub matched_skills: Vec<String>,
+    /// 简历中缺少的 JD 关键词，按权重排序
+    pub missing_keywords: Vec<String>,
+    /// 文件无法解析时的原因，此时不参与评分
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::NaiveDateTime;
+
+// ========== 技能词典文件 ==========
+
+/// 技能词典（data/skills.json 的格式）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillDictionary {
+    /// 词典格式版本
+    pub version: u32,
+    pub categories: Vec<CategoryDefinition>,
+    pub skills: Vec<SkillDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryDefinition {
+    pub key: String,
+    pub name: String,
+    /// 非技术类（软技能、管理能力）不计入 JD 的技能要求
+    #[serde(default = "default_technical")]
+    pub technical: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillDefinition {
+    /// 标准名称
+    pub name: String,
+    pub category: String,
+    /// 中英文别名和缩写，如 K8s、Golang
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    /// 父技能名称，如 React 的父技能为 JavaScript
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    /// 重要程度 0-100
+    #[serde(default = "default_importance")]
+    pub importance: u8,
+    /// 名称区分大小写匹配（别名不区分），避免 Go、Swift 等命中普通英文单词
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub case_sensitive: bool,
+}
+
+fn default_technical() -> bool {
+    true
+}
+
+fn default_importance() -> u8 {
+    60
+}
+
+// ========== 数据库覆盖 ==========
+
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct SkillCategory {
+    pub key: String,
+    pub name: String,
+    pub technical: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+/// 数据库中的技能（含别名）
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct Skill {
+    pub id: Uuid,
+    pub name: String,
+    pub category: String,
+    pub parent: Option<String>,
+    pub importance: i16,
+    pub case_sensitive: bool,
+    /// 屏蔽同名的内置技能
+    pub disabled: bool,
+    pub aliases: Vec<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct SkillAlias {
+    pub id: Uuid,
+    pub skill_id: Uuid,
+    pub alias: String,
+    pub created_at: NaiveDateTime,
+}
+
+// ========== 管理接口 ==========
+
+#[derive(Debug, Deserialize)]
+pub struct CreateSkillRequest {
+    pub name: String,
+    pub category: String,
+    pub parent: Option<String>,
+    pub importance: Option<u8>,
+    #[serde(default)]
+    pub case_sensitive: bool,
+    /// 创建一条 disabled 记录可屏蔽同名的内置技能
+    #[serde(default)]
+    pub disabled: bool,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateSkillRequest {
+    pub name: Option<String>,
+    pub category: Option<String>,
+    /// 传空字符串清除父技能
+    pub parent: Option<String>,
+    pub importance: Option<u8>,
+    pub case_sensitive: Option<bool>,
+    pub disabled: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateSkillAliasRequest {
+    pub alias: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateSkillCategoryRequest {
+    pub key: String,
+    pub name: String,
+    #[serde(default = "default_technical")]
+    pub technical: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateSkillCategoryRequest {
+    pub name: Option<String>,
+    pub technical: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DictionaryFormat {
+    #[default]
+    Json,
+    /// 每行一个技能：name,category,parent,importance,case_sensitive,aliases（别名以 | 分隔）
+    Csv,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DictionaryFormatQuery {
+    #[serde(default)]
+    pub format: DictionaryFormat,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ImportSkillsResponse {
+    pub categories: usize,
+    pub skills: usize,
+    pub aliases: usize,
+}
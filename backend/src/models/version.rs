@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::NaiveDateTime;
+
+// ========== 简历与版本 ==========
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct Resume {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub title: String,
+    pub current_version_id: Option<Uuid>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct ResumeVersion {
+    pub id: Uuid,
+    pub resume_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub version_number: i32,
+    pub source: String,
+    pub content: String,
+    pub note: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+/// 版本来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionSource {
+    ManualEdit,
+    Optimize,
+    Restore,
+}
+
+impl VersionSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VersionSource::ManualEdit => "manual_edit",
+            VersionSource::Optimize => "optimize",
+            VersionSource::Restore => "restore",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateResumeRequest {
+    pub title: String,
+    pub content: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ResumeDetailResponse {
+    pub resume: Resume,
+    pub current_version: Option<ResumeVersion>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SaveVersionRequest {
+    pub content: String,
+    pub note: Option<String>,
+}
+
+// ========== 版本对比 ==========
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffGranularity {
+    #[default]
+    Line,
+    Word,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DiffQuery {
+    pub from: Uuid,
+    pub to: Uuid,
+    #[serde(default)]
+    pub granularity: DiffGranularity,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffTag {
+    Equal,
+    Insert,
+    Delete,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DiffChunk {
+    pub tag: DiffTag,
+    pub text: String,
+    /// 在旧版本中的起始行号（从 1 开始）
+    pub old_line: Option<usize>,
+    /// 在新版本中的起始行号（从 1 开始）
+    pub new_line: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DiffStats {
+    pub insertions: usize,
+    pub deletions: usize,
+    pub unchanged: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VersionDiffResponse {
+    pub from: Uuid,
+    pub to: Uuid,
+    pub chunks: Vec<DiffChunk>,
+    pub stats: DiffStats,
+}
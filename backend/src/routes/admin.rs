@@ -0,0 +1,496 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::*;
+use crate::services::taxonomy::{
+    find_skill, import_dictionary, load_taxonomy, reload_taxonomy, skills_from_csv, skills_to_csv, SKILL_SELECT,
+};
+use crate::services::SkillTaxonomy;
+use crate::utils::auth::require_admin;
+use crate::utils::AppState;
+
+// ========== 技能 ==========
+
+/// 数据库中的技能覆盖（不含内置词典，完整词典见导出接口）
+pub async fn list_skills(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<Skill>>, (StatusCode, String)> {
+    require_admin(&app_state.db, &headers).await?;
+
+    let skills = sqlx::query_as::<_, Skill>(&format!("{} GROUP BY s.id ORDER BY s.name", SKILL_SELECT))
+        .fetch_all(&app_state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(skills))
+}
+
+pub async fn create_skill(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<CreateSkillRequest>,
+) -> Result<Json<Skill>, (StatusCode, String)> {
+    require_admin(&app_state.db, &headers).await?;
+    let pool = &app_state.db;
+
+    let name = payload.name.trim();
+    if name.is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "Skill name is required".to_string()));
+    }
+    validate_skill(Some(&payload.category), payload.parent.as_deref(), payload.importance)?;
+
+    let skill_id = Uuid::new_v4();
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO skills (id, name, category, parent, importance, case_sensitive, disabled)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        "#,
+    )
+    .bind(skill_id)
+    .bind(name)
+    .bind(&payload.category)
+    .bind(payload.parent.as_deref().map(str::trim).filter(|p| !p.is_empty()))
+    .bind(payload.importance.unwrap_or(60) as i16)
+    .bind(payload.case_sensitive)
+    .bind(payload.disabled)
+    .execute(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    for alias in payload.aliases.iter().map(|a| a.trim()).filter(|a| !a.is_empty()) {
+        sqlx::query("INSERT INTO skill_aliases (id, skill_id, alias) VALUES ($1, $2, $3)")
+            .bind(Uuid::new_v4())
+            .bind(skill_id)
+            .bind(alias)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    reload(pool).await?;
+    load_skill(pool, skill_id).await.map(Json)
+}
+
+pub async fn get_skill(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(skill_id): Path<Uuid>,
+) -> Result<Json<Skill>, (StatusCode, String)> {
+    require_admin(&app_state.db, &headers).await?;
+    load_skill(&app_state.db, skill_id).await.map(Json)
+}
+
+pub async fn update_skill(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(skill_id): Path<Uuid>,
+    Json(payload): Json<UpdateSkillRequest>,
+) -> Result<Json<Skill>, (StatusCode, String)> {
+    require_admin(&app_state.db, &headers).await?;
+    let pool = &app_state.db;
+
+    validate_skill(payload.category.as_deref(), payload.parent.as_deref(), payload.importance)?;
+
+    let result = sqlx::query(
+        r#"
+        UPDATE skills SET
+            name = COALESCE($1, name),
+            category = COALESCE($2, category),
+            parent = CASE WHEN $3::VARCHAR IS NULL THEN parent ELSE NULLIF($3, '') END,
+            importance = COALESCE($4, importance),
+            case_sensitive = COALESCE($5, case_sensitive),
+            disabled = COALESCE($6, disabled)
+        WHERE id = $7
+        "#,
+    )
+    .bind(payload.name.as_deref().map(str::trim).filter(|n| !n.is_empty()))
+    .bind(payload.category)
+    .bind(payload.parent.as_deref().map(str::trim))
+    .bind(payload.importance.map(|i| i as i16))
+    .bind(payload.case_sensitive)
+    .bind(payload.disabled)
+    .bind(skill_id)
+    .execute(pool)
+    .await
+    .map_err(db_error)?;
+
+    if result.rows_affected() == 0 {
+        return Err((StatusCode::NOT_FOUND, "Skill not found".to_string()));
+    }
+
+    reload(pool).await?;
+    load_skill(pool, skill_id).await.map(Json)
+}
+
+/// 删除覆盖记录；同名的内置技能随之恢复
+pub async fn delete_skill(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(skill_id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    require_admin(&app_state.db, &headers).await?;
+
+    let result = sqlx::query("DELETE FROM skills WHERE id = $1")
+        .bind(skill_id)
+        .execute(&app_state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if result.rows_affected() == 0 {
+        return Err((StatusCode::NOT_FOUND, "Skill not found".to_string()));
+    }
+
+    reload(&app_state.db).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ========== 别名 ==========
+
+pub async fn add_alias(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(skill_id): Path<Uuid>,
+    Json(payload): Json<CreateSkillAliasRequest>,
+) -> Result<Json<Skill>, (StatusCode, String)> {
+    require_admin(&app_state.db, &headers).await?;
+    let pool = &app_state.db;
+
+    let alias = payload.alias.trim();
+    if alias.is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "Alias is required".to_string()));
+    }
+    load_skill(pool, skill_id).await?;
+
+    sqlx::query("INSERT INTO skill_aliases (id, skill_id, alias) VALUES ($1, $2, $3)")
+        .bind(Uuid::new_v4())
+        .bind(skill_id)
+        .bind(alias)
+        .execute(pool)
+        .await
+        .map_err(db_error)?;
+
+    reload(pool).await?;
+    load_skill(pool, skill_id).await.map(Json)
+}
+
+pub async fn delete_alias(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path((skill_id, alias_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    require_admin(&app_state.db, &headers).await?;
+
+    let result = sqlx::query("DELETE FROM skill_aliases WHERE id = $1 AND skill_id = $2")
+        .bind(alias_id)
+        .bind(skill_id)
+        .execute(&app_state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if result.rows_affected() == 0 {
+        return Err((StatusCode::NOT_FOUND, "Alias not found".to_string()));
+    }
+
+    reload(&app_state.db).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 技能的别名记录（含 ID，用于删除）
+pub async fn list_aliases(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(skill_id): Path<Uuid>,
+) -> Result<Json<Vec<SkillAlias>>, (StatusCode, String)> {
+    require_admin(&app_state.db, &headers).await?;
+
+    let aliases = sqlx::query_as::<_, SkillAlias>(
+        "SELECT * FROM skill_aliases WHERE skill_id = $1 ORDER BY alias",
+    )
+    .bind(skill_id)
+    .fetch_all(&app_state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(aliases))
+}
+
+// ========== 分类 ==========
+
+pub async fn list_categories(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<SkillCategory>>, (StatusCode, String)> {
+    require_admin(&app_state.db, &headers).await?;
+
+    let categories = sqlx::query_as::<_, SkillCategory>("SELECT * FROM skill_categories ORDER BY key")
+        .fetch_all(&app_state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(categories))
+}
+
+pub async fn create_category(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<CreateSkillCategoryRequest>,
+) -> Result<Json<SkillCategory>, (StatusCode, String)> {
+    require_admin(&app_state.db, &headers).await?;
+
+    let key = payload.key.trim();
+    if key.is_empty() || payload.name.trim().is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "Category key and name are required".to_string()));
+    }
+
+    let category = sqlx::query_as::<_, SkillCategory>(
+        "INSERT INTO skill_categories (key, name, technical) VALUES ($1, $2, $3) RETURNING *",
+    )
+    .bind(key)
+    .bind(payload.name.trim())
+    .bind(payload.technical)
+    .fetch_one(&app_state.db)
+    .await
+    .map_err(db_error)?;
+
+    reload(&app_state.db).await?;
+    Ok(Json(category))
+}
+
+pub async fn update_category(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(key): Path<String>,
+    Json(payload): Json<UpdateSkillCategoryRequest>,
+) -> Result<Json<SkillCategory>, (StatusCode, String)> {
+    require_admin(&app_state.db, &headers).await?;
+
+    let category = sqlx::query_as::<_, SkillCategory>(
+        r#"
+        UPDATE skill_categories SET
+            name = COALESCE($1, name),
+            technical = COALESCE($2, technical)
+        WHERE key = $3
+        RETURNING *
+        "#,
+    )
+    .bind(payload.name)
+    .bind(payload.technical)
+    .bind(&key)
+    .fetch_optional(&app_state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::NOT_FOUND, "Category not found".to_string()))?;
+
+    reload(&app_state.db).await?;
+    Ok(Json(category))
+}
+
+pub async fn delete_category(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(key): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    require_admin(&app_state.db, &headers).await?;
+
+    let result = sqlx::query("DELETE FROM skill_categories WHERE key = $1")
+        .bind(&key)
+        .execute(&app_state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if result.rows_affected() == 0 {
+        return Err((StatusCode::NOT_FOUND, "Category not found".to_string()));
+    }
+
+    reload(&app_state.db).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ========== 导入导出 ==========
+
+/// 导出当前生效的完整词典（内置 + 数据库覆盖）
+pub async fn export_dictionary(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<DictionaryFormatQuery>,
+) -> Result<Response, (StatusCode, String)> {
+    require_admin(&app_state.db, &headers).await?;
+
+    let dictionary = load_taxonomy(&app_state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .to_dictionary();
+
+    match query.format {
+        DictionaryFormat::Json => Ok(Json(dictionary).into_response()),
+        DictionaryFormat::Csv => {
+            let body = skills_to_csv(&dictionary.skills)
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+            Ok((
+                [
+                    (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+                    (header::CONTENT_DISPOSITION, "attachment; filename=\"skills.csv\""),
+                ],
+                body,
+            )
+                .into_response())
+        }
+    }
+}
+
+/// 导入词典：JSON 格式与 data/skills.json 相同，CSV 格式只包含技能
+pub async fn import_dictionary_file(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<DictionaryFormatQuery>,
+    body: String,
+) -> Result<Json<ImportSkillsResponse>, (StatusCode, String)> {
+    require_admin(&app_state.db, &headers).await?;
+
+    let (categories, skills) = match query.format {
+        DictionaryFormat::Json => {
+            let dictionary: SkillDictionary = serde_json::from_str(&body)
+                .map_err(|e| (StatusCode::BAD_REQUEST, format!("Invalid dictionary JSON: {}", e)))?;
+            (dictionary.categories, dictionary.skills)
+        }
+        DictionaryFormat::Csv => {
+            let skills = skills_from_csv(&body)
+                .map_err(|e| (StatusCode::BAD_REQUEST, format!("Invalid dictionary CSV: {}", e)))?;
+            (Vec::new(), skills)
+        }
+    };
+
+    if let Some(skill) = skills.iter().find(|s| s.name.trim().is_empty() || s.category.trim().is_empty()) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Skill name and category are required (near \"{}\")", skill.name),
+        ));
+    }
+
+    // 分类和父技能可以是已有的，也可以是同一文件中导入的
+    let taxonomy = SkillTaxonomy::global();
+    let is_category = |key: &str| taxonomy.category(key).is_some() || categories.iter().any(|c| c.key == key);
+    let is_skill = |name: &str| {
+        taxonomy.lookup(name).is_some()
+            || skills.iter().any(|s| {
+                s.name.trim().eq_ignore_ascii_case(name) || s.aliases.iter().any(|a| a.trim().eq_ignore_ascii_case(name))
+            })
+    };
+    for skill in &skills {
+        check_skill(Some(&skill.category), skill.parent.as_deref(), Some(skill.importance), is_category, is_skill)
+            .map_err(|(status, message)| (status, format!("{} (near \"{}\")", message, skill.name)))?;
+    }
+
+    let result = import_dictionary(&app_state.db, &categories, &skills)
+        .await
+        .map_err(db_error)?;
+
+    reload(&app_state.db).await?;
+    Ok(Json(result))
+}
+
+/// 手动重新加载词典（如直接修改了数据库）
+pub async fn reload_dictionary(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<ImportSkillsResponse>, (StatusCode, String)> {
+    require_admin(&app_state.db, &headers).await?;
+
+    let dictionary = reload_taxonomy(&app_state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .to_dictionary();
+
+    Ok(Json(ImportSkillsResponse {
+        categories: dictionary.categories.len(),
+        skills: dictionary.skills.len(),
+        aliases: dictionary.skills.iter().map(|s| s.aliases.len()).sum(),
+    }))
+}
+
+// ========== 辅助方法 ==========
+
+/// 提交后刷新内存中的词典，使修改立即生效
+async fn reload(pool: &PgPool) -> Result<(), (StatusCode, String)> {
+    reload_taxonomy(pool)
+        .await
+        .map(|_| ())
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_skill(pool: &PgPool, skill_id: Uuid) -> Result<Skill, (StatusCode, String)> {
+    find_skill(pool, skill_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Skill not found".to_string()))
+}
+
+/// 分类和父技能必须已存在于当前词典中
+fn validate_skill(
+    category: Option<&str>,
+    parent: Option<&str>,
+    importance: Option<u8>,
+) -> Result<(), (StatusCode, String)> {
+    let taxonomy = SkillTaxonomy::global();
+    check_skill(
+        category,
+        parent,
+        importance,
+        |key| taxonomy.category(key).is_some(),
+        |name| taxonomy.lookup(name).is_some(),
+    )
+}
+
+fn check_skill(
+    category: Option<&str>,
+    parent: Option<&str>,
+    importance: Option<u8>,
+    is_category: impl Fn(&str) -> bool,
+    is_skill: impl Fn(&str) -> bool,
+) -> Result<(), (StatusCode, String)> {
+    if let Some(category) = category {
+        if !is_category(category) {
+            return Err((StatusCode::BAD_REQUEST, format!("Unknown category: {}", category)));
+        }
+    }
+    if let Some(parent) = parent.map(str::trim).filter(|p| !p.is_empty()) {
+        if !is_skill(parent) {
+            return Err((StatusCode::BAD_REQUEST, format!("Unknown parent skill: {}", parent)));
+        }
+    }
+    if importance.is_some_and(|i| i > 100) {
+        return Err((StatusCode::BAD_REQUEST, "Importance must be between 0 and 100".to_string()));
+    }
+
+    Ok(())
+}
+
+/// 名称、别名或分类重复时返回 409
+fn db_error(e: sqlx::Error) -> (StatusCode, String) {
+    let is_unique_violation = e
+        .as_database_error()
+        .and_then(|d| d.code())
+        .is_some_and(|code| code == "23505");
+
+    if is_unique_violation {
+        (StatusCode::CONFLICT, "Skill name, alias or category already exists".to_string())
+    } else {
+        (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+    }
+}
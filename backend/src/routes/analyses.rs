@@ -0,0 +1,80 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use uuid::Uuid;
+
+use crate::models::*;
+use crate::utils::auth::user_id_from_headers;
+use crate::utils::AppState;
+
+const DEFAULT_PER_PAGE: u32 = 20;
+const MAX_PER_PAGE: u32 = 100;
+
+pub async fn list(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<AnalysisListQuery>,
+) -> Result<Json<PaginatedResponse<AnalysisSummary>>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+    let pool = &app_state.db;
+
+    let page = query.page.unwrap_or(1).max(1);
+    let per_page = query.per_page.unwrap_or(DEFAULT_PER_PAGE).clamp(1, MAX_PER_PAGE);
+    let kind = query.kind.map(|k| k.as_str());
+
+    let items = sqlx::query_as::<_, AnalysisSummary>(
+        r#"
+        SELECT id, kind, resume_version_id, job_description_id, provider, model,
+               latency_ms, credits_charged, created_at
+        FROM analyses
+        WHERE user_id = $1 AND ($2::VARCHAR IS NULL OR kind = $2)
+        ORDER BY created_at DESC
+        LIMIT $3 OFFSET $4
+        "#,
+    )
+    .bind(user_id)
+    .bind(kind)
+    .bind(per_page as i64)
+    .bind(((page - 1) * per_page) as i64)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let total: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM analyses WHERE user_id = $1 AND ($2::VARCHAR IS NULL OR kind = $2)",
+    )
+    .bind(user_id)
+    .bind(kind)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(PaginatedResponse {
+        items,
+        page,
+        per_page,
+        total,
+    }))
+}
+
+pub async fn get(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(analysis_id): Path<Uuid>,
+) -> Result<Json<Analysis>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+
+    let analysis = sqlx::query_as::<_, Analysis>(
+        "SELECT * FROM analyses WHERE id = $1 AND user_id = $2",
+    )
+    .bind(analysis_id)
+    .bind(user_id)
+    .fetch_optional(&app_state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::NOT_FOUND, "Analysis not found".to_string()))?;
+
+    Ok(Json(analysis))
+}
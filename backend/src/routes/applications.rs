@@ -0,0 +1,393 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use sqlx::{PgPool, Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use crate::models::*;
+use crate::services::jd_parser::find_job_description;
+use crate::services::versions::find_user_version;
+use crate::services::ResumeOptimizer;
+use crate::utils::auth::user_id_from_headers;
+use crate::utils::AppState;
+
+const APPLICATION_SELECT: &str = r#"
+    SELECT a.*, j.company AS job_company, j.title AS job_title
+    FROM applications a
+    LEFT JOIN job_descriptions j ON j.id = a.job_description_id
+"#;
+
+pub async fn create(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<CreateApplicationRequest>,
+) -> Result<Json<ApplicationDetailResponse>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+    let pool = &app_state.db;
+
+    let resume_content = match payload.resume_version_id {
+        Some(version_id) => Some(load_version_content(pool, user_id, version_id).await?),
+        None => None,
+    };
+    let jd_text = match payload.job_description_id {
+        Some(job_id) => Some(load_job_text(pool, user_id, job_id).await?),
+        None => None,
+    };
+
+    let cover_letter = match (payload.cover_letter, resume_content, jd_text) {
+        (Some(letter), _, _) => Some(letter),
+        (None, Some(resume), Some(jd)) if payload.generate_cover_letter => {
+            Some(ResumeOptimizer::generate_cover_letter(&resume, &jd).await)
+        }
+        (None, _, _) if payload.generate_cover_letter => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                "resume_version_id and job_description_id are required to generate a cover letter".to_string(),
+            ));
+        }
+        _ => None,
+    };
+
+    let status = payload.status.unwrap_or(ApplicationStatus::Saved);
+    let applied_at = match status {
+        ApplicationStatus::Saved => payload.applied_at,
+        _ => payload.applied_at.or_else(|| Some(chrono::Utc::now().naive_utc())),
+    };
+
+    let application_id = Uuid::new_v4();
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO applications (
+            id, user_id, resume_version_id, job_description_id, cover_letter,
+            status, applied_at, notes, remind_at, reminder_note
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        "#,
+    )
+    .bind(application_id)
+    .bind(user_id)
+    .bind(payload.resume_version_id)
+    .bind(payload.job_description_id)
+    .bind(cover_letter)
+    .bind(status.as_str())
+    .bind(applied_at)
+    .bind(payload.notes)
+    .bind(payload.remind_at)
+    .bind(payload.reminder_note)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    sqlx::query(
+        "INSERT INTO application_status_history (id, application_id, from_status, to_status) VALUES ($1, $2, NULL, $3)",
+    )
+    .bind(Uuid::new_v4())
+    .bind(application_id)
+    .bind(status.as_str())
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    load_detail(pool, user_id, application_id).await.map(Json)
+}
+
+pub async fn list(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<ApplicationListQuery>,
+) -> Result<Json<Vec<Application>>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+
+    let mut builder: QueryBuilder<Postgres> = QueryBuilder::new(APPLICATION_SELECT);
+    builder.push(" WHERE a.user_id = ").push_bind(user_id);
+
+    if let Some(status) = query.status {
+        builder.push(" AND a.status = ").push_bind(status.as_str());
+    }
+    if let Some(job_id) = query.job_description_id {
+        builder.push(" AND a.job_description_id = ").push_bind(job_id);
+    }
+    if let Some(q) = query.q.as_deref().map(str::trim).filter(|q| !q.is_empty()) {
+        let pattern = format!("%{}%", q);
+        builder
+            .push(" AND (j.company ILIKE ")
+            .push_bind(pattern.clone())
+            .push(" OR j.title ILIKE ")
+            .push_bind(pattern.clone())
+            .push(" OR a.notes ILIKE ")
+            .push_bind(pattern)
+            .push(")");
+    }
+    if query.reminders_due {
+        builder.push(" AND a.remind_at <= NOW()");
+    }
+    builder.push(" ORDER BY a.updated_at DESC");
+
+    let applications = builder
+        .build_query_as::<Application>()
+        .fetch_all(&app_state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(applications))
+}
+
+pub async fn get(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(application_id): Path<Uuid>,
+) -> Result<Json<ApplicationDetailResponse>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+    load_detail(&app_state.db, user_id, application_id).await.map(Json)
+}
+
+pub async fn update(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(application_id): Path<Uuid>,
+    Json(payload): Json<UpdateApplicationRequest>,
+) -> Result<Json<ApplicationDetailResponse>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+    let pool = &app_state.db;
+
+    if let Some(version_id) = payload.resume_version_id {
+        load_version_content(pool, user_id, version_id).await?;
+    }
+    if let Some(job_id) = payload.job_description_id {
+        load_job_text(pool, user_id, job_id).await?;
+    }
+
+    let result = sqlx::query(
+        r#"
+        UPDATE applications SET
+            resume_version_id = COALESCE($1, resume_version_id),
+            job_description_id = COALESCE($2, job_description_id),
+            cover_letter = COALESCE($3, cover_letter),
+            notes = CASE WHEN $4 THEN $5 ELSE notes END,
+            remind_at = CASE WHEN $6 THEN $7 ELSE remind_at END,
+            reminder_note = CASE WHEN $8 THEN $9 ELSE reminder_note END
+        WHERE id = $10 AND user_id = $11
+        "#,
+    )
+    .bind(payload.resume_version_id)
+    .bind(payload.job_description_id)
+    .bind(payload.cover_letter)
+    .bind(payload.notes.is_some())
+    .bind(payload.notes.flatten())
+    .bind(payload.remind_at.is_some())
+    .bind(payload.remind_at.flatten())
+    .bind(payload.reminder_note.is_some())
+    .bind(payload.reminder_note.flatten())
+    .bind(application_id)
+    .bind(user_id)
+    .execute(pool)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if result.rows_affected() == 0 {
+        return Err((StatusCode::NOT_FOUND, "Application not found".to_string()));
+    }
+
+    load_detail(pool, user_id, application_id).await.map(Json)
+}
+
+/// 更新申请状态，并记录状态历史
+pub async fn update_status(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(application_id): Path<Uuid>,
+    Json(payload): Json<UpdateApplicationStatusRequest>,
+) -> Result<Json<ApplicationDetailResponse>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+    let pool = &app_state.db;
+    let changed_at = payload
+        .changed_at
+        .unwrap_or_else(|| chrono::Utc::now().naive_utc());
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let from_status: String = sqlx::query_scalar(
+        "SELECT status FROM applications WHERE id = $1 AND user_id = $2 FOR UPDATE",
+    )
+    .bind(application_id)
+    .bind(user_id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::NOT_FOUND, "Application not found".to_string()))?;
+
+    // 首次进入 applied 及之后的阶段时记录投递时间
+    let marks_applied = payload.status != ApplicationStatus::Saved;
+
+    sqlx::query(
+        r#"
+        UPDATE applications SET
+            status = $1,
+            status_changed_at = $2,
+            applied_at = CASE WHEN $3 AND applied_at IS NULL THEN $2 ELSE applied_at END
+        WHERE id = $4
+        "#,
+    )
+    .bind(payload.status.as_str())
+    .bind(changed_at)
+    .bind(marks_applied)
+    .bind(application_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO application_status_history (id, application_id, from_status, to_status, note, changed_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        "#,
+    )
+    .bind(Uuid::new_v4())
+    .bind(application_id)
+    .bind(from_status)
+    .bind(payload.status.as_str())
+    .bind(payload.note)
+    .bind(changed_at)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    load_detail(pool, user_id, application_id).await.map(Json)
+}
+
+pub async fn delete(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(application_id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+
+    let result = sqlx::query("DELETE FROM applications WHERE id = $1 AND user_id = $2")
+        .bind(application_id)
+        .bind(user_id)
+        .execute(&app_state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if result.rows_affected() == 0 {
+        return Err((StatusCode::NOT_FOUND, "Application not found".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 各阶段数量统计
+pub async fn stats(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<ApplicationStatsResponse>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+    let pool = &app_state.db;
+
+    let rows: Vec<(String, i64)> = sqlx::query_as(
+        "SELECT status, COUNT(*) FROM applications WHERE user_id = $1 GROUP BY status",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let reminders_due: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM applications WHERE user_id = $1 AND remind_at <= NOW()",
+    )
+    .bind(user_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let stages: Vec<StageCount> = ApplicationStatus::ALL
+        .iter()
+        .map(|status| StageCount {
+            status: *status,
+            count: rows
+                .iter()
+                .find(|(s, _)| s == status.as_str())
+                .map(|(_, count)| *count)
+                .unwrap_or(0),
+        })
+        .collect();
+
+    Ok(Json(ApplicationStatsResponse {
+        total: stages.iter().map(|s| s.count).sum(),
+        stages,
+        reminders_due,
+    }))
+}
+
+// ========== 辅助方法 ==========
+
+async fn load_detail(
+    pool: &PgPool,
+    user_id: Uuid,
+    application_id: Uuid,
+) -> Result<ApplicationDetailResponse, (StatusCode, String)> {
+    let application = sqlx::query_as::<_, Application>(&format!(
+        "{} WHERE a.id = $1 AND a.user_id = $2",
+        APPLICATION_SELECT
+    ))
+    .bind(application_id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .ok_or((StatusCode::NOT_FOUND, "Application not found".to_string()))?;
+
+    let history = sqlx::query_as::<_, ApplicationStatusChange>(
+        "SELECT * FROM application_status_history WHERE application_id = $1 ORDER BY changed_at",
+    )
+    .bind(application_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(ApplicationDetailResponse { application, history })
+}
+
+/// 读取属于该用户的简历版本内容
+async fn load_version_content(
+    pool: &PgPool,
+    user_id: Uuid,
+    version_id: Uuid,
+) -> Result<String, (StatusCode, String)> {
+    find_user_version(pool, user_id, version_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .map(|version| version.content)
+        .ok_or((StatusCode::NOT_FOUND, "Resume version not found".to_string()))
+}
+
+/// 读取属于该用户的 JD 原文
+async fn load_job_text(
+    pool: &PgPool,
+    user_id: Uuid,
+    job_id: Uuid,
+) -> Result<String, (StatusCode, String)> {
+    find_job_description(pool, user_id, job_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .map(|job| job.raw_text)
+        .ok_or((StatusCode::NOT_FOUND, "Job description not found".to_string()))
+}
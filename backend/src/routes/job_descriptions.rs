@@ -0,0 +1,134 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use sqlx::types::Json as SqlJson;
+use uuid::Uuid;
+
+use crate::models::*;
+use crate::services::jd_parser::{find_job_description, MAX_NAME_CHARS};
+use crate::services::relevance::invalidate_corpus;
+use crate::services::JdParser;
+use crate::utils::auth::user_id_from_headers;
+use crate::utils::AppState;
+
+/// 解析 JD（不保存）
+pub async fn parse(
+    State(_state): State<AppState>,
+    Json(req): Json<ParseJobDescriptionRequest>,
+) -> Json<ParsedJobDescription> {
+    Json(JdParser::parse(&req.text))
+}
+
+pub async fn create(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<SaveJobDescriptionRequest>,
+) -> Result<Json<JobDescription>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+
+    if payload.text.trim().is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "Job description text is required".to_string()));
+    }
+
+    let too_long = |value: &Option<String>| value.as_ref().is_some_and(|v| v.chars().count() > MAX_NAME_CHARS);
+    if too_long(&payload.company) || too_long(&payload.title) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Company and title must be at most {} characters", MAX_NAME_CHARS),
+        ));
+    }
+
+    let parsed = JdParser::parse(&payload.text);
+    let company = payload.company.or(parsed.company);
+    let title = payload.title.or(parsed.title);
+
+    let job = sqlx::query_as::<_, JobDescription>(
+        r#"
+        INSERT INTO job_descriptions (
+            id, user_id, raw_text, company, title, location, seniority,
+            salary_min, salary_max, salary_currency, salary_period,
+            required_skills, preferred_skills, responsibilities
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+        RETURNING *
+        "#,
+    )
+    .bind(Uuid::new_v4())
+    .bind(user_id)
+    .bind(&payload.text)
+    .bind(company)
+    .bind(title)
+    .bind(parsed.location)
+    .bind(parsed.seniority)
+    .bind(parsed.salary.as_ref().map(|s| s.min))
+    .bind(parsed.salary.as_ref().map(|s| s.max))
+    .bind(parsed.salary.as_ref().map(|s| s.currency.clone()))
+    .bind(parsed.salary.as_ref().map(|s| s.period.clone()))
+    .bind(SqlJson(parsed.required_skills))
+    .bind(SqlJson(parsed.preferred_skills))
+    .bind(SqlJson(parsed.responsibilities))
+    .fetch_one(&app_state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    invalidate_corpus();
+
+    Ok(Json(job))
+}
+
+pub async fn list(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<JobDescription>>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+
+    let jobs = sqlx::query_as::<_, JobDescription>(
+        "SELECT * FROM job_descriptions WHERE user_id = $1 ORDER BY created_at DESC",
+    )
+    .bind(user_id)
+    .fetch_all(&app_state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(jobs))
+}
+
+pub async fn get(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(job_id): Path<Uuid>,
+) -> Result<Json<JobDescription>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+
+    let job = find_job_description(&app_state.db, user_id, job_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Job description not found".to_string()))?;
+
+    Ok(Json(job))
+}
+
+pub async fn delete(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(job_id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+
+    let result = sqlx::query("DELETE FROM job_descriptions WHERE id = $1 AND user_id = $2")
+        .bind(job_id)
+        .bind(user_id)
+        .execute(&app_state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if result.rows_affected() == 0 {
+        return Err((StatusCode::NOT_FOUND, "Job description not found".to_string()));
+    }
+
+    invalidate_corpus();
+
+    Ok(StatusCode::NO_CONTENT)
+}
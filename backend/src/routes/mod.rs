@@ -1,6 +1,12 @@
 pub mod resume;
 pub mod auth;
 pub mod usage;
+pub mod versions;
+pub mod job_descriptions;
+pub mod applications;
+pub mod analyses;
+pub mod admin;
+pub mod screenings;
 
 use axum::{Json, extract::State};
 use serde_json::{json, Value};
@@ -1,51 +1,785 @@
-use axum::{Json, extract::State};
-use serde_json::{json, Value};
+use std::time::{Duration, Instant};
+
+use axum::{
+    extract::{Multipart, Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use serde::Serialize;
+use uuid::Uuid;
 use crate::{models::*, utils::AppState, services::ResumeOptimizer};
+use crate::services::relevance::load_corpus;
+use crate::services::{
+    AtsChecker, BatchScorer, BulletAnalyzer, BulletRewriter, JdParser, Proofreader, Redactor, RelevanceScorer,
+    ResumeDocument, ResumeLinter, ResumeTranslator, SkillGapAnalyzer, TimelineAnalyzer,
+};
+use crate::services::ats::AtsError;
+use crate::services::batch::{BatchJob, MAX_BATCH_JOBS};
+use crate::services::analysis::{record_analysis, NewAnalysis, BUILTIN_MODEL, BUILTIN_PROVIDER};
+use crate::services::jd_parser::find_job_description;
+use crate::services::translate::detect_language;
+use crate::services::versions::{append_version, find_resume, find_user_version, find_version};
+use crate::utils::auth::{optional_user_id_from_headers, user_id_from_headers};
+
+/// 上传简历文件的大小上限
+pub const MAX_UPLOAD_BYTES: usize = 10 * 1024 * 1024;
+
+/// 统计技能出现频率时最多读取的已保存 JD 数
+const MAX_SAVED_JOBS: i64 = 200;
 
 pub async fn optimize(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
     Json(req): Json<OptimizeResumeRequest>,
-) -> Json<OptimizeResumeResponse> {
-    let result = ResumeOptimizer::optimize(&req.resume, &req.job_description).await;
+) -> Result<Json<OptimizeResumeResponse>, (StatusCode, String)> {
+    let user_id = optional_user_id_from_headers(&headers)?;
+    let resume = resolve_resume(&state, user_id, &req.resume, req.resume_version_id).await?;
+    let jd = resolve_job_description(&state, user_id, &req.job_description, req.job_description_id).await?;
+
+    // 保存为新版本时需要登录并校验简历归属
+    let target_resume = match req.resume_id {
+        Some(resume_id) => {
+            let user_id = user_id.ok_or((StatusCode::UNAUTHORIZED, "Missing auth token".to_string()))?;
+            let resume = find_resume(&state.db, user_id, resume_id)
+                .await
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+                .ok_or((StatusCode::NOT_FOUND, "Resume not found".to_string()))?;
+            Some(resume)
+        }
+        None => None,
+    };
+
+    let corpus = load_corpus(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let started = Instant::now();
+    let result = ResumeOptimizer::optimize(&resume, &jd, &corpus).await;
+    let latency = started.elapsed();
+
+    let version_id = match target_resume {
+        Some(resume) => {
+            let version = append_version(
+                &state.db,
+                resume.id,
+                VersionSource::Optimize,
+                &result.optimized_resume,
+                None,
+            )
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+            Some(version.id)
+        }
+        None => None,
+    };
     
-    Json(OptimizeResumeResponse {
+    let mut response = OptimizeResumeResponse {
         optimized_resume: result.optimized_resume,
         changes: result.changes,
-        match_score: result.match_score,
-    })
+        suggestions: result.suggestions,
+        violations: result.violations,
+        match_score: result.relevance.score.round() as u8,
+        relevance: result.relevance,
+        requirement_gaps: result.requirement_gaps,
+        version_id,
+        analysis_id: None,
+    };
+    response.analysis_id = record_run(
+        &state,
+        user_id,
+        AnalysisKind::Optimize,
+        req.resume_version_id,
+        req.job_description_id,
+        &response,
+        latency,
+    )
+    .await?;
+
+    Ok(Json(response))
 }
 
 pub async fn score(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
     Json(req): Json<ScoreResumeRequest>,
-) -> Json<ScoreResumeResponse> {
-    let result = ResumeOptimizer::score(&req.resume, &req.job_description).await;
+) -> Result<Json<ScoreResumeResponse>, (StatusCode, String)> {
+    let user_id = optional_user_id_from_headers(&headers)?;
+    let resume = resolve_resume(&state, user_id, &req.resume, req.resume_version_id).await?;
+    let jd = resolve_job_description(&state, user_id, &req.job_description, req.job_description_id).await?;
+
+    let started = Instant::now();
+    let result = ResumeOptimizer::score(&resume, &jd).await;
+    let latency = started.elapsed();
     
-    Json(ScoreResumeResponse {
+    let mut response = ScoreResumeResponse {
         overall_score: result.overall_score,
         categories: result.categories,
         suggestions: result.suggestions,
-    })
+        timeline: result.timeline,
+        bullets: result.bullets,
+        completeness: result.completeness,
+        seniority: result.seniority,
+        ats: None,
+        analysis_id: None,
+    };
+    response.analysis_id = record_run(
+        &state,
+        user_id,
+        AnalysisKind::Score,
+        req.resume_version_id,
+        req.job_description_id,
+        &response,
+        latency,
+    )
+    .await?;
+
+    Ok(Json(response))
+}
+
+/// 上传 PDF / DOCX 简历评分，同时检查 ATS 能否正确解析文件
+///
+/// multipart 字段：file（必填）、job_description 或 job_description_id
+pub async fn score_file(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    mut multipart: Multipart,
+) -> Result<Json<ScoreResumeResponse>, (StatusCode, String)> {
+    let user_id = optional_user_id_from_headers(&headers)?;
+
+    let mut file = None;
+    let mut job_description = String::new();
+    let mut job_description_id = None;
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?
+    {
+        match field.name() {
+            Some("file") => {
+                let bytes = field.bytes().await.map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+                file = Some(bytes);
+            }
+            Some("job_description") => {
+                job_description = field.text().await.map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+            }
+            Some("job_description_id") => {
+                let id = field.text().await.map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+                let id = Uuid::parse_str(id.trim())
+                    .map_err(|_| (StatusCode::BAD_REQUEST, "Invalid job_description_id".to_string()))?;
+                job_description_id = Some(id);
+            }
+            _ => {}
+        }
+    }
+    let file = file.ok_or((StatusCode::BAD_REQUEST, "file is required".to_string()))?;
+    let jd = resolve_job_description(&state, user_id, &job_description, job_description_id).await?;
+
+    let started = Instant::now();
+    let inspection = tokio::task::spawn_blocking(move || AtsChecker::inspect(&file))
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .map_err(|e| match e {
+            AtsError::Unsupported => (StatusCode::UNSUPPORTED_MEDIA_TYPE, e.to_string()),
+            _ => (StatusCode::UNPROCESSABLE_ENTITY, e.to_string()),
+        })?;
+    let result = ResumeOptimizer::score(&inspection.text, &jd).await;
+    let latency = started.elapsed();
+
+    let mut response = ScoreResumeResponse {
+        overall_score: result.overall_score,
+        categories: result.categories,
+        suggestions: result.suggestions,
+        timeline: result.timeline,
+        bullets: result.bullets,
+        completeness: result.completeness,
+        seniority: result.seniority,
+        ats: Some(inspection.report),
+        analysis_id: None,
+    };
+    response.analysis_id = record_run(
+        &state,
+        user_id,
+        AnalysisKind::Score,
+        None,
+        job_description_id,
+        &response,
+        latency,
+    )
+    .await?;
+
+    Ok(Json(response))
+}
+
+/// 同一份简历对多个已保存或直接提交的 JD 评分，按总分排序
+pub async fn score_batch(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<BatchScoreRequest>,
+) -> Result<Json<BatchScoreResponse>, (StatusCode, String)> {
+    let user_id = optional_user_id_from_headers(&headers)?;
+    let resume = resolve_resume(&state, user_id, &req.resume, req.resume_version_id).await?;
+
+    let total = req.job_description_ids.len() + req.job_descriptions.len();
+    if total == 0 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "job_description_ids or job_descriptions is required".to_string(),
+        ));
+    }
+    if total > MAX_BATCH_JOBS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("At most {} job descriptions per request", MAX_BATCH_JOBS),
+        ));
+    }
+
+    let mut jobs = Vec::with_capacity(total);
+    for &job_id in &req.job_description_ids {
+        let text = resolve_job_description(&state, user_id, "", Some(job_id)).await?;
+        jobs.push(BatchJob {
+            job_description_id: Some(job_id),
+            index: None,
+            text,
+        });
+    }
+    for (index, text) in req.job_descriptions.iter().enumerate() {
+        if text.trim().is_empty() {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!("job_descriptions[{}] is empty", index),
+            ));
+        }
+        jobs.push(BatchJob {
+            job_description_id: None,
+            index: Some(index),
+            text: text.clone(),
+        });
+    }
+
+    let started = Instant::now();
+    let report = BatchScorer::score(&resume, jobs)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let latency = started.elapsed();
+
+    let mut response = BatchScoreResponse {
+        report,
+        analysis_id: None,
+    };
+    response.analysis_id = record_run(
+        &state,
+        user_id,
+        AnalysisKind::BatchScore,
+        req.resume_version_id,
+        None,
+        &response,
+        latency,
+    )
+    .await?;
+
+    Ok(Json(response))
+}
+
+/// 基于 BM25 权重的相关度
+pub async fn relevance(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<RelevanceRequest>,
+) -> Result<Json<RelevanceResponse>, (StatusCode, String)> {
+    let user_id = optional_user_id_from_headers(&headers)?;
+    let resume = resolve_resume(&state, user_id, &req.resume, req.resume_version_id).await?;
+    let jd = resolve_job_description(&state, user_id, &req.job_description, req.job_description_id).await?;
+
+    let corpus = load_corpus(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let started = Instant::now();
+    let report = RelevanceScorer::score(&resume, &jd, &corpus);
+    let latency = started.elapsed();
+
+    let mut response = RelevanceResponse {
+        report,
+        analysis_id: None,
+    };
+    response.analysis_id = record_run(
+        &state,
+        user_id,
+        AnalysisKind::Relevance,
+        req.resume_version_id,
+        req.job_description_id,
+        &response,
+        latency,
+    )
+    .await?;
+
+    Ok(Json(response))
+}
+
+/// 工作经历时间线：总年限、技能年限、空档和重叠
+pub async fn timeline(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<TimelineRequest>,
+) -> Result<Json<TimelineResponse>, (StatusCode, String)> {
+    let user_id = optional_user_id_from_headers(&headers)?;
+    let resume = resolve_resume(&state, user_id, &req.resume, req.resume_version_id).await?;
+
+    let started = Instant::now();
+    let timeline = TimelineAnalyzer::analyze(&ResumeDocument::parse(&resume));
+    let latency = started.elapsed();
+
+    let mut response = TimelineResponse {
+        timeline,
+        analysis_id: None,
+    };
+    response.analysis_id = record_run(
+        &state,
+        user_id,
+        AnalysisKind::Timeline,
+        req.resume_version_id,
+        None,
+        &response,
+        latency,
+    )
+    .await?;
+
+    Ok(Json(response))
+}
+
+/// 逐条分析经历要点：量化数据、动词、被动语态和模糊表述
+pub async fn analyze_bullets(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<BulletAnalysisRequest>,
+) -> Result<Json<BulletAnalysisResponse>, (StatusCode, String)> {
+    let user_id = optional_user_id_from_headers(&headers)?;
+    let resume = resolve_resume(&state, user_id, &req.resume, req.resume_version_id).await?;
+
+    let started = Instant::now();
+    let report = BulletAnalyzer::analyze(&ResumeDocument::parse(&resume));
+    let latency = started.elapsed();
+
+    let mut response = BulletAnalysisResponse {
+        report,
+        analysis_id: None,
+    };
+    response.analysis_id = record_run(
+        &state,
+        user_id,
+        AnalysisKind::Bullets,
+        req.resume_version_id,
+        None,
+        &response,
+        latency,
+    )
+    .await?;
+
+    Ok(Json(response))
+}
+
+/// 英文拼写、中文错别字、时态和标点检查
+pub async fn proofread(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<ProofreadRequest>,
+) -> Result<Json<ProofreadResponse>, (StatusCode, String)> {
+    let user_id = optional_user_id_from_headers(&headers)?;
+    let resume = resolve_resume(&state, user_id, &req.resume, req.resume_version_id).await?;
+
+    let started = Instant::now();
+    let findings = Proofreader::check(&ResumeDocument::parse(&resume));
+    let latency = started.elapsed();
+
+    let mut response = ProofreadResponse {
+        findings,
+        analysis_id: None,
+    };
+    response.analysis_id = record_run(
+        &state,
+        user_id,
+        AnalysisKind::Proofread,
+        req.resume_version_id,
+        None,
+        &response,
+        latency,
+    )
+    .await?;
+
+    Ok(Json(response))
+}
+
+/// 格式一致性检查，并返回按规则统一格式后的全文
+pub async fn lint(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<LintRequest>,
+) -> Result<Json<LintResponse>, (StatusCode, String)> {
+    let user_id = optional_user_id_from_headers(&headers)?;
+    let resume = resolve_resume(&state, user_id, &req.resume, req.resume_version_id).await?;
+
+    let started = Instant::now();
+    let report = ResumeLinter::lint(&ResumeDocument::parse(&resume), &req.rules);
+    let latency = started.elapsed();
+
+    let mut response = LintResponse {
+        report,
+        analysis_id: None,
+    };
+    response.analysis_id = record_run(
+        &state,
+        user_id,
+        AnalysisKind::Lint,
+        req.resume_version_id,
+        None,
+        &response,
+        latency,
+    )
+    .await?;
+
+    Ok(Json(response))
+}
+
+/// 去掉简历中的个人信息；映射只返回给调用方，运行结果不保存
+pub async fn redact(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<RedactRequest>,
+) -> Result<Json<RedactResponse>, (StatusCode, String)> {
+    let user_id = optional_user_id_from_headers(&headers)?;
+    let resume = resolve_resume(&state, user_id, &req.resume, req.resume_version_id).await?;
+
+    let mut redactor = Redactor::new(&req.kinds);
+    let text = redactor.redact_resume(&resume);
+
+    Ok(Json(RedactResponse {
+        text,
+        mapping: redactor.into_mapping(),
+    }))
+}
+
+/// 用脱敏时返回的映射还原文本
+pub async fn restore(Json(req): Json<RestoreRequest>) -> Json<RestoreResponse> {
+    let (text, unresolved) = Redactor::restore(&req.text, &req.mapping);
+    Json(RestoreResponse { text, unresolved })
+}
+
+/// 简历中英互译，保持结构；词表译名和锁定术语在 terms 中列出
+pub async fn translate(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<TranslateRequest>,
+) -> Result<Json<TranslateResponse>, (StatusCode, String)> {
+    let user_id = optional_user_id_from_headers(&headers)?;
+    let resume = resolve_resume(&state, user_id, &req.resume, req.resume_version_id).await?;
+    if detect_language(&resume) == req.target {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Resume is already in the target language".to_string(),
+        ));
+    }
+
+    let started = Instant::now();
+    let result = ResumeTranslator::translate(&resume, req.target, &req.locked_terms, &req.glossary).await;
+    let latency = started.elapsed();
+
+    let mut response = TranslateResponse {
+        result,
+        analysis_id: None,
+    };
+    response.analysis_id = record_run(
+        &state,
+        user_id,
+        AnalysisKind::Translate,
+        req.resume_version_id,
+        None,
+        &response,
+        latency,
+    )
+    .await?;
+
+    Ok(Json(response))
+}
+
+/// 简历缺少的技能，按 JD 权重和在已保存 JD 中的出现频率排序，附学习资源
+pub async fn skill_gaps(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(resume_id): Path<Uuid>,
+    Query(query): Query<SkillGapQuery>,
+) -> Result<Json<SkillGapResponse>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+    let pool = &state.db;
+
+    let resume = find_resume(pool, user_id, resume_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Resume not found".to_string()))?;
+    let version = match query.version_id.or(resume.current_version_id) {
+        Some(version_id) => find_version(pool, resume_id, version_id)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?,
+        None => None,
+    }
+    .ok_or((StatusCode::NOT_FOUND, "Version not found".to_string()))?;
+
+    let target = match query.job_description_id {
+        Some(job_id) => Some(
+            find_job_description(pool, user_id, job_id)
+                .await
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+                .ok_or((StatusCode::NOT_FOUND, "Job description not found".to_string()))?,
+        ),
+        None => None,
+    };
+    let saved = sqlx::query_as::<_, JobDescription>(
+        "SELECT * FROM job_descriptions WHERE user_id = $1 ORDER BY created_at DESC LIMIT $2",
+    )
+    .bind(user_id)
+    .bind(MAX_SAVED_JOBS)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let started = Instant::now();
+    let target = target.map(|job| JdParser::parse(&job.raw_text));
+    let saved: Vec<ParsedJobDescription> = saved.iter().map(|job| JdParser::parse(&job.raw_text)).collect();
+    let gaps = SkillGapAnalyzer::report(&ResumeDocument::parse(&version.content), target.as_ref(), &saved);
+    let latency = started.elapsed();
+
+    let mut response = SkillGapResponse {
+        resume_id,
+        version_id: version.id,
+        job_description_id: query.job_description_id,
+        saved_jobs: saved.len(),
+        gaps,
+        analysis_id: None,
+    };
+    response.analysis_id = record_run(
+        &state,
+        Some(user_id),
+        AnalysisKind::Gaps,
+        Some(version.id),
+        query.job_description_id,
+        &response,
+        latency,
+    )
+    .await?;
+
+    Ok(Json(response))
+}
+
+/// 将经历要点改写为 STAR 结构，并融入 JD 关键词
+pub async fn rewrite_bullets(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<RewriteBulletsRequest>,
+) -> Result<Json<RewriteBulletsResponse>, (StatusCode, String)> {
+    let user_id = optional_user_id_from_headers(&headers)?;
+    let resume = resolve_resume(&state, user_id, &req.resume, req.resume_version_id).await?;
+    let jd = resolve_job_description(&state, user_id, &req.job_description, req.job_description_id).await?;
+
+    let started = Instant::now();
+    let result = BulletRewriter::rewrite(&resume, &jd, &req.lines, req.alternatives).await;
+    let latency = started.elapsed();
+
+    let mut response = RewriteBulletsResponse {
+        bullets: result.bullets,
+        keywords: result.keywords,
+        provider: result.provider,
+        model: result.model,
+        analysis_id: None,
+    };
+    response.analysis_id = record_run_with(
+        &state,
+        user_id,
+        AnalysisKind::Rewrite,
+        req.resume_version_id,
+        req.job_description_id,
+        &response,
+        latency,
+        (&response.provider, &response.model),
+    )
+    .await?;
+
+    Ok(Json(response))
 }
 
 pub async fn generate_cover_letter(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
     Json(req): Json<CoverLetterRequest>,
-) -> Json<CoverLetterResponse> {
-    let letter = ResumeOptimizer::generate_cover_letter(&req.resume, &req.job_description).await;
+) -> Result<Json<CoverLetterResponse>, (StatusCode, String)> {
+    let user_id = optional_user_id_from_headers(&headers)?;
+    let resume = resolve_resume(&state, user_id, &req.resume, req.resume_version_id).await?;
+    let jd = resolve_job_description(&state, user_id, &req.job_description, req.job_description_id).await?;
+
+    let started = Instant::now();
+    let letter = ResumeOptimizer::generate_cover_letter(&resume, &jd).await;
+    let latency = started.elapsed();
     
-    Json(CoverLetterResponse {
+    let mut response = CoverLetterResponse {
         cover_letter: letter,
-    })
+        analysis_id: None,
+    };
+    response.analysis_id = record_run(
+        &state,
+        user_id,
+        AnalysisKind::CoverLetter,
+        req.resume_version_id,
+        req.job_description_id,
+        &response,
+        latency,
+    )
+    .await?;
+
+    Ok(Json(response))
 }
 
 pub async fn extract_keywords(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
     Json(req): Json<ExtractKeywordsRequest>,
-) -> Json<ExtractKeywordsResponse> {
-    let keywords = ResumeOptimizer::extract_keywords(&req.text).await;
+) -> Result<Json<ExtractKeywordsResponse>, (StatusCode, String)> {
+    let user_id = optional_user_id_from_headers(&headers)?;
+    let text = match (req.resume_version_id, req.job_description_id) {
+        (Some(version_id), _) => resolve_resume(&state, user_id, "", Some(version_id)).await?,
+        (None, Some(job_id)) => resolve_job_description(&state, user_id, "", Some(job_id)).await?,
+        (None, None) if req.text.trim().is_empty() => {
+            return Err((StatusCode::BAD_REQUEST, "text is required".to_string()));
+        }
+        (None, None) => req.text.clone(),
+    };
+
+    let started = Instant::now();
+    let keywords = ResumeOptimizer::extract_keywords(&text).await;
+    let latency = started.elapsed();
     
-    Json(ExtractKeywordsResponse {
+    let mut response = ExtractKeywordsResponse {
         keywords,
-    })
+        analysis_id: None,
+    };
+    response.analysis_id = record_run(
+        &state,
+        user_id,
+        AnalysisKind::Keywords,
+        req.resume_version_id,
+        req.job_description_id,
+        &response,
+        latency,
+    )
+    .await?;
+
+    Ok(Json(response))
+}
+
+// ========== 辅助方法 ==========
+
+/// 优先使用引用的简历版本，否则使用请求中的文本
+async fn resolve_resume(
+    state: &AppState,
+    user_id: Option<Uuid>,
+    inline: &str,
+    version_id: Option<Uuid>,
+) -> Result<String, (StatusCode, String)> {
+    match version_id {
+        Some(version_id) => {
+            let user_id = user_id.ok_or((StatusCode::UNAUTHORIZED, "Missing auth token".to_string()))?;
+            find_user_version(&state.db, user_id, version_id)
+                .await
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+                .map(|version| version.content)
+                .ok_or((StatusCode::NOT_FOUND, "Resume version not found".to_string()))
+        }
+        None if inline.trim().is_empty() => Err((
+            StatusCode::BAD_REQUEST,
+            "resume or resume_version_id is required".to_string(),
+        )),
+        None => Ok(inline.to_string()),
+    }
+}
+
+/// 优先使用引用的 JD，否则使用请求中的文本
+async fn resolve_job_description(
+    state: &AppState,
+    user_id: Option<Uuid>,
+    inline: &str,
+    job_id: Option<Uuid>,
+) -> Result<String, (StatusCode, String)> {
+    match job_id {
+        Some(job_id) => {
+            let user_id = user_id.ok_or((StatusCode::UNAUTHORIZED, "Missing auth token".to_string()))?;
+            find_job_description(&state.db, user_id, job_id)
+                .await
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+                .map(|job| job.raw_text)
+                .ok_or((StatusCode::NOT_FOUND, "Job description not found".to_string()))
+        }
+        None if inline.trim().is_empty() => Err((
+            StatusCode::BAD_REQUEST,
+            "job_description or job_description_id is required".to_string(),
+        )),
+        None => Ok(inline.to_string()),
+    }
+}
+
+/// 登录用户的运行结果会被保存，匿名调用不保存
+async fn record_run<T: Serialize>(
+    state: &AppState,
+    user_id: Option<Uuid>,
+    kind: AnalysisKind,
+    resume_version_id: Option<Uuid>,
+    job_description_id: Option<Uuid>,
+    output: &T,
+    latency: Duration,
+) -> Result<Option<Uuid>, (StatusCode, String)> {
+    record_run_with(
+        state,
+        user_id,
+        kind,
+        resume_version_id,
+        job_description_id,
+        output,
+        latency,
+        (BUILTIN_PROVIDER, BUILTIN_MODEL),
+    )
+    .await
+}
+
+/// 同 record_run，记录实际使用的模型
+#[allow(clippy::too_many_arguments)]
+async fn record_run_with<T: Serialize>(
+    state: &AppState,
+    user_id: Option<Uuid>,
+    kind: AnalysisKind,
+    resume_version_id: Option<Uuid>,
+    job_description_id: Option<Uuid>,
+    output: &T,
+    latency: Duration,
+    (provider, model): (&str, &str),
+) -> Result<Option<Uuid>, (StatusCode, String)> {
+    let Some(user_id) = user_id else {
+        return Ok(None);
+    };
+
+    let output = serde_json::to_value(output)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let analysis_id = record_analysis(
+        &state.db,
+        NewAnalysis {
+            user_id,
+            kind,
+            resume_version_id,
+            job_description_id,
+            output,
+            provider,
+            model,
+            latency_ms: latency.as_millis() as i32,
+        },
+    )
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Some(analysis_id))
 }
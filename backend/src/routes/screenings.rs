@@ -0,0 +1,187 @@
+use axum::{
+    extract::{Multipart, Path, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use uuid::Uuid;
+
+use crate::models::*;
+use crate::services::jd_parser::{find_job_description, MAX_NAME_CHARS};
+use crate::services::screening::{
+    candidates_to_csv, count_active_jobs, extract_resumes, find_screening_job, run_screening, ScreeningError,
+    MAX_ACTIVE_JOBS,
+};
+use crate::services::JdParser;
+use crate::utils::auth::user_id_from_headers;
+use crate::utils::AppState;
+
+/// 上传压缩包的大小上限
+pub const MAX_ARCHIVE_BYTES: usize = 50 * 1024 * 1024;
+
+/// 上传简历压缩包并按一个 JD 排序，任务在后台运行
+///
+/// multipart 字段：file（PDF / DOCX 简历的 zip 包，必填）、job_description 或 job_description_id
+pub async fn create(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, Json<ScreeningJobSummary>), (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+
+    let active = count_active_jobs(&app_state.db, user_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if active >= MAX_ACTIVE_JOBS {
+        return Err((
+            StatusCode::TOO_MANY_REQUESTS,
+            format!("At most {} screening jobs can run at the same time", MAX_ACTIVE_JOBS),
+        ));
+    }
+
+    let mut archive = None;
+    let mut job_description = String::new();
+    let mut job_description_id = None;
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?
+    {
+        match field.name() {
+            Some("file") => {
+                let bytes = field.bytes().await.map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+                archive = Some(bytes);
+            }
+            Some("job_description") => {
+                job_description = field.text().await.map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+            }
+            Some("job_description_id") => {
+                let id = field.text().await.map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+                let id = Uuid::parse_str(id.trim())
+                    .map_err(|_| (StatusCode::BAD_REQUEST, "Invalid job_description_id".to_string()))?;
+                job_description_id = Some(id);
+            }
+            _ => {}
+        }
+    }
+    let archive = archive.ok_or((StatusCode::BAD_REQUEST, "file is required".to_string()))?;
+
+    let (jd, saved_title) = match job_description_id {
+        Some(job_id) => {
+            let job = find_job_description(&app_state.db, user_id, job_id)
+                .await
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+                .ok_or((StatusCode::NOT_FOUND, "Job description not found".to_string()))?;
+            (job.raw_text, job.title)
+        }
+        None if job_description.trim().is_empty() => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                "job_description or job_description_id is required".to_string(),
+            ))
+        }
+        None => (job_description, None),
+    };
+    // 职位名写入 VARCHAR(200) 列，解压前先截断
+    let job_title = saved_title
+        .or_else(|| JdParser::parse(&jd).title)
+        .map(|title| title.chars().take(MAX_NAME_CHARS).collect::<String>());
+
+    let files = tokio::task::spawn_blocking(move || extract_resumes(&archive))
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .map_err(|e| match e {
+            ScreeningError::Archive(_) => (StatusCode::UNPROCESSABLE_ENTITY, e.to_string()),
+            ScreeningError::FileTooLarge(_) | ScreeningError::TooLarge => (StatusCode::PAYLOAD_TOO_LARGE, e.to_string()),
+            _ => (StatusCode::BAD_REQUEST, e.to_string()),
+        })?;
+
+    let job = sqlx::query_as::<_, ScreeningJobSummary>(
+        r#"
+        INSERT INTO screening_jobs (id, user_id, job_description_id, job_title, status, total)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id, job_description_id, job_title, status, total, processed, error, created_at, completed_at
+        "#,
+    )
+    .bind(Uuid::new_v4())
+    .bind(user_id)
+    .bind(job_description_id)
+    .bind(job_title)
+    .bind(ScreeningStatus::Pending.as_str())
+    .bind(files.len() as i32)
+    .fetch_one(&app_state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    tokio::spawn(run_screening(app_state.db.clone(), job.id, jd, files));
+
+    Ok((StatusCode::ACCEPTED, Json(job)))
+}
+
+pub async fn list(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<ScreeningJobSummary>>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+
+    let jobs = sqlx::query_as::<_, ScreeningJobSummary>(
+        r#"
+        SELECT id, job_description_id, job_title, status, total, processed, error, created_at, completed_at
+        FROM screening_jobs
+        WHERE user_id = $1
+        ORDER BY created_at DESC
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(&app_state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(jobs))
+}
+
+/// 任务进度（processed / total）；完成后 candidates 为排序后的候选人
+pub async fn get(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(job_id): Path<Uuid>,
+) -> Result<Json<ScreeningJob>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+
+    let job = find_screening_job(&app_state.db, user_id, job_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Screening job not found".to_string()))?;
+
+    Ok(Json(job))
+}
+
+/// 导出排序结果为 CSV，任务完成后可用
+pub async fn export(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(job_id): Path<Uuid>,
+) -> Result<Response, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+
+    let job = find_screening_job(&app_state.db, user_id, job_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Screening job not found".to_string()))?;
+    if job.status != ScreeningStatus::Completed.as_str() {
+        return Err((StatusCode::CONFLICT, "Screening job is not completed".to_string()));
+    }
+
+    let body = candidates_to_csv(&job.candidates).map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"screening-{}.csv\"", job.id),
+            ),
+        ],
+        body,
+    )
+        .into_response())
+}
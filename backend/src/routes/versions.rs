@@ -0,0 +1,231 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use uuid::Uuid;
+
+use crate::models::*;
+use crate::services::versions::{append_version, append_version_in, find_resume, find_version};
+use crate::services::VersionDiffer;
+use crate::utils::auth::user_id_from_headers;
+use crate::utils::AppState;
+
+pub async fn create_resume(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<CreateResumeRequest>,
+) -> Result<Json<ResumeDetailResponse>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+    let pool = &app_state.db;
+
+    if payload.title.trim().is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "Title is required".to_string()));
+    }
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    // 简历与首个版本同事务写入，避免留下没有版本的空简历
+    let resume_id = Uuid::new_v4();
+    sqlx::query("INSERT INTO resumes (id, user_id, title) VALUES ($1, $2, $3)")
+        .bind(resume_id)
+        .bind(user_id)
+        .bind(payload.title.trim())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let version = append_version_in(&mut tx, resume_id, VersionSource::ManualEdit, &payload.content, None)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let resume = find_resume(pool, user_id, resume_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Resume not found".to_string()))?;
+
+    Ok(Json(ResumeDetailResponse {
+        resume,
+        current_version: Some(version),
+    }))
+}
+
+pub async fn list_resumes(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<Resume>>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+
+    let resumes = sqlx::query_as::<_, Resume>(
+        "SELECT * FROM resumes WHERE user_id = $1 ORDER BY updated_at DESC",
+    )
+    .bind(user_id)
+    .fetch_all(&app_state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(resumes))
+}
+
+pub async fn get_resume(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(resume_id): Path<Uuid>,
+) -> Result<Json<ResumeDetailResponse>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+    let pool = &app_state.db;
+
+    let resume = find_resume(pool, user_id, resume_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Resume not found".to_string()))?;
+
+    let current_version = match resume.current_version_id {
+        Some(version_id) => find_version(pool, resume.id, version_id)
+            .await
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?,
+        None => None,
+    };
+
+    Ok(Json(ResumeDetailResponse { resume, current_version }))
+}
+
+pub async fn save_version(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(resume_id): Path<Uuid>,
+    Json(payload): Json<SaveVersionRequest>,
+) -> Result<Json<ResumeVersion>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+    let pool = &app_state.db;
+
+    find_resume(pool, user_id, resume_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Resume not found".to_string()))?;
+
+    let version = append_version(
+        pool,
+        resume_id,
+        VersionSource::ManualEdit,
+        &payload.content,
+        payload.note.as_deref(),
+    )
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(version))
+}
+
+pub async fn list_versions(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(resume_id): Path<Uuid>,
+) -> Result<Json<Vec<ResumeVersion>>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+    let pool = &app_state.db;
+
+    find_resume(pool, user_id, resume_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Resume not found".to_string()))?;
+
+    let versions = sqlx::query_as::<_, ResumeVersion>(
+        "SELECT * FROM resume_versions WHERE resume_id = $1 ORDER BY version_number DESC",
+    )
+    .bind(resume_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(versions))
+}
+
+pub async fn get_version(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path((resume_id, version_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ResumeVersion>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+    let pool = &app_state.db;
+
+    find_resume(pool, user_id, resume_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Resume not found".to_string()))?;
+
+    let version = find_version(pool, resume_id, version_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Version not found".to_string()))?;
+
+    Ok(Json(version))
+}
+
+pub async fn diff_versions(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path(resume_id): Path<Uuid>,
+    Query(query): Query<DiffQuery>,
+) -> Result<Json<VersionDiffResponse>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+    let pool = &app_state.db;
+
+    find_resume(pool, user_id, resume_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Resume not found".to_string()))?;
+
+    let from = find_version(pool, resume_id, query.from)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Version not found".to_string()))?;
+
+    let to = find_version(pool, resume_id, query.to)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Version not found".to_string()))?;
+
+    let (chunks, stats) = VersionDiffer::diff(&from.content, &to.content, query.granularity);
+
+    Ok(Json(VersionDiffResponse {
+        from: from.id,
+        to: to.id,
+        chunks,
+        stats,
+    }))
+}
+
+/// 恢复到指定版本：以该版本内容追加一个新版本，历史保持不变
+pub async fn restore_version(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    Path((resume_id, version_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ResumeVersion>, (StatusCode, String)> {
+    let user_id = user_id_from_headers(&headers)?;
+    let pool = &app_state.db;
+
+    find_resume(pool, user_id, resume_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Resume not found".to_string()))?;
+
+    let target = find_version(pool, resume_id, version_id)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or((StatusCode::NOT_FOUND, "Version not found".to_string()))?;
+
+    let note = format!("恢复自版本 {}", target.version_number);
+    let version = append_version(pool, resume_id, VersionSource::Restore, &target.content, Some(&note))
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(version))
+}
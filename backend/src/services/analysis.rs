@@ -0,0 +1,49 @@
+use sqlx::types::Json;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::*;
+
+/// 内置规则引擎
+pub const BUILTIN_PROVIDER: &str = "builtin";
+pub const BUILTIN_MODEL: &str = "rules-v1";
+
+pub struct NewAnalysis<'a> {
+    pub user_id: Uuid,
+    pub kind: AnalysisKind,
+    pub resume_version_id: Option<Uuid>,
+    pub job_description_id: Option<Uuid>,
+    pub output: serde_json::Value,
+    pub provider: &'a str,
+    pub model: &'a str,
+    pub latency_ms: i32,
+}
+
+/// 保存一次分析结果，记录本次运行对应的使用次数
+pub async fn record_analysis(pool: &PgPool, analysis: NewAnalysis<'_>) -> Result<Uuid, sqlx::Error> {
+    let analysis_id = Uuid::new_v4();
+
+    sqlx::query(
+        r#"
+        INSERT INTO analyses (
+            id, user_id, kind, resume_version_id, job_description_id,
+            output, provider, model, latency_ms, credits_charged
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        "#,
+    )
+    .bind(analysis_id)
+    .bind(analysis.user_id)
+    .bind(analysis.kind.as_str())
+    .bind(analysis.resume_version_id)
+    .bind(analysis.job_description_id)
+    .bind(Json(&analysis.output))
+    .bind(analysis.provider)
+    .bind(analysis.model)
+    .bind(analysis.latency_ms)
+    .bind(analysis.kind.credits())
+    .execute(pool)
+    .await?;
+
+    Ok(analysis_id)
+}
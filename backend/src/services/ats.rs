@@ -0,0 +1,739 @@
+use std::collections::{BTreeSet, HashMap};
+use std::io::{Cursor, Read};
+use std::panic;
+use std::sync::LazyLock;
+
+use pdf_extract::{Dictionary, Document, Object, ObjectId};
+use regex::Regex;
+use zip::result::ZipError;
+use zip::ZipArchive;
+
+use crate::models::*;
+use crate::services::{CompletenessChecker, ResumeDocument};
+
+/// 超过该页数提示篇幅过长
+const MAX_PAGES: u32 = 2;
+
+/// 无法得到页数时，超过该字数提示篇幅过长
+const MAX_CHARACTERS: usize = 6000;
+
+/// 可提取文字少于该数的页视为图片页
+const MIN_PAGE_CHARACTERS: usize = 50;
+
+/// DOCX 中单个 XML 部件最多读取的字节数
+const MAX_PART_BYTES: u64 = 20 * 1024 * 1024;
+
+/// DOCX 中所有 XML 部件合计最多读取的字节数
+const MAX_DOCX_BYTES: u64 = 50 * 1024 * 1024;
+
+/// 沿页面树向上查找 MediaBox 的最大层数，防止 Parent 成环
+const MAX_PAGE_TREE_DEPTH: usize = 32;
+
+/// A4 页面尺寸，页面未声明 MediaBox 时使用
+const DEFAULT_PAGE_SIZE: (f32, f32) = (595.0, 842.0);
+
+/// ATS 普遍支持的字体（小写、去掉空格和符号后按前缀匹配）
+const COMMON_FONTS: &[&str] = &[
+    "arial", "helvetica", "times", "calibri", "cambria", "georgia", "garamond", "verdana", "tahoma", "trebuchet",
+    "segoe", "roboto", "opensans", "lato", "sourcesans", "sourceserif", "notosans", "notoserif", "bookantiqua",
+    "palatino", "centurygothic", "gillsans", "futura", "avenir", "sfpro", "courier", "consolas", "menlo", "symbol",
+    "wingdings", "zapfdingbats", "lmroman", "cmr", "cmbx", "cmti", "cmsy", "cmmi", "latinmodern", "dejavu",
+    "liberation", "inter", "montserrat", "raleway", "aptos",
+    "simsun", "nsimsun", "simhei", "microsoftyahei", "msyh", "kaiti", "fangsong", "dengxian", "stsong", "stheiti",
+    "stkaiti", "stfangsong", "stxihei", "pingfang", "hiragino", "sourcehansans", "sourcehanserif", "adobesong",
+    "adobeheiti", "adobekaiti", "adobefangsong", "wenquanyi", "wqy", "harmonyos", "alibabapuhuiti",
+    "宋体", "新宋体", "黑体", "微软雅黑", "楷体", "仿宋", "等线", "苹方", "思源",
+];
+
+/// 页眉页脚部件，如 word/header1.xml
+static DOCX_HEADER_FOOTER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^word/(?:header|footer)\d*\.xml$").unwrap());
+
+/// 兼容旧版 Word 的备用内容，与正文中的文本框重复
+static DOCX_FALLBACK: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<mc:Fallback>.*?</mc:Fallback>").unwrap());
+
+static DOCX_TEXT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<w:t(?:\s[^>]*)?>([^<]*)</w:t>|<w:tab/>|<w:br(?:\s[^>]*)?/>|</w:p>").unwrap());
+
+static DOCX_TABLE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<w:tbl>").unwrap());
+
+static DOCX_COLUMNS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"<w:cols\b[^>]*\bw:num="(\d+)""#).unwrap());
+
+static DOCX_TEXT_BOX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<w:txbxContent>").unwrap());
+
+static DOCX_IMAGE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<pic:pic\b|<v:imagedata\b").unwrap());
+
+static DOCX_FONT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"\bw:(?:ascii|hAnsi|eastAsia|cs)="([^"]+)""#).unwrap());
+
+static DOCX_PAGES: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<Pages>(\d+)</Pages>").unwrap());
+
+#[derive(Debug, thiserror::Error)]
+pub enum AtsError {
+    #[error("Unsupported file type, expected PDF or DOCX")]
+    Unsupported,
+    #[error("Invalid PDF: {0}")]
+    Pdf(#[from] pdf_extract::Error),
+    #[error("Invalid DOCX: {0}")]
+    Docx(#[from] ZipError),
+    #[error("Invalid PDF: file could not be parsed")]
+    Corrupted,
+}
+
+/// 检查上传的 PDF / DOCX 简历能否被 ATS 正确解析，并提取纯文本用于评分
+pub struct AtsChecker;
+
+impl AtsChecker {
+    pub fn inspect(bytes: &[u8]) -> Result<AtsInspection, AtsError> {
+        let extracted = if bytes.windows(5).take(1024).any(|w| w == b"%PDF-") {
+            Self::inspect_pdf(bytes)?
+        } else if bytes.starts_with(b"PK\x03\x04") {
+            Self::inspect_docx(bytes)?
+        } else {
+            return Err(AtsError::Unsupported);
+        };
+
+        let Extracted {
+            file_kind,
+            text,
+            pages,
+            mut issues,
+        } = extracted;
+        let characters = text.chars().filter(|c| !c.is_whitespace()).count();
+        Self::text_issues(&text, characters, pages, &mut issues);
+
+        let penalty: u32 = issues
+            .iter()
+            .map(|i| match i.severity {
+                FindingSeverity::Error => 30,
+                FindingSeverity::Warning => 10,
+                FindingSeverity::Info => 2,
+            })
+            .sum();
+
+        Ok(AtsInspection {
+            text,
+            report: AtsReport {
+                file_kind,
+                pages,
+                characters,
+                score: 100u32.saturating_sub(penalty) as u8,
+                issues,
+            },
+        })
+    }
+
+    // ========== PDF ==========
+
+    fn inspect_pdf(bytes: &[u8]) -> Result<Extracted, AtsError> {
+        // lopdf 遇到个别损坏的文件会 panic，按无效 PDF 处理
+        let doc = panic::catch_unwind(|| Document::load_mem(bytes)).map_err(|_| AtsError::Corrupted)??;
+        let pages = doc.get_pages();
+        let page_count = Some(pages.len() as u32);
+        let mut issues = Vec::new();
+
+        // pdf-extract 遇到个别字体会 panic，按无法提取处理
+        let page_texts = panic::catch_unwind(|| pdf_extract::extract_text_from_mem_by_pages(bytes))
+            .ok()
+            .and_then(Result::ok);
+        let Some(page_texts) = page_texts else {
+            let (kind, message) = if doc.is_encrypted() {
+                (AtsIssueKind::Encrypted, "PDF 已加密，ATS 无法读取，请导出未加密的版本")
+            } else {
+                (AtsIssueKind::UnreadableText, "无法从 PDF 中提取文字，请用 Word 或其他工具重新导出 PDF")
+            };
+            issues.push(issue(kind, FindingSeverity::Error, message.to_string(), None, None));
+            return Ok(Extracted {
+                file_kind: ResumeFileKind::Pdf,
+                text: String::new(),
+                pages: page_count,
+                issues,
+            });
+        };
+
+        let mut images = 0;
+        let mut fonts = BTreeSet::new();
+        let mut type3 = false;
+        for (&number, &page_id) in &pages {
+            let text = page_texts.get(number as usize - 1).map(String::as_str).unwrap_or_default();
+            let characters = text.chars().filter(|c| !c.is_whitespace()).count();
+            let layout = PdfLayout::read(&doc, page_id);
+
+            let page_images = Self::pdf_images(&doc, page_id) + layout.inline_images;
+            if page_images > 0 && characters < MIN_PAGE_CHARACTERS {
+                issues.push(issue(
+                    AtsIssueKind::ImageText,
+                    FindingSeverity::Error,
+                    "该页几乎没有可提取的文字，内容可能是图片或扫描件，ATS 无法识别，请从原文档直接导出 PDF".to_string(),
+                    Some(number),
+                    None,
+                ));
+            } else {
+                images += page_images;
+            }
+
+            if layout.has_table() {
+                issues.push(issue(
+                    AtsIssueKind::Table,
+                    FindingSeverity::Warning,
+                    "该页含有表格，ATS 可能打乱单元格顺序，建议改为普通段落".to_string(),
+                    Some(number),
+                    None,
+                ));
+            }
+            if layout.has_columns(Self::page_size(&doc, page_id).0) {
+                issues.push(issue(
+                    AtsIssueKind::MultiColumn,
+                    FindingSeverity::Warning,
+                    "该页为多栏排版，ATS 可能把左右两栏的内容混在一起，建议改为单栏".to_string(),
+                    Some(number),
+                    None,
+                ));
+            }
+
+            for font in doc.get_page_fonts(page_id).unwrap_or_default().values() {
+                type3 |= font.get(b"Subtype").and_then(Object::as_name).is_ok_and(|n| n == b"Type3");
+                if let Ok(name) = font.get(b"BaseFont").and_then(Object::as_name) {
+                    // 编码无法识别的字体名（如 GBK 编码的中文名）不作判断
+                    if let Ok(name) = std::str::from_utf8(name) {
+                        fonts.insert(name.to_string());
+                    }
+                }
+            }
+        }
+
+        if images > 0 {
+            issues.push(image_notice(images));
+        }
+        if type3 {
+            issues.push(issue(
+                AtsIssueKind::UnusualFont,
+                FindingSeverity::Warning,
+                "部分文字使用 Type 3 字体（字形以图形绘制），ATS 可能无法识别，建议改用常用字体重新导出".to_string(),
+                None,
+                None,
+            ));
+        }
+        issues.extend(Self::font_issues(fonts));
+
+        // 每页重复出现的行视为页眉页脚
+        let mut seen: HashMap<&str, usize> = HashMap::new();
+        for text in &page_texts {
+            let lines: BTreeSet<&str> = text.lines().map(str::trim).filter(|l| !l.is_empty()).collect();
+            for line in lines {
+                *seen.entry(line).or_default() += 1;
+            }
+        }
+        let repeated: Vec<&str> = seen.into_iter().filter(|(_, n)| *n > 1).map(|(line, _)| line).collect();
+        issues.extend(Self::header_footer_issues(&repeated.join("\n")));
+
+        Ok(Extracted {
+            file_kind: ResumeFileKind::Pdf,
+            text: page_texts.join("\n"),
+            pages: page_count,
+            issues,
+        })
+    }
+
+    /// 页面资源中的图片数
+    fn pdf_images(doc: &Document, page_id: ObjectId) -> usize {
+        let Ok((resources, inherited)) = doc.get_page_resources(page_id) else {
+            return 0;
+        };
+        let inherited: Vec<&Dictionary> = inherited.iter().filter_map(|id| doc.get_dictionary(*id).ok()).collect();
+
+        resources
+            .into_iter()
+            .chain(inherited)
+            .filter_map(|r| r.get(b"XObject").ok())
+            .filter_map(|x| doc.dereference(x).ok())
+            .filter_map(|(_, x)| x.as_dict().ok())
+            .flat_map(|xobjects| xobjects.iter())
+            .filter(|(_, value)| {
+                doc.dereference(value)
+                    .ok()
+                    .and_then(|(_, o)| o.as_stream().ok())
+                    .is_some_and(|s| s.dict.get(b"Subtype").and_then(Object::as_name).is_ok_and(|n| n == b"Image"))
+            })
+            .count()
+    }
+
+    /// 页面宽高，MediaBox 可能继承自上级页面树
+    fn page_size(doc: &Document, page_id: ObjectId) -> (f32, f32) {
+        let mut node = doc.get_dictionary(page_id).ok();
+        for _ in 0..MAX_PAGE_TREE_DEPTH {
+            let Some(dict) = node else {
+                break;
+            };
+            if let Ok(Object::Array(bounds)) = dict.get_deref(b"MediaBox", doc) {
+                let bounds: Vec<f32> = bounds.iter().filter_map(|b| b.as_float().ok()).collect();
+                if let [x0, y0, x1, y1] = bounds[..] {
+                    return ((x1 - x0).abs(), (y1 - y0).abs());
+                }
+            }
+            node = dict
+                .get(b"Parent")
+                .and_then(Object::as_reference)
+                .and_then(|id| doc.get_dictionary(id))
+                .ok();
+        }
+        DEFAULT_PAGE_SIZE
+    }
+
+    // ========== DOCX ==========
+
+    fn inspect_docx(bytes: &[u8]) -> Result<Extracted, AtsError> {
+        let mut archive = ZipArchive::new(Cursor::new(bytes))?;
+        let mut budget = MAX_DOCX_BYTES;
+        let document = read_part(&mut archive, "word/document.xml", &mut budget)?.ok_or(AtsError::Unsupported)?;
+        let styles = read_part(&mut archive, "word/styles.xml", &mut budget)?.unwrap_or_default();
+        let app = read_part(&mut archive, "docProps/app.xml", &mut budget)?.unwrap_or_default();
+
+        let document = DOCX_FALLBACK.replace_all(&document, "");
+        let text = docx_text(&document);
+        let characters = text.chars().filter(|c| !c.is_whitespace()).count();
+        let mut issues = Vec::new();
+
+        let images = DOCX_IMAGE.find_iter(&document).count();
+        if images > 0 && characters < MIN_PAGE_CHARACTERS {
+            issues.push(issue(
+                AtsIssueKind::ImageText,
+                FindingSeverity::Error,
+                "文档几乎没有可提取的文字，内容可能是图片，ATS 无法识别，请改为可编辑的文字".to_string(),
+                None,
+                None,
+            ));
+        } else if images > 0 {
+            issues.push(image_notice(images));
+        }
+
+        let tables = DOCX_TABLE.find_iter(&document).count();
+        if tables > 0 {
+            issues.push(issue(
+                AtsIssueKind::Table,
+                FindingSeverity::Warning,
+                format!("文档含有 {} 个表格，ATS 可能打乱单元格顺序，建议改为普通段落", tables),
+                None,
+                None,
+            ));
+        }
+
+        let columns = DOCX_COLUMNS
+            .captures_iter(&document)
+            .filter_map(|c| c[1].parse::<u32>().ok())
+            .max()
+            .unwrap_or(1);
+        if columns > 1 {
+            issues.push(issue(
+                AtsIssueKind::MultiColumn,
+                FindingSeverity::Warning,
+                format!("文档为 {} 栏排版，ATS 可能把各栏内容混在一起，建议改为单栏", columns),
+                None,
+                None,
+            ));
+        }
+
+        let text_boxes = DOCX_TEXT_BOX.find_iter(&document).count();
+        if text_boxes > 0 {
+            issues.push(issue(
+                AtsIssueKind::TextBox,
+                FindingSeverity::Warning,
+                format!("文档含有 {} 个文本框，部分 ATS 会跳过文本框中的内容，建议改为正文段落", text_boxes),
+                None,
+                None,
+            ));
+        }
+
+        let mut header_footer = String::new();
+        let parts: Vec<String> = archive
+            .file_names()
+            .filter(|name| DOCX_HEADER_FOOTER.is_match(name))
+            .map(str::to_string)
+            .collect();
+        for part in parts {
+            if budget == 0 {
+                break;
+            }
+            if let Some(xml) = read_part(&mut archive, &part, &mut budget)? {
+                header_footer.push_str(&docx_text(&DOCX_FALLBACK.replace_all(&xml, "")));
+            }
+        }
+        issues.extend(Self::header_footer_issues(&header_footer));
+
+        let fonts: BTreeSet<String> = DOCX_FONT
+            .captures_iter(&document)
+            .chain(DOCX_FONT.captures_iter(&styles))
+            .map(|c| unescape_xml(&c[1]))
+            .collect();
+        issues.extend(Self::font_issues(fonts));
+
+        Ok(Extracted {
+            file_kind: ResumeFileKind::Docx,
+            text,
+            pages: DOCX_PAGES.captures(&app).and_then(|c| c[1].parse().ok()),
+            issues,
+        })
+    }
+
+    // ========== 通用检查 ==========
+
+    /// 页眉页脚中的邮箱和手机号
+    fn header_footer_issues(text: &str) -> Vec<AtsIssue> {
+        if text.trim().is_empty() {
+            return Vec::new();
+        }
+        CompletenessChecker::contacts(&ResumeDocument::parse(text))
+            .into_iter()
+            .filter(|c| matches!(c.kind, ContactKind::Email | ContactKind::Phone))
+            .map(|c| {
+                issue(
+                    AtsIssueKind::HeaderFooterContact,
+                    FindingSeverity::Warning,
+                    format!("联系方式「{}」位于页眉或页脚，部分 ATS 会忽略页眉页脚，建议移到正文开头", c.value),
+                    None,
+                    Some(c.value),
+                )
+            })
+            .collect()
+    }
+
+    fn font_issues(fonts: BTreeSet<String>) -> Vec<AtsIssue> {
+        let mut unusual = BTreeSet::new();
+        for font in fonts {
+            // 去掉子集前缀和字重后缀，如 ABCDEF+Calibri,Bold
+            let name = font.split_once('+').filter(|(p, _)| p.len() == 6).map_or(font.as_str(), |(_, n)| n);
+            let name = name.split([',', '-']).next().unwrap_or(name).trim().to_string();
+            let key: String = name
+                .chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect();
+            if !key.is_empty() && !COMMON_FONTS.iter().any(|f| key.starts_with(f)) {
+                unusual.insert(name);
+            }
+        }
+
+        unusual
+            .into_iter()
+            .map(|name| {
+                issue(
+                    AtsIssueKind::UnusualFont,
+                    FindingSeverity::Warning,
+                    format!("字体「{}」不常见，部分 ATS 可能无法正确识别，建议改用 Arial、Calibri、宋体、微软雅黑等常用字体", name),
+                    None,
+                    Some(name),
+                )
+            })
+            .collect()
+    }
+
+    /// 乱码、小节标题和篇幅
+    fn text_issues(text: &str, characters: usize, pages: Option<u32>, issues: &mut Vec<AtsIssue>) {
+        let unreadable = text
+            .chars()
+            .filter(|c| *c == '\u{FFFD}' || ('\u{E000}'..='\u{F8FF}').contains(c) || (c.is_control() && !c.is_whitespace()))
+            .count();
+        let blocked = issues.iter().any(|i| i.severity == FindingSeverity::Error);
+        if characters > 0 && unreadable * 20 > characters {
+            issues.push(issue(
+                AtsIssueKind::UnreadableText,
+                FindingSeverity::Error,
+                format!(
+                    "提取出的文字约 {}% 为乱码，ATS 无法读取，常见原因是字体未嵌入或缺少 Unicode 映射，请改用常用字体重新导出",
+                    unreadable * 100 / characters
+                ),
+                None,
+                None,
+            ));
+        } else if characters < MIN_PAGE_CHARACTERS && !blocked {
+            issues.push(issue(
+                AtsIssueKind::UnreadableText,
+                FindingSeverity::Error,
+                "几乎没有可提取的文字，ATS 无法读取简历内容".to_string(),
+                None,
+                None,
+            ));
+        }
+        if characters < MIN_PAGE_CHARACTERS {
+            return;
+        }
+
+        let doc = ResumeDocument::parse(text);
+        let first_line = doc.lines().next().map(|l| l.number);
+        for line in doc.lines() {
+            // 第一行通常是姓名
+            if Some(line.number) != first_line && looks_like_heading(&line.text) {
+                issues.push(issue(
+                    AtsIssueKind::NonStandardHeading,
+                    FindingSeverity::Warning,
+                    format!(
+                        "小节标题「{}」不是常见写法，ATS 可能无法识别，建议使用“工作经历”“教育背景”“专业技能”等标准标题",
+                        line.text.trim()
+                    ),
+                    None,
+                    Some(line.text.trim().to_string()),
+                ));
+            }
+        }
+        if doc.sections.iter().all(|s| s.heading.is_none()) {
+            issues.push(issue(
+                AtsIssueKind::NonStandardHeading,
+                FindingSeverity::Error,
+                "未识别到任何标准小节标题，ATS 无法区分工作经历、教育背景等内容，请为每部分加上标准标题".to_string(),
+                None,
+                None,
+            ));
+        }
+
+        match pages {
+            Some(pages) if pages > MAX_PAGES => issues.push(issue(
+                AtsIssueKind::ExcessiveLength,
+                FindingSeverity::Warning,
+                format!("简历共 {} 页，建议精简到 {} 页以内，突出与职位相关的内容", pages, MAX_PAGES),
+                None,
+                None,
+            )),
+            None if characters > MAX_CHARACTERS => issues.push(issue(
+                AtsIssueKind::ExcessiveLength,
+                FindingSeverity::Warning,
+                format!("简历约 {} 字，建议精简到 {} 页以内，突出与职位相关的内容", characters, MAX_PAGES),
+                None,
+                None,
+            )),
+            _ => {}
+        }
+    }
+}
+
+// ========== PDF 版面 ==========
+
+/// [a b c d e f] 形式的变换矩阵
+type Matrix = [f32; 6];
+
+const IDENTITY: Matrix = [1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+
+fn multiply(m: &Matrix, n: &Matrix) -> Matrix {
+    [
+        m[0] * n[0] + m[1] * n[2],
+        m[0] * n[1] + m[1] * n[3],
+        m[2] * n[0] + m[3] * n[2],
+        m[2] * n[1] + m[3] * n[3],
+        m[4] * n[0] + m[5] * n[2] + n[4],
+        m[4] * n[1] + m[5] * n[3] + n[5],
+    ]
+}
+
+fn transform(m: &Matrix, x: f32, y: f32) -> (f32, f32) {
+    (m[0] * x + m[2] * y + m[4], m[1] * x + m[3] * y + m[5])
+}
+
+/// 页面内容流中的文字位置和线框
+#[derive(Default)]
+struct PdfLayout {
+    /// 每段文字的起点
+    runs: Vec<(f32, f32)>,
+    /// 矩形单元格（宽高都超过阈值）
+    cells: Vec<(f32, f32)>,
+    horizontal_rules: usize,
+    vertical_rules: usize,
+    inline_images: usize,
+}
+
+impl PdfLayout {
+    fn read(doc: &Document, page_id: ObjectId) -> Self {
+        let mut layout = Self::default();
+        let Ok(content) = doc.get_and_decode_page_content(page_id) else {
+            return layout;
+        };
+
+        let mut ctm = IDENTITY;
+        let mut stack = Vec::new();
+        let (mut tm, mut tlm) = (IDENTITY, IDENTITY);
+        let mut leading = 0.0;
+        let mut point = (0.0, 0.0);
+
+        for op in &content.operations {
+            let args: Vec<f32> = op.operands.iter().map(|o| o.as_float().unwrap_or(0.0)).collect();
+            match (op.operator.as_str(), args.as_slice()) {
+                ("q", _) => stack.push(ctm),
+                ("Q", _) => ctm = stack.pop().unwrap_or(IDENTITY),
+                ("cm", [a, b, c, d, e, f]) => ctm = multiply(&[*a, *b, *c, *d, *e, *f], &ctm),
+                ("BT", _) => (tm, tlm) = (IDENTITY, IDENTITY),
+                ("Tm", [a, b, c, d, e, f]) => (tm, tlm) = ([*a, *b, *c, *d, *e, *f], [*a, *b, *c, *d, *e, *f]),
+                ("TL", [l]) => leading = *l,
+                ("Td" | "TD", [x, y]) => {
+                    if op.operator == "TD" {
+                        leading = -y;
+                    }
+                    tlm = multiply(&[1.0, 0.0, 0.0, 1.0, *x, *y], &tlm);
+                    tm = tlm;
+                }
+                ("T*", _) => {
+                    tlm = multiply(&[1.0, 0.0, 0.0, 1.0, 0.0, -leading], &tlm);
+                    tm = tlm;
+                }
+                ("Tj" | "TJ" | "'" | "\"", _) => {
+                    if op.operator == "'" || op.operator == "\"" {
+                        tlm = multiply(&[1.0, 0.0, 0.0, 1.0, 0.0, -leading], &tlm);
+                        tm = tlm;
+                    }
+                    layout.runs.push(transform(&multiply(&tm, &ctm), 0.0, 0.0));
+                }
+                ("re", [x, y, w, h]) => {
+                    let (x0, y0) = transform(&ctm, *x, *y);
+                    let (x1, y1) = transform(&ctm, x + w, y + h);
+                    let (w, h) = ((x1 - x0).abs(), (y1 - y0).abs());
+                    // 很细的矩形是表格边框
+                    if h < 2.0 && w > 20.0 {
+                        layout.horizontal_rules += 1;
+                    } else if w < 2.0 && h > 8.0 {
+                        layout.vertical_rules += 1;
+                    } else if w >= 20.0 && h >= 8.0 {
+                        layout.cells.push((x0.min(x1), y0.min(y1)));
+                    }
+                }
+                ("m", [x, y]) => point = transform(&ctm, *x, *y),
+                ("l", [x, y]) => {
+                    let next = transform(&ctm, *x, *y);
+                    let (dx, dy) = ((next.0 - point.0).abs(), (next.1 - point.1).abs());
+                    if dy < 1.0 && dx > 20.0 {
+                        layout.horizontal_rules += 1;
+                    } else if dx < 1.0 && dy > 8.0 {
+                        layout.vertical_rules += 1;
+                    }
+                    point = next;
+                }
+                ("BI", _) => layout.inline_images += 1,
+                _ => {}
+            }
+        }
+        layout
+    }
+
+    /// 至少两行两列的单元格，或纵横交错的边框线
+    fn has_table(&self) -> bool {
+        if self.horizontal_rules >= 3 && self.vertical_rules >= 3 {
+            return true;
+        }
+        let mut rows: HashMap<i32, BTreeSet<i32>> = HashMap::new();
+        for (x, y) in &self.cells {
+            rows.entry((y / 2.0).round() as i32).or_default().insert((x / 2.0).round() as i32);
+        }
+        rows.values().filter(|columns| columns.len() >= 2).count() >= 2
+    }
+
+    /// 页面中部有大量文字从同一位置起排，视为第二栏
+    fn has_columns(&self, width: f32) -> bool {
+        let rows: BTreeSet<i32> = self.runs.iter().map(|(_, y)| (y / 2.0).round() as i32).collect();
+        let mut starts: HashMap<i32, BTreeSet<i32>> = HashMap::new();
+        for (x, y) in &self.runs {
+            if (width * 0.28..=width * 0.65).contains(x) {
+                starts.entry((x / 10.0).round() as i32).or_default().insert((y / 2.0).round() as i32);
+            }
+        }
+        let aligned = starts.values().map(BTreeSet::len).max().unwrap_or(0);
+        aligned >= 8.max(rows.len() / 4)
+    }
+}
+
+// ========== 辅助函数 ==========
+
+fn issue(
+    kind: AtsIssueKind,
+    severity: FindingSeverity,
+    message: String,
+    page: Option<u32>,
+    detail: Option<String>,
+) -> AtsIssue {
+    AtsIssue {
+        kind,
+        severity,
+        message,
+        page,
+        detail,
+    }
+}
+
+fn image_notice(images: usize) -> AtsIssue {
+    issue(
+        AtsIssueKind::ImageText,
+        FindingSeverity::Info,
+        format!("含有 {} 张图片，图片中的文字（如技能图标、图表）不会被 ATS 识别，重要信息请用文字写明", images),
+        None,
+        None,
+    )
+}
+
+/// 读取压缩包中的 XML 部件并扣减剩余字节预算，不存在时返回 None
+fn read_part(
+    archive: &mut ZipArchive<Cursor<&[u8]>>,
+    name: &str,
+    budget: &mut u64,
+) -> Result<Option<String>, ZipError> {
+    let file = match archive.by_name(name) {
+        Ok(file) => file,
+        Err(ZipError::FileNotFound) => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let mut xml = String::new();
+    file.take(MAX_PART_BYTES.min(*budget)).read_to_string(&mut xml)?;
+    *budget = budget.saturating_sub(xml.len() as u64);
+    Ok(Some(xml))
+}
+
+/// 按段落提取 Word XML 中的文字
+fn docx_text(xml: &str) -> String {
+    let mut text = String::new();
+    for caps in DOCX_TEXT.captures_iter(xml) {
+        match caps.get(1) {
+            Some(run) => text.push_str(&unescape_xml(run.as_str())),
+            None if &caps[0] == "<w:tab/>" => text.push('\t'),
+            None => text.push('\n'),
+        }
+    }
+    text
+}
+
+fn unescape_xml(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// 形似标题但不是标准写法的行，如 “# 我的作品”“【职业亮点】”“CAREER HIGHLIGHTS”
+fn looks_like_heading(line: &str) -> bool {
+    let line = line.trim();
+    let length = line.chars().count();
+    if length == 0 || length > 20 || ResumeDocument::heading_kind(line).is_some() {
+        return false;
+    }
+
+    let marked = line.starts_with('#') || (line.starts_with('【') && line.ends_with('】'));
+    let letters: Vec<char> = line.chars().filter(|c| c.is_alphabetic()).collect();
+    let capitals = letters.len() >= 4
+        && letters.iter().all(|c| c.is_ascii_uppercase())
+        && line.split_whitespace().count() <= 4
+        && !line.chars().any(|c| c.is_ascii_digit());
+    let labelled = (line.ends_with('：') || line.ends_with(':'))
+        && length <= 10
+        && !line.chars().any(|c| c.is_ascii_digit());
+    marked || capitals || labelled
+}
+
+// ========== 内部结果结构 ==========
+
+pub struct AtsInspection {
+    /// 提取出的纯文本，用于评分
+    pub text: String,
+    pub report: AtsReport,
+}
+
+struct Extracted {
+    file_kind: ResumeFileKind,
+    text: String,
+    pages: Option<u32>,
+    issues: Vec<AtsIssue>,
+}
@@ -0,0 +1,201 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tokio::task::{JoinError, JoinSet};
+use uuid::Uuid;
+
+use crate::models::*;
+use crate::services::{JdParser, ResumeDocument, ResumeTailor, ScoringEngine, SkillTaxonomy};
+
+/// 单次对比最多的职位数
+pub const MAX_BATCH_JOBS: usize = 10;
+
+/// 每个职位返回的缺失关键词数
+const MAX_MISSING_KEYWORDS: usize = 5;
+
+/// 参与提升估算的候选技能数
+const MAX_IMPACT_CANDIDATES: usize = 8;
+
+/// 返回的技能提升条数
+const MAX_SKILL_IMPACT: usize = 5;
+
+/// 估算提升时同时进行的评分数
+const CONCURRENCY: usize = 4;
+
+/// 参与对比的一个职位
+pub struct BatchJob {
+    pub job_description_id: Option<Uuid>,
+    pub index: Option<usize>,
+    pub text: String,
+}
+
+/// 估算提升的一次评分：补上技能的简历对一个职位
+struct ImpactJob {
+    skill: String,
+    resume: Arc<str>,
+    jd: String,
+    rank: usize,
+    before: u8,
+}
+
+/// 单个职位的评分结果，保留缺失技能用于估算提升
+struct JobScore {
+    position: usize,
+    text: String,
+    item: BatchScoreItem,
+    missing_skills: Vec<String>,
+}
+
+/// 同一份简历对多个职位评分：各职位并发计算，按总分排序，
+/// 并估算补上哪些技能能同时提高最多职位的得分
+pub struct BatchScorer;
+
+impl BatchScorer {
+    pub async fn score(resume: &str, jobs: Vec<BatchJob>) -> Result<BatchScoreReport, JoinError> {
+        let resume: Arc<str> = Arc::from(resume);
+
+        let mut tasks = JoinSet::new();
+        for (position, job) in jobs.into_iter().enumerate() {
+            let resume = Arc::clone(&resume);
+            tasks.spawn_blocking(move || Self::score_job(position, &resume, job));
+        }
+        let mut scores = Vec::new();
+        while let Some(score) = tasks.join_next().await {
+            scores.push(score?);
+        }
+
+        // 同分时保持提交顺序
+        scores.sort_by_key(|s| (Reverse(s.item.overall_score), s.position));
+        for (i, score) in scores.iter_mut().enumerate() {
+            score.item.rank = i + 1;
+        }
+
+        let skill_impact = Self::skill_impact(&resume, &scores).await?;
+
+        Ok(BatchScoreReport {
+            results: scores.into_iter().map(|s| s.item).collect(),
+            skill_impact,
+        })
+    }
+
+    // ========== 辅助方法 ==========
+
+    fn score_job(position: usize, resume: &str, job: BatchJob) -> JobScore {
+        let taxonomy = SkillTaxonomy::global();
+        let doc = ResumeDocument::parse(resume);
+        let parsed = JdParser::parse(&job.text);
+        let result = ScoringEngine::score(resume, &job.text);
+
+        let missing = ScoringEngine::missing_keywords(&doc, &job.text, &parsed.requirements);
+        let missing_skills = missing
+            .iter()
+            .filter(|keyword| taxonomy.lookup(keyword).is_some())
+            .cloned()
+            .collect();
+
+        JobScore {
+            position,
+            text: job.text,
+            item: BatchScoreItem {
+                rank: 0,
+                job_description_id: job.job_description_id,
+                index: job.index,
+                title: parsed.title,
+                company: parsed.company,
+                overall_score: result.overall_score,
+                categories: result
+                    .categories
+                    .into_iter()
+                    .map(|c| BatchCategoryScore { key: c.key, score: c.score })
+                    .collect(),
+                missing_keywords: missing.into_iter().take(MAX_MISSING_KEYWORDS).collect(),
+            },
+            missing_skills,
+        }
+    }
+
+    /// 候选技能为多数职位缺少的技能；把技能补进简历后重新评分，比较总分变化
+    async fn skill_impact(resume: &Arc<str>, scores: &[JobScore]) -> Result<Vec<SkillImpact>, JoinError> {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        let mut order: Vec<&str> = Vec::new();
+        for score in scores {
+            for skill in &score.missing_skills {
+                let count = counts.entry(skill.as_str()).or_insert(0);
+                if *count == 0 {
+                    order.push(skill.as_str());
+                }
+                *count += 1;
+            }
+        }
+        order.sort_by(|a, b| counts[b].cmp(&counts[a]));
+        order.truncate(MAX_IMPACT_CANDIDATES);
+
+        // 技能补进技能小节后，对缺少该技能的职位重新评分
+        let doc = ResumeDocument::parse(resume);
+        let mut pending = Vec::new();
+        for skill in order {
+            let augmented: Arc<str> = Arc::from(ResumeTailor::add_skills(&doc, &[skill.to_string()]));
+            for score in scores.iter().filter(|s| s.missing_skills.iter().any(|m| m == skill)) {
+                pending.push(ImpactJob {
+                    skill: skill.to_string(),
+                    resume: Arc::clone(&augmented),
+                    jd: score.text.clone(),
+                    rank: score.item.rank,
+                    before: score.item.overall_score,
+                });
+            }
+        }
+        let spawn = |tasks: &mut JoinSet<(String, usize, u8)>, job: ImpactJob| {
+            tasks.spawn_blocking(move || {
+                let after = ScoringEngine::score(&job.resume, &job.jd).overall_score;
+                (job.skill, job.rank, after.saturating_sub(job.before))
+            });
+        };
+
+        let mut pending = pending.into_iter();
+        let mut tasks = JoinSet::new();
+        for job in pending.by_ref().take(CONCURRENCY) {
+            spawn(&mut tasks, job);
+        }
+
+        let mut impacts: Vec<SkillImpact> = Vec::new();
+        while let Some(joined) = tasks.join_next().await {
+            let (skill, rank, gain) = joined?;
+            if let Some(job) = pending.next() {
+                spawn(&mut tasks, job);
+            }
+            if gain == 0 {
+                continue;
+            }
+            let position = match impacts.iter().position(|i| i.skill == skill) {
+                Some(position) => position,
+                None => {
+                    impacts.push(SkillImpact {
+                        skill,
+                        jobs_improved: 0,
+                        total_gain: 0,
+                        gains: Vec::new(),
+                    });
+                    impacts.len() - 1
+                }
+            };
+            let impact = &mut impacts[position];
+            impact.jobs_improved += 1;
+            impact.total_gain += gain as u32;
+            impact.gains.push(SkillImpactGain { rank, gain });
+        }
+
+        for impact in &mut impacts {
+            impact.gains.sort_by_key(|g| g.rank);
+        }
+        impacts.sort_by(|a, b| {
+            b.total_gain
+                .cmp(&a.total_gain)
+                .then(b.jobs_improved.cmp(&a.jobs_improved))
+                .then(a.skill.cmp(&b.skill))
+        });
+        impacts.truncate(MAX_SKILL_IMPACT);
+        Ok(impacts)
+    }
+}
@@ -0,0 +1,300 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::models::*;
+use crate::services::document::{DocLine, SectionKind};
+use crate::services::timeline::DATE_RANGE;
+use crate::services::{JdParser, ResumeDocument};
+
+/// 体现主导和结果的中文动词
+const STRONG_VERBS_ZH: &[&str] = &[
+    "主导", "牵头", "带领", "领导", "设计", "搭建", "构建", "架构", "实现", "开发", "重构", "优化",
+    "提升", "提高", "降低", "减少", "增加", "推动", "推进", "落地", "上线", "交付", "创建", "建立",
+    "打造", "发起", "攻克", "解决", "改进", "迁移", "制定", "规划", "孵化", "拓展", "完成", "独立负责",
+    "从零搭建", "自研", "引入", "统筹", "管理", "培养", "节省", "缩短",
+];
+
+/// 只体现参与或职责的中文动词
+const WEAK_VERBS_ZH: &[&str] = &[
+    "负责", "参与", "协助", "配合", "帮助", "支持", "跟进", "了解", "熟悉", "学习", "接触", "处理",
+    "维护", "从事", "承担", "进行", "做",
+];
+
+const STRONG_VERBS_EN: &[&str] = &[
+    "led", "built", "designed", "architected", "implemented", "developed", "launched", "delivered",
+    "created", "reduced", "increased", "improved", "optimized", "optimised", "automated", "migrated",
+    "drove", "spearheaded", "established", "founded", "scaled", "streamlined", "shipped", "owned",
+    "mentored", "negotiated", "achieved", "accelerated", "cut", "saved", "grew", "generated",
+    "refactored", "resolved", "engineered", "introduced", "pioneered", "transformed", "managed",
+    "directed", "headed", "initiated", "won", "boosted", "doubled", "tripled",
+];
+
+/// 英文弱动词和短语，按前缀匹配
+const WEAK_VERBS_EN: &[&str] = &[
+    "responsible for", "helped", "assisted", "participated", "worked on", "involved in", "handled",
+    "supported", "contributed to", "tasked with", "duties included", "was part of", "familiar with",
+    "exposure to", "tried", "did", "worked with",
+];
+
+/// 中文动词需出现在要点的前几个字内，允许 “独立”“成功” 等修饰词
+const VERB_WINDOW: usize = 4;
+
+/// 表述模糊的词
+const VAGUE_PHRASES: &[&str] = &[
+    "等等", "一些", "若干", "很多", "许多", "各种", "各类", "多种", "一定的", "相关工作", "其他工作",
+    "日常工作", "各项工作", "various", "several", "a lot of", "lots of", "etc", "and so on",
+    "numerous", "stuff", "things",
+];
+
+/// 没有数据支撑时显得空泛的程度词
+const UNQUANTIFIED_CLAIMS: &[&str] = &[
+    "显著", "大幅", "明显", "有效", "极大", "大大", "significantly", "greatly", "substantially",
+    "dramatically", "considerably", "effectively", "massively",
+];
+
+/// 中文 “被” 字句中不算被动语态的常见表述
+const PASSIVE_EXCEPTIONS_ZH: &[&str] = &["被评为", "被授予", "被选为", "被誉为", "被提拔"];
+
+/// 金额上下文，出现时 “200万” 视为金额
+const MONEY_CONTEXT: &[&str] = &[
+    "成本", "营收", "收入", "销售额", "gmv", "利润", "预算", "融资", "节省", "节约", "revenue", "cost",
+    "sales", "budget", "savings", "profit",
+];
+
+static MONEY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)[$¥￥€£]\s*\d[\d,]*(?:\.\d+)?\s*(?:k\b|m\b|b\b|万|亿|million|billion)?|\d[\d,]*(?:\.\d+)?\s*(?:万元|亿元|千元|万美元|美元|元|rmb\b|usd\b|dollars?\b)").unwrap()
+});
+
+static PERCENTAGE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\d+(?:\.\d+)?\s*(?:%|％|个百分点|percent\b|pp\b)").unwrap()
+});
+
+static MULTIPLIER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)\d+(?:\.\d+)?\s*(?:倍|x\b|×)").unwrap());
+
+/// 时长；不含 “年”“月”，避免与日期混淆
+static DURATION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?:^|[^\d.])(?P<m>\d{1,4}(?:\.\d+)?\s*(?:ms\b|毫秒|秒|分钟|小时|天|周|个月|hours?\b|hrs?\b|days?\b|weeks?\b|months?\b|minutes?\b|mins?\b|seconds?\b|s\b))").unwrap()
+});
+
+/// 数量和规模：带单位的数字、带量级的数字，以及 “从 A 到 B” 的变化
+static COUNT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?:^|[^\d.])(?P<m>(?:从|from)\s*\d[\d,.]*\S*?\s*(?:降至|降到|提升至|提升到|提高到|增长到|增加到|减少到|到|至|to)\s*\d[\d,.]*|\d[\d,]*(?:\.\d+)?\s*(?:(?:k\b|m\b|w\b|万|亿|千万|百万|million|billion)\+?\s*(?:人|名|个|次|台|条|家|款|项|篇|套|位|笔|单|用户|users?\b|customers?\b|requests?\b)?|\+?\s*(?:人|名|个|次|台|条|家|款|项|篇|套|位|笔|单|用户|users?\b|customers?\b|clients?\b|requests?\b|qps\b|tps\b|dau\b|mau\b|nodes?\b|servers?\b|services?\b|engineers?\b|members?\b|people\b|projects?\b)))").unwrap()
+});
+
+static PASSIVE_EN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:was|were|been|being|is|are|got)\s+(?:\w+ly\s+)?\w+(?:ed|en)\b").unwrap()
+});
+
+/// 经历要点分析：量化数据、动词强弱、被动语态和模糊表述
+pub struct BulletAnalyzer;
+
+impl BulletAnalyzer {
+    pub fn analyze(doc: &ResumeDocument) -> BulletReport {
+        let bullets: Vec<BulletAnalysis> = Self::bullet_lines(doc).into_iter().map(Self::analyze_line).collect();
+
+        BulletReport {
+            quantified: bullets.iter().filter(|b| !b.metrics.is_empty()).count(),
+            strong_verbs: bullets.iter().filter(|b| b.verb_strength == VerbStrength::Strong).count(),
+            bullets,
+        }
+    }
+
+    /// 工作和项目经历中的要点，跳过日期行和较短的标题行
+    fn bullet_lines(doc: &ResumeDocument) -> Vec<&DocLine> {
+        doc.section_lines(SectionKind::Experience)
+            .chain(doc.section_lines(SectionKind::Projects))
+            .filter(|l| l.is_bullet() || l.content().chars().count() > 20)
+            .filter(|l| !DATE_RANGE.is_match(&l.text))
+            .collect()
+    }
+
+    fn analyze_line(line: &DocLine) -> BulletAnalysis {
+        let metrics = Self::metrics(line);
+        let (verb, verb_strength) = Self::leading_verb(line.content());
+        let mut findings = Vec::new();
+
+        if metrics.is_empty() {
+            findings.push(BulletFinding {
+                issue: BulletIssue::MissingMetric,
+                severity: FindingSeverity::Warning,
+                message: "缺少量化结果，可补充数字、比例、金额或规模".to_string(),
+                span: None,
+            });
+        }
+
+        match (&verb, verb_strength) {
+            (Some(verb), VerbStrength::Weak) => findings.push(BulletFinding {
+                issue: BulletIssue::WeakVerb,
+                severity: FindingSeverity::Warning,
+                message: format!("「{}」只体现职责或参与，建议改用体现结果的动词，如 {}", verb, Self::verb_examples(line)),
+                span: line.term_span(verb),
+            }),
+            (None, _) => findings.push(BulletFinding {
+                issue: BulletIssue::MissingVerb,
+                severity: FindingSeverity::Info,
+                message: format!("建议以动作动词开头，如 {}", Self::verb_examples(line)),
+                span: None,
+            }),
+            _ => {}
+        }
+
+        if let Some(span) = Self::passive_span(line) {
+            findings.push(BulletFinding {
+                issue: BulletIssue::PassiveVoice,
+                severity: FindingSeverity::Info,
+                message: format!("「{}」为被动表述，建议改为主动表述以突出个人贡献", span.text),
+                span: Some(span),
+            });
+        }
+
+        for phrase in VAGUE_PHRASES {
+            if let Some(span) = line.term_span(phrase) {
+                findings.push(BulletFinding {
+                    issue: BulletIssue::VaguePhrase,
+                    severity: FindingSeverity::Info,
+                    message: format!("「{}」表述模糊，建议写明具体内容或数量", span.text),
+                    span: Some(span),
+                });
+            }
+        }
+        if metrics.is_empty() {
+            for claim in UNQUANTIFIED_CLAIMS {
+                if let Some(span) = line.term_span(claim) {
+                    findings.push(BulletFinding {
+                        issue: BulletIssue::VaguePhrase,
+                        severity: FindingSeverity::Warning,
+                        message: format!("「{}」缺少数据支撑，建议给出具体的提升幅度", span.text),
+                        span: Some(span),
+                    });
+                }
+            }
+        }
+
+        // 两个及以上的警告（如弱动词且无量化结果）说明该条只描述了职责
+        let warnings = findings.iter().filter(|f| f.severity == FindingSeverity::Warning).count();
+        let severity = if warnings >= 2 {
+            Some(FindingSeverity::Error)
+        } else {
+            findings.iter().map(|f| f.severity).max()
+        };
+
+        BulletAnalysis {
+            line: line.span(),
+            metrics,
+            verb,
+            verb_strength,
+            findings,
+            severity,
+        }
+    }
+
+    /// 行内的量化数据，按优先级识别且互不重叠
+    fn metrics(line: &DocLine) -> Vec<BulletMetric> {
+        let lower = line.text.to_lowercase();
+        let money_context = MONEY_CONTEXT.iter().any(|w| lower.contains(w));
+
+        let mut found: Vec<(usize, usize, MetricKind)> = Vec::new();
+        for (regex, kind) in [
+            (&MONEY, MetricKind::Money),
+            (&PERCENTAGE, MetricKind::Percentage),
+            (&MULTIPLIER, MetricKind::Multiplier),
+            (&DURATION, MetricKind::Duration),
+            (&COUNT, MetricKind::Count),
+        ] {
+            for caps in regex.captures_iter(&line.text) {
+                let m = caps.name("m").or(caps.get(0)).unwrap();
+                let (start, end) = (m.start(), m.as_str().trim_end().len() + m.start());
+                if found.iter().any(|(s, e, _)| start < *e && *s < end) {
+                    continue;
+                }
+                let text = m.as_str();
+                let kind = if kind == MetricKind::Count && money_context && (text.contains('万') || text.contains('亿')) {
+                    MetricKind::Money
+                } else {
+                    kind
+                };
+                found.push((start, end, kind));
+            }
+        }
+        found.sort_by_key(|(start, _, _)| *start);
+
+        found
+            .into_iter()
+            .map(|(start, end, kind)| BulletMetric {
+                kind,
+                span: line.slice_span(start, end),
+            })
+            .collect()
+    }
+
+    /// 要点开头的动词及其强弱
+    fn leading_verb(content: &str) -> (Option<String>, VerbStrength) {
+        let lower = content.to_lowercase();
+
+        if lower.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            if let Some(weak) = WEAK_VERBS_EN.iter().find(|w| lower.starts_with(*w)) {
+                return (Some(content[..weak.len()].to_string()), VerbStrength::Weak);
+            }
+            let word: &str = content.split(|c: char| !c.is_ascii_alphabetic()).next().unwrap_or_default();
+            let word_lower = word.to_lowercase();
+            // 未收录的过去式动词也视为有力动词，如 Orchestrated
+            return if STRONG_VERBS_EN.contains(&word_lower.as_str()) || word_lower.ends_with("ed") {
+                (Some(word.to_string()), VerbStrength::Strong)
+            } else {
+                (None, VerbStrength::Missing)
+            };
+        }
+
+        // 取前几个字内最早出现的动词，位置相同时取较长的
+        let head: String = content.chars().take(VERB_WINDOW + 4).collect();
+        let candidates = STRONG_VERBS_ZH
+            .iter()
+            .map(|v| (v, VerbStrength::Strong))
+            .chain(WEAK_VERBS_ZH.iter().map(|v| (v, VerbStrength::Weak)));
+        let best = candidates
+            .filter_map(|(verb, strength)| {
+                let index = head.find(verb)?;
+                // “被重构” 中的动词不是要点的主语动作
+                if head[..index].ends_with('被') {
+                    return None;
+                }
+                let position = head[..index].chars().count();
+                (position <= VERB_WINDOW).then_some((position, verb.chars().count(), *verb, strength))
+            })
+            .min_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+
+        match best {
+            Some((_, _, verb, strength)) => (Some(verb.to_string()), strength),
+            None => (None, VerbStrength::Missing),
+        }
+    }
+
+    fn verb_examples(line: &DocLine) -> &'static str {
+        if line.content().starts_with(|c: char| c.is_ascii_alphabetic()) {
+            "Led、Built、Reduced"
+        } else {
+            "主导、设计、优化"
+        }
+    }
+
+    fn passive_span(line: &DocLine) -> Option<EvidenceSpan> {
+        if let Some(m) = PASSIVE_EN.find(&line.text) {
+            return Some(line.slice_span(m.start(), m.end()));
+        }
+
+        for (i, _) in line.text.match_indices('被') {
+            let rest = &line.text[i..];
+            if PASSIVE_EXCEPTIONS_ZH.iter().any(|e| rest.starts_with(e)) {
+                continue;
+            }
+            let end = rest.char_indices().nth(3).map(|(j, _)| i + j).unwrap_or(line.text.len());
+            return Some(line.slice_span(i, end));
+        }
+
+        ["由我", "由本人"]
+            .iter()
+            .find_map(|phrase| line.term_span(phrase))
+            .filter(|_| JdParser::contains_term(&line.text, "负责"))
+    }
+}
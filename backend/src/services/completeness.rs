@@ -0,0 +1,242 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::models::*;
+use crate::services::document::{DocLine, SectionKind};
+use crate::services::timeline::{DATE_PARTS, DATE_RANGE};
+use crate::services::ResumeDocument;
+
+/// 标准小节：（标识，名称，类型，权重，缺失时的建议）；联系方式的权重按邮箱和手机号是否有效折算
+const STANDARD_SECTIONS: &[(&str, &str, SectionKind, u32, &str)] = &[
+    ("contact", "联系方式", SectionKind::Contact, 25, "在简历开头写明手机号和邮箱"),
+    ("summary", "个人简介", SectionKind::Summary, 10, "在开头添加个人简介，概括核心竞争力"),
+    ("experience", "工作经历", SectionKind::Experience, 25, "添加“工作经历”小节，写明公司、职位和起止时间"),
+    ("education", "教育背景", SectionKind::Education, 15, "添加“教育背景”小节，写明学校、专业和学历"),
+    ("skills", "专业技能", SectionKind::Skills, 15, "添加“专业技能”小节，列出与职位相关的技能"),
+    ("projects", "项目经历", SectionKind::Projects, 10, "添加“项目经历”小节，写明项目背景、个人职责和成果"),
+];
+
+/// 含 @ 或全角 ＠ 的片段，格式是否正确另行校验
+pub static EMAIL_CANDIDATE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[^\s:：,，;；|｜<>()（）\[\]【】@＠]+[@＠][^\s,，;；|｜<>()（）\[\]【】]*").unwrap());
+
+static EMAIL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^[A-Za-z0-9._%+-]+@[A-Za-z0-9](?:[A-Za-z0-9-]*[A-Za-z0-9])?(?:\.[A-Za-z0-9](?:[A-Za-z0-9-]*[A-Za-z0-9])?)*\.[A-Za-z]{2,}$").unwrap()
+});
+
+/// 数字、空格、横线和括号组成的片段，至少 7 位
+pub static PHONE_CANDIDATE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:\+|＋)?\d[\d\s\-()（）]{5,}\d").unwrap());
+
+/// 中国大陆手机号，可带 86 / +86
+pub static CN_MOBILE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(?:\+?86)?1[3-9]\d{9}$").unwrap());
+
+/// 中国大陆固定电话，区号 + 号码
+pub static CN_LANDLINE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^0\d{2,3}\d{7,8}$").unwrap());
+
+/// E.164 国际号码
+pub static INTERNATIONAL: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\+[1-9]\d{7,14}$").unwrap());
+
+static LINKEDIN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?:https?://)?(?:[a-z]{2,3}\.)?linkedin\.com[^\s,，;；|｜()（）]*").unwrap()
+});
+
+static LINKEDIN_PROFILE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(?:https?://)?(?:[a-z]{2,3}\.)?linkedin\.com/(?:in|pub)/[a-z0-9%_-]{3,100}/?$").unwrap()
+});
+
+static GITHUB: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)(?:https?://)?(?:www\.)?github\.com[^\s,，;；|｜()（）]*").unwrap());
+
+/// 用户主页或仓库地址；用户名为字母数字和单个横线，最长 39 位
+static GITHUB_PROFILE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(?:https?://)?(?:www\.)?github\.com/[a-z0-9](?:-?[a-z0-9]){0,38}(?:/[\w.-]+)?/?$").unwrap()
+});
+
+/// 标准小节和联系方式检查
+pub struct CompletenessChecker;
+
+impl CompletenessChecker {
+    pub fn check(doc: &ResumeDocument) -> CompletenessReport {
+        let contacts = Self::contacts(doc);
+        let mut issues: Vec<String> = contacts.iter().filter_map(|c| c.message.clone()).collect();
+
+        let valid = |kind: ContactKind| contacts.iter().any(|c| c.kind == kind && c.valid);
+        let found = |kind: ContactKind| contacts.iter().any(|c| c.kind == kind);
+        if !found(ContactKind::Email) {
+            issues.push("未识别到邮箱，请在简历开头写明常用邮箱，如 name@example.com".to_string());
+        }
+        if !found(ContactKind::Phone) {
+            issues.push("未识别到手机号，请在简历开头写明手机号，国际号码带上国家代码，如 +1 415 555 0100".to_string());
+        }
+        if !found(ContactKind::Github) && !found(ContactKind::Linkedin) {
+            issues.push("可附上 GitHub 或 LinkedIn 主页链接，便于招聘方了解更多".to_string());
+        }
+        // 联系方式按有效的邮箱和手机号各占一半
+        let contact_ratio = (valid(ContactKind::Email) as u32 + valid(ContactKind::Phone) as u32) as f32 / 2.0;
+
+        let mut score = 0.0;
+        let mut sections = Vec::new();
+        for (key, name, kind, weight, advice) in STANDARD_SECTIONS {
+            let heading = doc
+                .sections
+                .iter()
+                .find(|s| s.kind == *kind)
+                .and_then(|s| s.heading.as_ref().map(DocLine::span));
+            let (present, evidence, ratio) = match kind {
+                SectionKind::Contact => {
+                    let evidence = heading.or_else(|| contacts.first().map(|c| c.span.clone()));
+                    (!contacts.is_empty() || evidence.is_some(), evidence, contact_ratio)
+                }
+                _ => {
+                    let present = doc.has_section(*kind);
+                    (present, heading, if present { 1.0 } else { 0.0 })
+                }
+            };
+            if !present && *kind != SectionKind::Contact {
+                issues.push(advice.to_string());
+            }
+            score += *weight as f32 * ratio;
+            sections.push(SectionCheck {
+                key: key.to_string(),
+                name: name.to_string(),
+                present,
+                evidence,
+            });
+        }
+
+        CompletenessReport {
+            score: score.round().min(100.0) as u8,
+            sections,
+            contacts,
+            issues,
+        }
+    }
+
+    /// 页眉和联系方式小节中的联系方式；都没有时在全文中查找邮箱和主页链接
+    pub fn contacts(doc: &ResumeDocument) -> Vec<ContactItem> {
+        let header: Vec<&DocLine> = doc
+            .section_lines(SectionKind::Header)
+            .chain(doc.section_lines(SectionKind::Contact))
+            .collect();
+
+        let mut contacts = Vec::new();
+        for line in &header {
+            contacts.extend(Self::emails(line));
+            contacts.extend(Self::phones(line));
+            contacts.extend(Self::profiles(line));
+        }
+        if contacts.is_empty() {
+            for line in doc.lines() {
+                contacts.extend(Self::emails(line));
+                contacts.extend(Self::profiles(line));
+            }
+        }
+        contacts
+    }
+
+    // ========== 辅助方法 ==========
+
+    fn emails(line: &DocLine) -> Vec<ContactItem> {
+        EMAIL_CANDIDATE
+            .find_iter(&line.text)
+            .map(|m| {
+                let value = m.as_str().trim_end_matches(['.', '。']);
+                let message = if EMAIL.is_match(value) {
+                    None
+                } else if !value.is_ascii() {
+                    Some(format!("邮箱「{}」含有全角或中文字符，请改为半角", value))
+                } else if value.matches('@').count() > 1 {
+                    Some(format!("邮箱「{}」含有多个 @", value))
+                } else if value.starts_with('@') {
+                    Some(format!("邮箱「{}」缺少用户名", value))
+                } else if !value.rsplit('@').next().unwrap_or_default().contains('.') {
+                    Some(format!("邮箱「{}」缺少域名后缀，如 .com", value))
+                } else {
+                    Some(format!("邮箱「{}」格式不正确，应为 name@example.com 形式", value))
+                };
+                ContactItem {
+                    kind: ContactKind::Email,
+                    value: value.to_string(),
+                    valid: message.is_none(),
+                    message,
+                    span: line.slice_span(m.start(), m.start() + value.len()),
+                }
+            })
+            .collect()
+    }
+
+    fn phones(line: &DocLine) -> Vec<ContactItem> {
+        PHONE_CANDIDATE
+            .find_iter(&line.text)
+            .filter(|m| !DATE_RANGE.is_match(m.as_str()) && !is_date(m.as_str()))
+            .map(|m| {
+                let value = m.as_str().trim();
+                let digits: String = value
+                    .chars()
+                    .filter(|c| c.is_ascii_digit() || *c == '+' || *c == '＋')
+                    .map(|c| if c == '＋' { '+' } else { c })
+                    .collect();
+                let count = digits.chars().filter(|c| c.is_ascii_digit()).count();
+
+                let message = if CN_MOBILE.is_match(&digits)
+                    || CN_LANDLINE.is_match(&digits)
+                    || INTERNATIONAL.is_match(&digits)
+                {
+                    None
+                } else if digits.starts_with('1') && count != 11 {
+                    Some(format!("手机号「{}」为 {} 位，中国大陆手机号应为 11 位", value, count))
+                } else if digits.starts_with('1') {
+                    Some(format!("手机号「{}」号段不正确，请核对", value))
+                } else if digits.starts_with('+') {
+                    Some(format!("电话「{}」位数不正确，国际号码应为 +国家代码 加 8-15 位数字", value))
+                } else {
+                    Some(format!("电话「{}」格式无法识别，国际号码请加上国家代码，如 +1 415 555 0100", value))
+                };
+                ContactItem {
+                    kind: ContactKind::Phone,
+                    value: value.to_string(),
+                    valid: message.is_none(),
+                    message,
+                    span: line.slice_span(m.start(), m.start() + m.as_str().trim_end().len()),
+                }
+            })
+            .collect()
+    }
+
+    fn profiles(line: &DocLine) -> Vec<ContactItem> {
+        let linkedin = LINKEDIN.find_iter(&line.text).map(|m| {
+            let value = m.as_str().trim_end_matches(['.', '。']);
+            let message = (!LINKEDIN_PROFILE.is_match(value))
+                .then(|| format!("LinkedIn 链接「{}」应为 linkedin.com/in/用户名 形式", value));
+            (ContactKind::Linkedin, m.start(), value, message)
+        });
+        let github = GITHUB.find_iter(&line.text).map(|m| {
+            let value = m.as_str().trim_end_matches(['.', '。']);
+            let message = (!GITHUB_PROFILE.is_match(value))
+                .then(|| format!("GitHub 链接「{}」应为 github.com/用户名 形式", value));
+            (ContactKind::Github, m.start(), value, message)
+        });
+
+        linkedin
+            .chain(github)
+            .map(|(kind, start, value, message)| ContactItem {
+                kind,
+                value: value.to_string(),
+                valid: message.is_none(),
+                message,
+                span: line.slice_span(start, start + value.len()),
+            })
+            .collect()
+    }
+}
+
+/// 单个日期（如 “出生日期：1995-06-15”）不当作电话号码
+fn is_date(value: &str) -> bool {
+    DATE_PARTS.captures(value.trim()).is_some_and(|caps| {
+        let year = caps.name("y2").or(caps.name("y3")).and_then(|y| y.as_str().parse::<u32>().ok());
+        let month = caps.name("m2").or(caps.name("m3")).and_then(|m| m.as_str().parse::<u32>().ok());
+        year.is_some_and(|y| (1900..2100).contains(&y)) && month.is_some_and(|m| (1..=12).contains(&m))
+    })
+}
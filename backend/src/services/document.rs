@@ -0,0 +1,252 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::Serialize;
+
+use crate::models::{EvidenceSpan, SkillDefinition};
+use crate::services::tokenizer::is_cjk;
+use crate::services::{JdParser, SkillTaxonomy};
+
+/// 页眉中最多查找姓名的行数
+const NAME_LINES: usize = 3;
+
+/// 行首的 “姓名：”“Name:” 标签
+static NAME_LABEL: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)^(?:姓\s*名|name)\s*[:：]\s*").unwrap());
+
+/// 2-4 个汉字（少数民族姓名可含 ·），或 2-3 个首字母大写的英文单词
+static PERSON_NAME: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?:\p{Han}{2,4}|\p{Han}{1,4}·\p{Han}{1,6}|[A-Z][a-z]+(?:[ -][A-Z][a-z]+){1,2})$").unwrap()
+});
+
+/// 简历中的标准小节
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SectionKind {
+    /// 第一个小节标题之前的内容，通常是姓名和联系方式
+    Header,
+    Contact,
+    Summary,
+    Experience,
+    Projects,
+    Education,
+    Skills,
+    Certifications,
+    Awards,
+}
+
+const SECTION_HEADINGS: &[(SectionKind, &[&str])] = &[
+    (SectionKind::Contact, &["联系方式", "个人信息", "基本信息", "contact", "contact information", "personal information"]),
+    (SectionKind::Summary, &[
+        "个人简介", "个人总结", "自我评价", "个人优势", "职业概述", "简介", "求职意向",
+        "summary", "professional summary", "profile", "about me", "objective",
+    ]),
+    (SectionKind::Experience, &[
+        "工作经历", "工作经验", "职业经历", "实习经历", "工作履历",
+        "experience", "work experience", "professional experience", "employment history", "employment",
+    ]),
+    (SectionKind::Projects, &["项目经历", "项目经验", "项目", "projects", "project experience", "personal projects"]),
+    (SectionKind::Education, &["教育背景", "教育经历", "学历", "education", "academic background"]),
+    (SectionKind::Skills, &[
+        "专业技能", "技能", "技能清单", "技术栈", "技术能力", "技能特长",
+        "skills", "technical skills", "core competencies", "tech stack",
+    ]),
+    (SectionKind::Certifications, &["证书", "资格证书", "认证", "certifications", "certificates", "licenses"]),
+    (SectionKind::Awards, &["获奖经历", "获奖", "荣誉", "荣誉奖项", "awards", "honors", "honors & awards"]),
+];
+
+/// 简历中的一行
+#[derive(Debug, Clone)]
+pub struct DocLine {
+    /// 行号（从 1 开始）
+    pub number: usize,
+    /// 在全文中的字符偏移
+    pub char_start: usize,
+    pub text: String,
+}
+
+impl DocLine {
+    /// 去掉项目符号后的内容
+    pub fn content(&self) -> &str {
+        JdParser::strip_bullet(&self.text)
+    }
+
+    /// 是否为项目符号行
+    pub fn is_bullet(&self) -> bool {
+        self.content().len() != self.text.trim().len()
+    }
+
+    /// 整行作为证据
+    pub fn span(&self) -> EvidenceSpan {
+        EvidenceSpan {
+            line: self.number,
+            start: self.char_start,
+            end: self.char_start + self.text.chars().count(),
+            text: self.text.trim().to_string(),
+        }
+    }
+
+    /// 行内某个术语作为证据
+    pub fn term_span(&self, term: &str) -> Option<EvidenceSpan> {
+        let offset = JdParser::find_term(&self.text, term)?;
+        let start = self.char_start + offset;
+        let text: String = self.text.chars().skip(offset).take(term.chars().count()).collect();
+        Some(EvidenceSpan {
+            line: self.number,
+            start,
+            end: start + text.chars().count(),
+            text,
+        })
+    }
+
+    /// 行内按字节偏移截取的片段作为证据
+    pub fn slice_span(&self, byte_start: usize, byte_end: usize) -> EvidenceSpan {
+        let start = self.char_start + self.text[..byte_start].chars().count();
+        let text = self.text[byte_start..byte_end].to_string();
+        EvidenceSpan {
+            line: self.number,
+            start,
+            end: start + text.chars().count(),
+            text,
+        }
+    }
+
+    /// 行内某个技能（名称或任一别名）作为证据
+    pub fn skill_span(&self, taxonomy: &SkillTaxonomy, skill: &SkillDefinition) -> Option<EvidenceSpan> {
+        let (offset, len) = taxonomy.find(&self.text, skill)?;
+        let start = self.char_start + offset;
+        Some(EvidenceSpan {
+            line: self.number,
+            start,
+            end: start + len,
+            text: self.text.chars().skip(offset).take(len).collect(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ResumeSection {
+    pub kind: SectionKind,
+    /// 小节标题行；Header 小节没有标题
+    pub heading: Option<DocLine>,
+    pub lines: Vec<DocLine>,
+}
+
+/// 结构化的简历
+#[derive(Debug, Clone)]
+pub struct ResumeDocument {
+    pub text: String,
+    pub sections: Vec<ResumeSection>,
+}
+
+impl ResumeDocument {
+    pub fn parse(text: &str) -> Self {
+        let mut sections = vec![ResumeSection {
+            kind: SectionKind::Header,
+            heading: None,
+            lines: Vec::new(),
+        }];
+
+        let mut char_start = 0;
+        for (i, raw) in text.split('\n').enumerate() {
+            let line = DocLine {
+                number: i + 1,
+                char_start,
+                text: raw.trim_end_matches('\r').to_string(),
+            };
+            char_start += raw.chars().count() + 1;
+
+            if line.text.trim().is_empty() {
+                continue;
+            }
+
+            if let Some(kind) = Self::heading_kind(&line.text) {
+                sections.push(ResumeSection {
+                    kind,
+                    heading: Some(line),
+                    lines: Vec::new(),
+                });
+                continue;
+            }
+
+            if let Some(section) = sections.last_mut() {
+                section.lines.push(line);
+            }
+        }
+
+        Self {
+            text: text.to_string(),
+            sections,
+        }
+    }
+
+    pub fn has_section(&self, kind: SectionKind) -> bool {
+        self.sections.iter().any(|s| s.kind == kind)
+    }
+
+    /// 指定类型小节中的所有行
+    pub fn section_lines(&self, kind: SectionKind) -> impl Iterator<Item = &DocLine> {
+        self.sections
+            .iter()
+            .filter(move |s| s.kind == kind)
+            .flat_map(|s| s.lines.iter())
+    }
+
+    /// 全部内容行（不含小节标题）
+    pub fn lines(&self) -> impl Iterator<Item = &DocLine> {
+        self.sections.iter().flat_map(|s| s.lines.iter())
+    }
+
+    /// 术语在简历中首次出现的位置
+    pub fn find_term(&self, term: &str) -> Option<EvidenceSpan> {
+        self.lines().find_map(|line| line.term_span(term))
+    }
+
+    /// 技能在简历中首次出现的位置，名称和别名均可命中；不在词典中的按普通术语查找
+    pub fn find_skill(&self, taxonomy: &SkillTaxonomy, name: &str) -> Option<EvidenceSpan> {
+        match taxonomy.lookup(name) {
+            Some(skill) => self.lines().find_map(|line| line.skill_span(taxonomy, skill)),
+            None => self.find_term(name),
+        }
+    }
+
+    /// 候选人姓名：页眉前几行中第一个符合姓名格式的首段（按 | 等分隔）
+    pub fn candidate_name(&self) -> Option<String> {
+        self.section_lines(SectionKind::Header)
+            .take(NAME_LINES)
+            .filter(|line| !line.text.contains("简历") && !line.text.contains("求职"))
+            .find_map(|line| {
+                let content = NAME_LABEL.replace(line.content().trim(), "");
+                let first = content.split(['|', '｜', '/', ',', '，', ';', '；']).next()?.trim();
+                // 中文姓名后常跟性别、年龄等，以空格分隔
+                let first = if first.chars().any(is_cjk) {
+                    first.split_whitespace().next()?
+                } else {
+                    first
+                };
+                PERSON_NAME.is_match(first).then(|| first.to_string())
+            })
+    }
+
+    /// 判断一行是否为小节标题
+    pub fn heading_kind(line: &str) -> Option<SectionKind> {
+        let heading = Self::clean_heading(line).to_lowercase();
+        if heading.is_empty() || heading.chars().count() > 30 {
+            return None;
+        }
+
+        SECTION_HEADINGS
+            .iter()
+            .find(|(_, names)| names.iter().any(|n| heading == *n))
+            .map(|(kind, _)| *kind)
+    }
+
+    /// 去掉 Markdown 标记、括号和结尾冒号
+    fn clean_heading(line: &str) -> &str {
+        line.trim()
+            .trim_start_matches('#')
+            .trim()
+            .trim_matches(|c: char| "【】[]*_=".contains(c))
+            .trim_end_matches([':', '：'])
+            .trim()
+    }
+}
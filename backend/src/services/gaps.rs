@@ -0,0 +1,101 @@
+use crate::models::*;
+use crate::services::{LearningCatalog, RequirementExtractor, ResumeDocument, SkillTaxonomy, TimelineAnalyzer};
+
+/// 排序时 JD 权重所占比例，其余为在已保存 JD 中的出现频率
+const IMPORTANCE_SHARE: f32 = 0.6;
+
+/// 技能差距报告：简历缺少的技能按重要程度排序，并附学习资源
+pub struct SkillGapAnalyzer;
+
+impl SkillGapAnalyzer {
+    /// target 为指定的 JD；未指定时综合 saved 中所有 JD 的技能要求
+    pub fn report(
+        doc: &ResumeDocument,
+        target: Option<&ParsedJobDescription>,
+        saved: &[ParsedJobDescription],
+    ) -> Vec<SkillGap> {
+        let taxonomy = SkillTaxonomy::global();
+        let catalog = LearningCatalog::global();
+        let timeline = TimelineAnalyzer::analyze(doc);
+
+        let sources: Vec<&JdRequirement> = match target {
+            Some(jd) => jd.requirements.iter().collect(),
+            None => saved.iter().flat_map(|jd| jd.requirements.iter()).collect(),
+        };
+        let requirements = Self::merge_skills(sources);
+
+        let unmet: Vec<RequirementGap> = RequirementExtractor::evaluate(doc, &timeline, &requirements)
+            .into_iter()
+            .filter(|g| !g.met)
+            .collect();
+        let max_weight = unmet.iter().map(|g| g.requirement.weight).fold(0.0, f32::max);
+
+        let mut gaps: Vec<SkillGap> = unmet
+            .into_iter()
+            .filter_map(|gap| {
+                let skill = gap.requirement.skill.clone()?;
+                let job_count = saved
+                    .iter()
+                    .filter(|jd| {
+                        jd.requirements
+                            .iter()
+                            .any(|r| r.skill.as_deref().is_some_and(|s| s.eq_ignore_ascii_case(&skill)))
+                    })
+                    .count();
+                let frequency = if saved.is_empty() { 0.0 } else { job_count as f32 / saved.len() as f32 };
+                let importance = gap.requirement.weight;
+                let relative = if max_weight > 0.0 { importance / max_weight } else { 0.0 };
+                let score = if saved.is_empty() {
+                    relative
+                } else {
+                    IMPORTANCE_SHARE * relative + (1.0 - IMPORTANCE_SHARE) * frequency
+                };
+
+                Some(SkillGap {
+                    category: taxonomy.lookup(&skill).map(|s| s.category.clone()).unwrap_or_default(),
+                    resources: catalog.resources_for(&skill),
+                    priority: gap.requirement.priority,
+                    importance: round2(importance),
+                    job_count,
+                    frequency: round2(frequency),
+                    score: round2(score),
+                    detail: gap.detail,
+                    skill,
+                })
+            })
+            .collect();
+
+        gaps.sort_by(|a, b| b.score.total_cmp(&a.score).then(b.job_count.cmp(&a.job_count)));
+        gaps
+    }
+
+    // ========== 辅助方法 ==========
+
+    /// 同一技能只保留一条：必备优先，取最高权重和最高年限要求
+    fn merge_skills(sources: Vec<&JdRequirement>) -> Vec<JdRequirement> {
+        let mut merged: Vec<JdRequirement> = Vec::new();
+        for requirement in sources.into_iter().filter(|r| r.kind == RequirementKind::Skill) {
+            let Some(skill) = requirement.skill.as_deref() else {
+                continue;
+            };
+            let existing = merged
+                .iter_mut()
+                .find(|r| r.skill.as_deref().is_some_and(|s| s.eq_ignore_ascii_case(skill)));
+            match existing {
+                Some(existing) => {
+                    if requirement.priority == RequirementPriority::Must {
+                        existing.priority = RequirementPriority::Must;
+                    }
+                    existing.weight = existing.weight.max(requirement.weight);
+                    existing.min_years = existing.min_years.max(requirement.min_years);
+                }
+                None => merged.push(requirement.clone()),
+            }
+        }
+        merged
+    }
+}
+
+fn round2(value: f32) -> f32 {
+    (value * 100.0).round() / 100.0
+}
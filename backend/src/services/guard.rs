@@ -0,0 +1,204 @@
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::models::*;
+use crate::services::document::SectionKind;
+use crate::services::timeline::DATE_RANGE;
+use crate::services::tokenizer::is_cjk;
+use crate::services::{JdParser, RequirementExtractor, ResumeDocument, TimelineAnalyzer};
+
+/// 常见证书和考试
+const CERTIFICATIONS: &[&str] = &[
+    "PMP", "CPA", "CFA", "ACCA", "FRM", "CISSP", "CISA", "CCNA", "CCNP", "CCIE", "CKA", "CKAD", "RHCE", "OCP",
+    "AWS Certified", "Google Cloud Certified", "Microsoft Certified", "Certified Scrum Master", "CSM",
+    "软考", "系统架构设计师", "系统分析师", "注册会计师", "法律职业资格", "教师资格证", "英语六级", "英语四级",
+    "CET-6", "CET-4", "专业八级", "TEM-8", "雅思", "IELTS", "托福", "TOEFL", "日语N1", "日语N2",
+];
+
+/// 年份；“2019年” 中的 “年” 不算单词字符
+static YEAR: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?-u:\b)(?:19|20)\d{2}(?-u:\b)").unwrap());
+
+static NUMBER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\d+(?:[.,]\d+)*").unwrap());
+
+/// 带公司后缀的名称；单独的 “公司” 多为普通用词（如 “为公司节省成本”），不作识别
+static EMPLOYER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"[^\s，,。；;|｜]{2,20}(?:有限公司|股份公司|集团)|\b[A-Z][\w&.-]*(?:\s+[A-Z][\w&.-]*)*\s+(?:Inc|Ltd|LLC|Corp|Corporation|GmbH)\b").unwrap()
+});
+
+/// 带修饰词的职位名称；单独的 “工程师”“Engineer” 过于宽泛，不作校验
+static TITLE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?:高级|资深|初级|中级|首席|技术|后端|前端|全栈|算法|数据|产品|项目|测试|运维|研发|软件|系统|平台|开发|客户端|服务端|大数据|安全)+(?:工程师|经理|总监|主管|架构师|专员|负责人|实习生|设计师|分析师|顾问)|\b(?:(?:senior|junior|lead|principal|staff|chief|head)\s+)+(?:(?:software|backend|back-end|frontend|front-end|full[- ]stack|data|product|project|platform|security|qa)\s+)*(?:engineer|developer|manager|director|architect|analyst|designer|consultant|scientist)\b|\b(?:software|backend|back-end|frontend|front-end|full[- ]stack|data|product|project|platform|security|qa)\s+(?:engineer|developer|manager|director|architect|analyst|designer|consultant|scientist)\b").unwrap()
+});
+
+/// 经历标题行中的分隔符，如 “字节跳动 | 高级后端工程师 | 2021.03 - 至今”
+static HEADER_SEPARATOR: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\s*(?:[|｜·,，/—–]|\s-\s)\s*").unwrap());
+
+/// 校验生成内容中的公司、职位、时间、学历、证书和数字是否都来自原简历
+pub struct HallucinationGuard {
+    text: String,
+    lines: HashSet<String>,
+    numbers: HashSet<String>,
+    degrees: Vec<EducationLevel>,
+}
+
+impl HallucinationGuard {
+    pub fn new(original: &ResumeDocument) -> Self {
+        let mut numbers: HashSet<String> = NUMBER
+            .find_iter(&original.text)
+            .map(|m| normalize_number(m.as_str()))
+            .collect();
+        // 由时间线计算出的年限不算新增，如 “约 7.6 年工作经验”
+        let timeline = TimelineAnalyzer::analyze(original);
+        numbers.insert(timeline.total_years.to_string());
+        numbers.extend(timeline.skill_years.iter().map(|s| s.years.to_string()));
+
+        Self {
+            text: original.text.to_lowercase(),
+            lines: original.lines().map(|l| l.text.trim().to_string()).collect(),
+            numbers,
+            degrees: RequirementExtractor::education_levels(&original.text),
+        }
+    }
+
+    /// 找出原简历中没有的事实，不修改内容
+    pub fn check(&self, generated: &str) -> Vec<GuardViolation> {
+        let doc = ResumeDocument::parse(generated);
+        let mut violations = Vec::new();
+
+        for section in &doc.sections {
+            for line in &section.lines {
+                // 与原简历完全相同的行不需要校验
+                let text = line.text.trim();
+                if self.lines.contains(text) {
+                    continue;
+                }
+                let mut push = |kind: EntityKind, value: &str| {
+                    let seen = violations
+                        .iter()
+                        .any(|v: &GuardViolation| v.line == line.number && v.kind == kind && v.value == value);
+                    if seen {
+                        return;
+                    }
+                    violations.push(GuardViolation {
+                        kind,
+                        value: value.to_string(),
+                        line: line.number,
+                        stripped: false,
+                    });
+                };
+
+                let mut dates: Vec<(usize, usize)> = Vec::new();
+                for m in DATE_RANGE.find_iter(text) {
+                    dates.push((m.start(), m.end()));
+                    if !self.contains(m.as_str()) && !self.contains_compact(m.as_str()) {
+                        push(EntityKind::Date, m.as_str());
+                    }
+                }
+                for year in YEAR.find_iter(text) {
+                    let in_range = dates.iter().any(|(s, e)| *s <= year.start() && year.end() <= *e);
+                    if !in_range && !self.contains(year.as_str()) {
+                        push(EntityKind::Date, year.as_str());
+                    }
+                }
+
+                for m in NUMBER.find_iter(text) {
+                    let in_date = dates.iter().any(|(s, e)| *s <= m.start() && m.end() <= *e);
+                    let is_year = YEAR.is_match(m.as_str()) && m.as_str().len() == 4;
+                    if !in_date && !is_year && !self.numbers.contains(&normalize_number(m.as_str())) {
+                        push(EntityKind::Number, m.as_str());
+                    }
+                }
+
+                for level in RequirementExtractor::education_levels(text) {
+                    if !self.degrees.contains(&level) {
+                        push(EntityKind::Degree, level.label());
+                    }
+                }
+
+                for cert in CERTIFICATIONS {
+                    if JdParser::contains_term(text, cert) && !JdParser::contains_term(&self.text, cert) {
+                        push(EntityKind::Certification, cert);
+                    }
+                }
+                if section.kind == SectionKind::Certifications && !self.contains(line.content()) {
+                    push(EntityKind::Certification, line.content());
+                }
+
+                for m in EMPLOYER.find_iter(text) {
+                    if !self.contains(m.as_str()) {
+                        push(EntityKind::Company, m.as_str());
+                    }
+                }
+                for m in TITLE.find_iter(text) {
+                    if !self.contains(m.as_str()) {
+                        push(EntityKind::Title, m.as_str());
+                    }
+                }
+
+                // 经历标题行中除时间和职位外的部分视为公司名
+                if section.kind == SectionKind::Experience && !line.is_bullet() {
+                    for part in HEADER_SEPARATOR.split(text).map(str::trim) {
+                        let is_other = part.chars().count() < 2
+                            || DATE_RANGE.is_match(part)
+                            || YEAR.is_match(part)
+                            || TITLE.is_match(part);
+                        if !is_other && !self.contains(part) {
+                            push(EntityKind::Company, part);
+                        }
+                    }
+                }
+            }
+        }
+
+        violations
+    }
+
+    /// 去掉原简历中没有的事实：数字替换为占位，其他事实所在的行整行删除
+    pub fn strip(&self, generated: &str) -> (String, Vec<GuardViolation>) {
+        let mut violations = self.check(generated);
+
+        let mut lines: Vec<String> = Vec::new();
+        for (i, line) in generated.split('\n').enumerate() {
+            let number = i + 1;
+            let found: Vec<&GuardViolation> = violations.iter().filter(|v| v.line == number).collect();
+            if found.is_empty() {
+                lines.push(line.to_string());
+            } else if found.iter().all(|v| v.kind == EntityKind::Number) {
+                let placeholder = if line.chars().any(is_cjk) { "【X】" } else { "[X]" };
+                let numbers: Vec<&str> = found.iter().map(|v| v.value.as_str()).collect();
+                let replaced = NUMBER.replace_all(line, |caps: &regex::Captures| {
+                    if numbers.contains(&&caps[0]) {
+                        placeholder.to_string()
+                    } else {
+                        caps[0].to_string()
+                    }
+                });
+                lines.push(replaced.into_owned());
+            }
+        }
+        for violation in &mut violations {
+            violation.stripped = true;
+        }
+
+        (lines.join("\n"), violations)
+    }
+
+    // ========== 辅助方法 ==========
+
+    fn contains(&self, value: &str) -> bool {
+        self.text.contains(&value.trim().to_lowercase())
+    }
+
+    /// 忽略空白比较，如 “2021.03-至今” 与 “2021.03 - 至今”
+    fn contains_compact(&self, value: &str) -> bool {
+        let compact = |s: &str| s.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+        compact(&self.text).contains(&compact(&value.to_lowercase()))
+    }
+}
+
+/// 去掉千分位，如 1,000 与 1000 视为相同
+fn normalize_number(value: &str) -> String {
+    value.replace(',', "")
+}
@@ -0,0 +1,420 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::*;
+use crate::services::RequirementExtractor;
+
+/// 常见公司简称到正式名称的映射
+const KNOWN_COMPANIES: &[(&str, &str)] = &[
+    ("阿里巴巴", "阿里巴巴"),
+    ("蚂蚁集团", "蚂蚁集团"),
+    ("腾讯", "腾讯"),
+    ("字节跳动", "字节跳动"),
+    ("字节", "字节跳动"),
+    ("美团", "美团"),
+    ("百度", "百度"),
+    ("京东", "京东"),
+    ("华为", "华为"),
+    ("网易", "网易"),
+    ("小米", "小米"),
+    ("拼多多", "拼多多"),
+    ("快手", "快手"),
+    ("滴滴", "滴滴"),
+    ("携程", "携程"),
+    ("Microsoft", "Microsoft"),
+    ("Google", "Google"),
+    ("Amazon", "Amazon"),
+    ("Apple", "Apple"),
+    ("Meta", "Meta"),
+];
+
+const CITIES: &[&str] = &[
+    "北京", "上海", "深圳", "广州", "杭州", "成都", "南京", "武汉", "西安", "苏州",
+    "天津", "重庆", "长沙", "厦门", "合肥", "香港", "新加坡", "远程",
+    "Beijing", "Shanghai", "Shenzhen", "Hangzhou", "Hong Kong", "Singapore",
+    "London", "New York", "San Francisco", "Seattle", "Remote",
+];
+
+const ROLE_WORDS: &[&str] = &[
+    "工程师", "开发", "架构师", "经理", "设计师", "分析师", "运营", "产品", "专家",
+    "总监", "主管", "实习生", "测试", "算法", "科学家",
+    "Engineer", "Developer", "Manager", "Architect", "Designer", "Analyst",
+    "Scientist", "Lead", "Intern", "Director", "Consultant", "Specialist",
+];
+
+const REQUIRED_HEADINGS: &[&str] = &[
+    "任职要求", "岗位要求", "任职资格", "职位要求", "技能要求", "我们希望你",
+    "requirements", "qualifications", "what you need", "what you'll need",
+    "must have", "must-have",
+];
+
+const PREFERRED_HEADINGS: &[&str] = &[
+    "加分项", "优先条件", "加分", "preferred", "preferred qualifications",
+    "nice to have", "nice-to-have", "bonus points", "bonus",
+];
+
+const RESPONSIBILITY_HEADINGS: &[&str] = &[
+    "岗位职责", "工作职责", "职位描述", "工作内容", "职责描述", "你将负责",
+    "responsibilities", "what you'll do", "what you will do", "the role",
+];
+
+/// 公司名、职位名的最大字符数，与 job_descriptions 表的列宽一致
+pub const MAX_NAME_CHARS: usize = 200;
+
+/// 工作地点的最大字符数
+const MAX_LOCATION_CHARS: usize = 100;
+
+static LEGAL_ENTITY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"([\p{Han}A-Za-z0-9（）()]{2,30}?(?:股份有限公司|有限责任公司|有限公司|集团))").unwrap()
+});
+
+static ABOUT_COMPANY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?:About|Join)\s+([A-Z][\w&.-]*(?:\s+[A-Z][\w&.-]*){0,3})").unwrap()
+});
+
+static CN_SALARY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(\d+(?:\.\d+)?)\s*(k|千|万|w)?\s*(?:-|~|～|—|–|至|到)\s*(\d+(?:\.\d+)?)\s*(k|千|万|w)").unwrap()
+});
+
+static DOLLAR_SALARY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\$\s?(\d[\d,]*(?:\.\d+)?)\s*(k)?\s*(?:-|–|—|~|to)\s*\$?\s?(\d[\d,]*(?:\.\d+)?)\s*(k)?").unwrap()
+});
+
+static BULLET_PREFIX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\s*(?:[-*•·●▪◆]|\d{1,2}\s*[.、)）]|[（(]\d{1,2}[)）])\s*").unwrap()
+});
+
+#[derive(Clone, Copy, PartialEq)]
+enum JdSection {
+    Unknown,
+    Required,
+    Preferred,
+    Responsibilities,
+}
+
+pub struct JdParser;
+
+impl JdParser {
+    /// 解析职位描述
+    pub fn parse(text: &str) -> ParsedJobDescription {
+        let lines: Vec<&str> = text
+            .lines()
+            .map(|l| l.trim())
+            .filter(|l| !l.is_empty())
+            .collect();
+
+        let title = Self::labeled_value(&lines, &["职位名称", "岗位名称", "职位", "岗位", "job title", "position", "title"], MAX_NAME_CHARS)
+            .or_else(|| Self::find_title(&lines));
+
+        let seniority = Self::infer_seniority(title.as_deref(), text);
+
+        let (required_lines, preferred_lines, responsibilities) = Self::split_sections(&lines);
+
+        let requirements = RequirementExtractor::extract(&required_lines, &preferred_lines);
+        let skills = |priority: RequirementPriority| {
+            requirements
+                .iter()
+                .filter(|r| r.kind == RequirementKind::Skill && r.priority == priority)
+                .filter_map(|r| r.skill.clone())
+                .collect::<Vec<_>>()
+        };
+        let required_skills = skills(RequirementPriority::Must);
+        let preferred_skills = skills(RequirementPriority::Preferred);
+
+        ParsedJobDescription {
+            company: Self::labeled_value(&lines, &["公司名称", "公司", "company"], MAX_NAME_CHARS)
+                .or_else(|| Self::find_company(&lines)),
+            title,
+            location: Self::labeled_value(&lines, &["工作地点", "工作城市", "办公地点", "地点", "城市", "location", "based in"], MAX_LOCATION_CHARS)
+                .or_else(|| Self::find_city(text)),
+            seniority,
+            salary: Self::find_salary(&lines),
+            required_skills,
+            preferred_skills,
+            responsibilities,
+            requirements,
+        }
+    }
+
+    /// 判断文本中是否出现某个术语；英文术语要求词边界，避免 Go 命中 Google
+    pub fn contains_term(text: &str, term: &str) -> bool {
+        Self::find_term(text, term).is_some()
+    }
+
+    /// 查找术语首次出现的字符偏移（忽略大小写）
+    pub fn find_term(text: &str, term: &str) -> Option<usize> {
+        let haystack = text.to_lowercase();
+        let needle = term.to_lowercase();
+        if needle.is_empty() {
+            return None;
+        }
+
+        let needs_boundary = needle.is_ascii();
+        haystack
+            .match_indices(&needle)
+            .find(|(i, _)| {
+                if !needs_boundary {
+                    return true;
+                }
+                let before = haystack[..*i].chars().next_back();
+                let after = haystack[i + needle.len()..].chars().next();
+                !before.is_some_and(|c| c.is_ascii_alphanumeric())
+                    && !after.is_some_and(|c| c.is_ascii_alphanumeric())
+            })
+            .map(|(i, _)| haystack[..i].chars().count())
+    }
+
+    /// 去掉行首的项目符号和序号
+    pub fn strip_bullet(line: &str) -> &str {
+        match BULLET_PREFIX.find(line) {
+            Some(m) => line[m.end()..].trim(),
+            None => line.trim(),
+        }
+    }
+
+    // ========== 辅助方法 ==========
+
+    /// 读取 "标签：值" 形式的字段；值在句末标点处截断，且不超过 max_chars 个字符
+    fn labeled_value(lines: &[&str], labels: &[&str], max_chars: usize) -> Option<String> {
+        for line in lines {
+            let line = Self::strip_bullet(line);
+            let Some((key, value)) = line.split_once(['：', ':']) else {
+                continue;
+            };
+            let key = key.trim().to_lowercase();
+            let value = value
+                .split(['。', '；', ';', '！', '!', '\t'])
+                .next()
+                .unwrap_or_default()
+                .trim();
+            if !value.is_empty() && labels.iter().any(|label| key == *label) {
+                return Some(value.chars().take(max_chars).collect::<String>().trim_end().to_string());
+            }
+        }
+        None
+    }
+
+    fn find_company(lines: &[&str]) -> Option<String> {
+        for line in lines {
+            if let Some(m) = LEGAL_ENTITY.captures(line).and_then(|c| c.get(1)) {
+                let name = m.as_str();
+                let name = ["欢迎加入", "我们是", "加入", "关于"]
+                    .iter()
+                    .fold(name, |n, prefix| n.strip_prefix(prefix).unwrap_or(n));
+                return Some(name.to_string());
+            }
+            if let Some(m) = ABOUT_COMPANY.captures(line).and_then(|c| c.get(1)) {
+                return Some(m.as_str().trim_end_matches(['.', ',']).to_string());
+            }
+        }
+
+        let text = lines.join("\n");
+        KNOWN_COMPANIES
+            .iter()
+            .find(|(alias, _)| Self::contains_term(&text, alias))
+            .map(|(_, name)| name.to_string())
+    }
+
+    fn find_title(lines: &[&str]) -> Option<String> {
+        lines.iter().take(5).find_map(|line| {
+            let line = Self::strip_bullet(line);
+            let line = ["招聘", "诚聘", "急聘", "Hiring:", "We are hiring"]
+                .iter()
+                .fold(line, |l, prefix| l.strip_prefix(prefix).unwrap_or(l))
+                .trim();
+
+            // 去掉 【北京】 这类前缀
+            let line = match line.strip_prefix('【').and_then(|l| l.split_once('】')) {
+                Some((_, rest)) => rest.trim(),
+                None => line,
+            };
+
+            let is_title = line.chars().count() <= 40
+                && !line.contains(['：', ':', '。'])
+                && ROLE_WORDS.iter().any(|w| Self::contains_term(line, w));
+            is_title.then(|| line.to_string())
+        })
+    }
+
+    fn find_city(text: &str) -> Option<String> {
+        CITIES
+            .iter()
+            .find(|city| Self::contains_term(text, city))
+            .map(|city| city.to_string())
+    }
+
+    /// 根据职位名称和正文推断职级
+    pub fn infer_seniority(title: Option<&str>, text: &str) -> Option<String> {
+        const LEVELS: &[(&str, &[&str])] = &[
+            ("intern", &["实习", "intern"]),
+            ("principal", &["首席", "principal"]),
+            ("staff", &["专家", "staff"]),
+            ("manager", &["总监", "经理", "主管", "manager", "director", "head of"]),
+            ("lead", &["负责人", "组长", "lead"]),
+            ("senior", &["高级", "资深", "senior", "sr."]),
+            ("junior", &["初级", "应届", "junior", "entry level", "graduate"]),
+            ("mid", &["中级", "mid-level", "intermediate"]),
+        ];
+
+        let find_level = |source: &str| {
+            LEVELS
+                .iter()
+                .find(|(_, words)| words.iter().any(|w| Self::contains_term(source, w)))
+                .map(|(level, _)| level.to_string())
+        };
+
+        title.and_then(find_level).or_else(|| find_level(text))
+    }
+
+    fn find_salary(lines: &[&str]) -> Option<SalaryRange> {
+        for line in lines {
+            let lower = line.to_lowercase();
+
+            if let Some(caps) = DOLLAR_SALARY.captures(line) {
+                let parse = |value: &str, unit: Option<regex::Match>| {
+                    let value: f64 = value.replace(',', "").parse().ok()?;
+                    Some(if unit.is_some() { value * 1000.0 } else { value } as i64)
+                };
+                let (Some(min), Some(max)) = (
+                    parse(&caps[1], caps.get(2).or(caps.get(4))),
+                    parse(&caps[3], caps.get(4)),
+                ) else {
+                    continue;
+                };
+                let period = if ["/mo", "per month", "monthly"].iter().any(|m| lower.contains(m)) {
+                    "month"
+                } else {
+                    "year"
+                };
+                return Some(SalaryRange {
+                    min,
+                    max,
+                    currency: "USD".to_string(),
+                    period: period.to_string(),
+                });
+            }
+
+            if let Some(caps) = CN_SALARY.captures(line) {
+                let multiplier = |unit: &str| match unit.to_lowercase().as_str() {
+                    "k" | "千" => 1_000.0,
+                    "万" | "w" => 10_000.0,
+                    _ => 1.0,
+                };
+                let max_unit = multiplier(&caps[4]);
+                let min_unit = caps.get(2).map(|u| multiplier(u.as_str())).unwrap_or(max_unit);
+                let (Ok(min), Ok(max)) = (caps[1].parse::<f64>(), caps[3].parse::<f64>()) else {
+                    continue;
+                };
+                let (min, max) = ((min * min_unit) as i64, (max * max_unit) as i64);
+
+                let currency = if lower.contains("usd") {
+                    "USD"
+                } else if lower.contains("eur") || line.contains('€') {
+                    "EUR"
+                } else if lower.contains("sgd") {
+                    "SGD"
+                } else {
+                    "CNY"
+                };
+
+                // 未注明时，超过 10 万视为年薪
+                let period = if ["年薪", "/年", "每年", "per year", "annual"].iter().any(|m| lower.contains(m)) {
+                    "year"
+                } else if ["月薪", "/月", "每月"].iter().any(|m| lower.contains(m)) {
+                    "month"
+                } else if max >= 100_000 {
+                    "year"
+                } else {
+                    "month"
+                };
+
+                return Some(SalaryRange {
+                    min,
+                    max,
+                    currency: currency.to_string(),
+                    period: period.to_string(),
+                });
+            }
+        }
+        None
+    }
+
+    /// 按小节拆分，返回（必备要求行，加分项行，职责列表）
+    fn split_sections(lines: &[&str]) -> (Vec<String>, Vec<String>, Vec<String>) {
+        let mut required = Vec::new();
+        let mut preferred = Vec::new();
+        let mut responsibilities = Vec::new();
+        let mut section = JdSection::Unknown;
+
+        for line in lines {
+            let stripped = Self::strip_bullet(line);
+
+            // 小节标题，可能带有同一行的内容，如 "任职要求：3年以上经验"
+            let (head, rest) = match stripped.split_once(['：', ':']) {
+                Some((head, rest)) => (head.trim(), rest.trim()),
+                None => (stripped, ""),
+            };
+            let content = match Self::heading_section(head) {
+                Some(next) => {
+                    section = next;
+                    if rest.is_empty() {
+                        continue;
+                    }
+                    rest
+                }
+                None => stripped,
+            };
+
+            // 行内的 “优先” 等标记由 RequirementExtractor 逐句处理
+            match section {
+                JdSection::Responsibilities => responsibilities.push(content.to_string()),
+                JdSection::Preferred => preferred.push(content.to_string()),
+                JdSection::Required | JdSection::Unknown => required.push(content.to_string()),
+            }
+        }
+
+        (required, preferred, responsibilities)
+    }
+
+    fn heading_section(head: &str) -> Option<JdSection> {
+        let head = head
+            .trim_matches(|c: char| c.is_ascii_punctuation() || "【】#".contains(c))
+            .trim()
+            .to_lowercase();
+
+        if head.chars().count() > 24 {
+            return None;
+        }
+
+        let matches = |headings: &[&str]| headings.iter().any(|h| head == *h);
+        if matches(RESPONSIBILITY_HEADINGS) {
+            Some(JdSection::Responsibilities)
+        } else if matches(PREFERRED_HEADINGS) {
+            Some(JdSection::Preferred)
+        } else if matches(REQUIRED_HEADINGS) {
+            Some(JdSection::Required)
+        } else {
+            None
+        }
+    }
+}
+
+// ========== JD 持久化 ==========
+
+/// 查询属于指定用户的 JD
+pub async fn find_job_description(
+    pool: &PgPool,
+    user_id: Uuid,
+    job_id: Uuid,
+) -> Result<Option<JobDescription>, sqlx::Error> {
+    sqlx::query_as::<_, JobDescription>(
+        "SELECT * FROM job_descriptions WHERE id = $1 AND user_id = $2",
+    )
+    .bind(job_id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await
+}
@@ -0,0 +1,80 @@
+use std::sync::LazyLock;
+
+use serde::Deserialize;
+
+use crate::models::*;
+use crate::services::SkillTaxonomy;
+
+/// 内置学习资源目录
+const BUNDLED_RESOURCES: &str = include_str!("../../data/learning_resources.json");
+
+/// 自定义学习资源目录文件的环境变量，格式与 learning_resources.json 相同；
+/// 文件中出现的技能整体替换内置资源，其他技能沿用内置资源
+const RESOURCES_ENV: &str = "LEARNING_RESOURCES_PATH";
+
+/// 每个技能最多返回的资源数
+const MAX_RESOURCES: usize = 3;
+
+static GLOBAL: LazyLock<LearningCatalog> = LazyLock::new(|| {
+    let mut catalog = LearningCatalog::from_json(BUNDLED_RESOURCES).expect("invalid bundled learning resources");
+
+    if let Ok(path) = std::env::var(RESOURCES_ENV) {
+        match std::fs::read_to_string(&path) {
+            Ok(content) => match LearningCatalog::from_json(&content) {
+                Ok(custom) => catalog.merge(custom),
+                Err(e) => tracing::warn!("Invalid learning resources {}: {}", path, e),
+            },
+            Err(e) => tracing::warn!("Failed to load learning resources {}: {}", path, e),
+        }
+    }
+
+    catalog
+});
+
+#[derive(Deserialize)]
+struct CatalogFile {
+    resources: Vec<LearningResource>,
+}
+
+/// 按技能查找学习资源
+#[derive(Debug, Clone, Default)]
+pub struct LearningCatalog {
+    resources: Vec<LearningResource>,
+}
+
+impl LearningCatalog {
+    pub fn from_json(content: &str) -> Result<Self, serde_json::Error> {
+        let file: CatalogFile = serde_json::from_str(content)?;
+        Ok(Self {
+            resources: file.resources,
+        })
+    }
+
+    pub fn global() -> &'static LearningCatalog {
+        &GLOBAL
+    }
+
+    /// 技能的学习资源；名称和别名都按技能词典归一后比较
+    pub fn resources_for(&self, skill: &str) -> Vec<LearningResource> {
+        let taxonomy = SkillTaxonomy::global();
+        let canonical = |name: &str| {
+            taxonomy
+                .lookup(name)
+                .map(|s| s.name.to_lowercase())
+                .unwrap_or_else(|| name.trim().to_lowercase())
+        };
+        let target = canonical(skill);
+        self.resources
+            .iter()
+            .filter(|r| canonical(&r.skill) == target)
+            .take(MAX_RESOURCES)
+            .cloned()
+            .collect()
+    }
+
+    fn merge(&mut self, custom: LearningCatalog) {
+        self.resources
+            .retain(|r| !custom.resources.iter().any(|c| c.skill.eq_ignore_ascii_case(&r.skill)));
+        self.resources.extend(custom.resources);
+    }
+}
@@ -0,0 +1,411 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::models::*;
+use crate::services::document::DocLine;
+use crate::services::proofread::{is_english_word, width_mismatches};
+use crate::services::timeline::{DATE_PARTS, DATE_RANGE, MONTH_NAMES};
+use crate::services::tokenizer::is_cjk;
+use crate::services::{ResumeDocument, SkillTaxonomy};
+
+/// 行首的项目符号；编号列表不参与统一
+static BULLET_MARKER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\s*)([-*•·●▪◆])\s*").unwrap());
+
+/// 要点句末的标点
+const TRAILING_MARKS: &[char] = &['。', '.', ';', '；', ',', '，'];
+
+/// 格式一致性检查：日期、项目符号、技术名词大小写、句末标点和全半角
+pub struct ResumeLinter;
+
+impl ResumeLinter {
+    pub fn lint(doc: &ResumeDocument, rules: &LintRules) -> LintReport {
+        let lines: Vec<&DocLine> = doc.lines().collect();
+
+        // 先生成的修改优先，与之重叠的修改丢弃
+        let mut edits = Vec::new();
+        if rules.trailing_punctuation {
+            Self::trailing_punctuation(&lines, rules.trailing_style, &mut edits);
+        }
+        if rules.bullet_style {
+            Self::bullet_style(&lines, rules.bullet_marker, &mut edits);
+        }
+        if rules.date_format {
+            Self::date_format(&lines, rules.date_style, &mut edits);
+        }
+        if rules.tech_capitalization {
+            Self::tech_capitalization(&lines, &mut edits);
+        }
+        if rules.punctuation_width {
+            for line in &lines {
+                edits.extend(width_mismatches(&line.text).into_iter().map(|m| Edit {
+                    line,
+                    start: m.start,
+                    end: m.end,
+                    replacement: m.replacement,
+                    rule: LintRule::PunctuationWidth,
+                    message: m.message,
+                }));
+            }
+        }
+
+        let mut accepted: Vec<Edit> = Vec::new();
+        for edit in edits {
+            if !accepted.iter().any(|a| a.overlaps(&edit)) {
+                accepted.push(edit);
+            }
+        }
+        accepted.sort_by_key(|e| (e.line.number, e.start));
+
+        LintReport {
+            fixed: Self::apply(&doc.text, &accepted),
+            findings: accepted
+                .into_iter()
+                .map(|e| LintFinding {
+                    rule: e.rule,
+                    message: e.message,
+                    span: e.line.slice_span(e.start, e.end),
+                    replacement: e.replacement,
+                })
+                .collect(),
+        }
+    }
+
+    // ========== 规则 ==========
+
+    /// 要点统一加或不加句末标点
+    fn trailing_punctuation<'a>(lines: &[&'a DocLine], style: Option<TrailingPunctuation>, edits: &mut Vec<Edit<'a>>) {
+        let bullets: Vec<(&DocLine, usize, &str)> = lines
+            .iter()
+            .filter(|l| l.is_bullet() && !l.content().is_empty())
+            .filter_map(|l| Some((*l, l.text.find(l.content())?, l.content())))
+            .collect();
+        let style = style.unwrap_or_else(|| {
+            let ending = bullets.iter().filter(|(_, _, c)| c.ends_with(TRAILING_MARKS)).count();
+            if ending * 2 > bullets.len() {
+                TrailingPunctuation::Always
+            } else {
+                TrailingPunctuation::Never
+            }
+        });
+
+        for (line, offset, content) in bullets {
+            // 省略号和 etc. 不算句末标点
+            if content.ends_with("...") || content.ends_with('…') || content.ends_with("etc.") {
+                continue;
+            }
+            let end = offset + content.len();
+            let stem = content.trim_end_matches(TRAILING_MARKS);
+            let start = offset + stem.len();
+            let period = if content.chars().any(is_cjk) { "。" } else { "." };
+            let (replacement, message) = match style {
+                TrailingPunctuation::Never if start < end => {
+                    (String::new(), format!("要点句末标点不统一，去掉句末的「{}」", &line.text[start..end]))
+                }
+                TrailingPunctuation::Always if start == end => {
+                    (period.to_string(), format!("要点句末标点不统一，句末加上「{}」", period))
+                }
+                TrailingPunctuation::Always if &line.text[start..end] != period => (
+                    period.to_string(),
+                    format!("要点句末标点不统一，「{}」改为「{}」", &line.text[start..end], period),
+                ),
+                _ => continue,
+            };
+            edits.push(Edit {
+                line,
+                start,
+                end,
+                replacement,
+                rule: LintRule::TrailingPunctuation,
+                message,
+            });
+        }
+    }
+
+    /// 统一项目符号，符号后保留一个空格
+    fn bullet_style<'a>(lines: &[&'a DocLine], marker: Option<char>, edits: &mut Vec<Edit<'a>>) {
+        let bullets: Vec<(&DocLine, usize, &str, char)> = lines
+            .iter()
+            .filter_map(|line| {
+                let caps = BULLET_MARKER.captures(&line.text)?;
+                let prefix = caps.get(0).unwrap();
+                let symbol = caps[2].chars().next()?;
+                let rest = &line.text[prefix.end()..];
+                // **加粗** 之类的写法
+                if rest.is_empty() || rest.starts_with(symbol) {
+                    return None;
+                }
+                Some((*line, prefix.end(), caps.get(1).unwrap().as_str(), symbol))
+            })
+            .collect();
+        let Some(target) = marker.or_else(|| most_common(bullets.iter().map(|b| b.3))) else {
+            return;
+        };
+
+        for (line, prefix_end, indent, symbol) in bullets {
+            let expected = format!("{}{} ", indent, target);
+            if line.text[..prefix_end] == expected {
+                continue;
+            }
+            let message = if symbol == target {
+                format!("项目符号「{}」后保留一个空格", target)
+            } else {
+                format!("项目符号不统一，「{}」改为「{}」", symbol, target)
+            };
+            edits.push(Edit {
+                line,
+                start: 0,
+                end: prefix_end,
+                replacement: expected,
+                rule: LintRule::BulletStyle,
+                message,
+            });
+        }
+    }
+
+    /// 时间段统一日期写法和分隔符，如 2019-3 ~ 2021.06 → 2019.03 - 2021.06
+    fn date_format<'a>(lines: &[&'a DocLine], style: Option<DateStyle>, edits: &mut Vec<Edit<'a>>) {
+        let ranges: Vec<DateRange> = lines
+            .iter()
+            .flat_map(|line| {
+                DATE_RANGE.captures_iter(&line.text).filter_map(move |caps| {
+                    let whole = caps.get(0).unwrap();
+                    let (from, to) = (caps.get(1).unwrap(), caps.get(2).unwrap());
+                    Some(DateRange {
+                        line,
+                        start: whole.start(),
+                        end: whole.end(),
+                        from: DatePoint::parse(from.as_str())?,
+                        // 至今、Present 等保留原文
+                        to: DatePoint::parse(to.as_str()),
+                        to_text: to.as_str().to_string(),
+                        separator: line.text[from.end()..to.start()].to_string(),
+                    })
+                })
+            })
+            .collect();
+
+        let styles = ranges
+            .iter()
+            .flat_map(|r| std::iter::once(&r.from).chain(r.to.as_ref()))
+            .filter_map(|p| p.style);
+        let Some(style) = style.or_else(|| most_common(styles)) else {
+            return;
+        };
+        let Some(separator) = most_common(ranges.iter().map(|r| r.separator.clone())) else {
+            return;
+        };
+
+        for range in ranges {
+            let to = match &range.to {
+                Some(point) => point.render(style),
+                None => range.to_text.clone(),
+            };
+            let replacement = format!("{}{}{}", range.from.render(style), separator, to);
+            if range.line.text[range.start..range.end] == replacement {
+                continue;
+            }
+            edits.push(Edit {
+                line: range.line,
+                start: range.start,
+                end: range.end,
+                message: format!("日期写法不统一，改为「{}」", replacement),
+                replacement,
+                rule: LintRule::DateFormat,
+            });
+        }
+    }
+
+    /// 技术名词按技能词典中的写法，如 javascript → JavaScript、mysql → MySQL
+    fn tech_capitalization<'a>(lines: &[&'a DocLine], edits: &mut Vec<Edit<'a>>) {
+        let taxonomy = SkillTaxonomy::global();
+        let mut canonical: HashMap<String, String> = HashMap::new();
+        for skill in taxonomy.skills() {
+            if skill.case_sensitive || !taxonomy.is_technical(skill) {
+                continue;
+            }
+            for name in std::iter::once(&skill.name).chain(skill.aliases.iter()) {
+                let lower = name.to_lowercase();
+                // 与普通英文单词相同的（如 Swift、Spring）无法判断是否指技术
+                let cased = name.is_ascii() && name.chars().any(|c| c.is_ascii_uppercase());
+                if cased && lower.len() >= 2 && !is_english_word(&lower) {
+                    canonical.entry(lower).or_insert_with(|| name.clone());
+                }
+            }
+        }
+        if canonical.is_empty() {
+            return;
+        }
+
+        let mut terms: Vec<&String> = canonical.values().collect();
+        terms.sort_by_key(|t| std::cmp::Reverse(t.len()));
+        let alternation: Vec<String> = terms.iter().map(|t| regex::escape(t)).collect();
+        let Ok(pattern) = Regex::new(&format!("(?i)(?:{})", alternation.join("|"))) else {
+            return;
+        };
+
+        for line in lines {
+            let text = &line.text;
+            for m in pattern.find_iter(text) {
+                let prev = text[..m.start()].chars().last();
+                let next = text[m.end()..].chars().next();
+                let joined_before = prev.is_some_and(|c| c.is_ascii_alphanumeric() || "._-".contains(c));
+                let joined_after = next.is_some_and(|c| c.is_ascii_alphanumeric() || "+#_".contains(c));
+                if joined_before || joined_after {
+                    continue;
+                }
+                // 网址、邮箱和路径中的写法不改
+                let segment_start = text[..m.start()].rfind(char::is_whitespace).map_or(0, |i| i + 1);
+                let segment_end = text[m.end()..].find(char::is_whitespace).map_or(text.len(), |i| m.end() + i);
+                if text[segment_start..segment_end].contains(['/', '@', '\\']) {
+                    continue;
+                }
+
+                let Some(name) = canonical.get(&m.as_str().to_lowercase()) else {
+                    continue;
+                };
+                if m.as_str() != name {
+                    edits.push(Edit {
+                        line,
+                        start: m.start(),
+                        end: m.end(),
+                        replacement: name.clone(),
+                        rule: LintRule::TechCapitalization,
+                        message: format!("技术名词「{}」应写作「{}」", m.as_str(), name),
+                    });
+                }
+            }
+        }
+    }
+
+    // ========== 辅助方法 ==========
+
+    /// 按行应用修改，修改之间互不重叠
+    fn apply(text: &str, edits: &[Edit]) -> String {
+        text.split('\n')
+            .enumerate()
+            .map(|(i, raw)| {
+                let mut line = raw.to_string();
+                for edit in edits.iter().rev().filter(|e| e.line.number == i + 1) {
+                    line.replace_range(edit.start..edit.end, &edit.replacement);
+                }
+                line
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// 出现最多的值，次数相同时取最先出现的
+fn most_common<T: PartialEq>(values: impl Iterator<Item = T>) -> Option<T> {
+    let mut counts: Vec<(T, usize)> = Vec::new();
+    for value in values {
+        match counts.iter_mut().find(|(v, _)| *v == value) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((value, 1)),
+        }
+    }
+    let mut best: Option<(T, usize)> = None;
+    for (value, count) in counts {
+        if best.as_ref().is_none_or(|(_, c)| count > *c) {
+            best = Some((value, count));
+        }
+    }
+    best.map(|(value, _)| value)
+}
+
+// ========== 内部结果结构 ==========
+
+/// 对某一行的修改，起止为字节偏移
+struct Edit<'a> {
+    line: &'a DocLine,
+    start: usize,
+    end: usize,
+    replacement: String,
+    rule: LintRule,
+    message: String,
+}
+
+impl Edit<'_> {
+    fn overlaps(&self, other: &Edit) -> bool {
+        if self.line.number != other.line.number {
+            return false;
+        }
+        // 同一位置的两处插入也视为冲突
+        (self.start < other.end && other.start < self.end) || (self.start == other.start && self.end == other.end)
+    }
+}
+
+struct DateRange<'a> {
+    line: &'a DocLine,
+    start: usize,
+    end: usize,
+    from: DatePoint,
+    to: Option<DatePoint>,
+    to_text: String,
+    separator: String,
+}
+
+/// 单个日期；只写年份时没有月份，也不区分写法
+pub struct DatePoint {
+    year: i32,
+    month: Option<u32>,
+    style: Option<DateStyle>,
+}
+
+impl DatePoint {
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let caps = DATE_PARTS.captures(text)?;
+        if let Some(name) = caps.name("name") {
+            let name = name.as_str().to_lowercase();
+            let month = MONTH_NAMES.iter().position(|m| *m == name)? as u32 + 1;
+            return Some(Self {
+                year: caps["y1"].parse().ok()?,
+                month: Some(month),
+                style: Some(DateStyle::MonthName),
+            });
+        }
+        if let Some(month) = caps.name("m2") {
+            let month: u32 = month.as_str().parse().ok()?;
+            return (1..=12).contains(&month).then_some(Self {
+                year: caps["y2"].parse().ok()?,
+                month: Some(month),
+                style: Some(DateStyle::MonthFirst),
+            });
+        }
+
+        let year = caps.name("y3")?;
+        let month = caps.name("m3");
+        let style = month.map(|m| match &text[year.end()..m.start()] {
+            s if s.contains('年') => DateStyle::Chinese,
+            s if s.contains('-') => DateStyle::Dashed,
+            s if s.contains('/') => DateStyle::Slashed,
+            _ => DateStyle::Dotted,
+        });
+        Some(Self {
+            year: year.as_str().parse().ok()?,
+            month: month.and_then(|m| m.as_str().parse().ok()),
+            style,
+        })
+        .filter(|p| p.month.is_none_or(|m| (1..=12).contains(&m)))
+    }
+
+    pub fn render(&self, style: DateStyle) -> String {
+        let (year, Some(month)) = (self.year, self.month) else {
+            return self.year.to_string();
+        };
+        match style {
+            DateStyle::Dotted => format!("{}.{:02}", year, month),
+            DateStyle::Dashed => format!("{}-{:02}", year, month),
+            DateStyle::Slashed => format!("{}/{:02}", year, month),
+            DateStyle::MonthFirst => format!("{:02}/{}", month, year),
+            DateStyle::Chinese => format!("{}年{}月", year, month),
+            DateStyle::MonthName => {
+                let name = MONTH_NAMES[month as usize - 1];
+                format!("{}{} {}", name[..1].to_uppercase(), &name[1..], year)
+            }
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_BASE_URL: &str = "https://api.openai.com/v1";
+const DEFAULT_MODEL: &str = "gpt-4o-mini";
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// 未配置 LLM_API_KEY 时为空，调用方使用规则引擎
+static CLIENT: LazyLock<Option<LlmClient>> = LazyLock::new(LlmClient::from_env);
+
+#[derive(Debug, thiserror::Error)]
+pub enum LlmError {
+    #[error("LLM request failed: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error("LLM returned status {0}: {1}")]
+    Status(u16, String),
+    #[error("LLM returned an empty response")]
+    Empty,
+}
+
+/// OpenAI 兼容的对话接口
+pub struct LlmClient {
+    http: reqwest::Client,
+    base_url: String,
+    api_key: String,
+    provider: String,
+    model: String,
+}
+
+#[derive(Serialize)]
+struct ChatRequest<'a> {
+    model: &'a str,
+    messages: [ChatMessage<'a>; 2],
+    temperature: f32,
+}
+
+#[derive(Serialize)]
+struct ChatMessage<'a> {
+    role: &'a str,
+    content: &'a str,
+}
+
+#[derive(Deserialize)]
+struct ChatResponse {
+    choices: Vec<ChatChoice>,
+}
+
+#[derive(Deserialize)]
+struct ChatChoice {
+    message: ChatReply,
+}
+
+#[derive(Deserialize)]
+struct ChatReply {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+impl LlmClient {
+    /// 环境变量：LLM_API_KEY（必填）、LLM_BASE_URL、LLM_MODEL、LLM_PROVIDER、LLM_TIMEOUT_SECS
+    fn from_env() -> Option<Self> {
+        let api_key = std::env::var("LLM_API_KEY").ok().filter(|k| !k.trim().is_empty())?;
+        let timeout = std::env::var("LLM_TIMEOUT_SECS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_TIMEOUT_SECS);
+        let http = match reqwest::Client::builder().timeout(Duration::from_secs(timeout)).build() {
+            Ok(http) => http,
+            Err(e) => {
+                tracing::warn!("Failed to build LLM client: {}", e);
+                return None;
+            }
+        };
+
+        Some(Self {
+            http,
+            base_url: std::env::var("LLM_BASE_URL")
+                .unwrap_or_else(|_| DEFAULT_BASE_URL.to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            api_key,
+            provider: std::env::var("LLM_PROVIDER").unwrap_or_else(|_| "openai".to_string()),
+            model: std::env::var("LLM_MODEL").unwrap_or_else(|_| DEFAULT_MODEL.to_string()),
+        })
+    }
+
+    pub fn global() -> Option<&'static LlmClient> {
+        CLIENT.as_ref()
+    }
+
+    pub fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// 单轮对话，返回模型回复的文本
+    pub async fn chat(&self, system: &str, user: &str) -> Result<String, LlmError> {
+        let request = ChatRequest {
+            model: &self.model,
+            messages: [
+                ChatMessage { role: "system", content: system },
+                ChatMessage { role: "user", content: user },
+            ],
+            temperature: 0.7,
+        };
+
+        let response = self
+            .http
+            .post(format!("{}/chat/completions", self.base_url))
+            .bearer_auth(&self.api_key)
+            .json(&request)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(LlmError::Status(status.as_u16(), body));
+        }
+
+        let response: ChatResponse = response.json().await?;
+        response
+            .choices
+            .into_iter()
+            .next()
+            .and_then(|c| c.message.content)
+            .filter(|c| !c.trim().is_empty())
+            .ok_or(LlmError::Empty)
+    }
+}
+
+/// 从模型回复中取出 JSON，兼容 ```json 代码块
+pub fn extract_json(reply: &str) -> &str {
+    let start = reply.find(['{', '[']).unwrap_or(0);
+    let end = reply.rfind(['}', ']']).map(|i| i + 1).unwrap_or(reply.len());
+    if start < end {
+        &reply[start..end]
+    } else {
+        reply
+    }
+}
@@ -1,3 +1,52 @@
 pub mod resume;
+pub mod versions;
+pub mod jd_parser;
+pub mod analysis;
+pub mod document;
+pub mod scoring;
+pub mod tokenizer;
+pub mod relevance;
+pub mod taxonomy;
+pub mod requirements;
+pub mod timeline;
+pub mod bullets;
+pub mod llm;
+pub mod rewrite;
+pub mod tailor;
+pub mod guard;
+pub mod completeness;
+pub mod ats;
+pub mod proofread;
+pub mod lint;
+pub mod seniority;
+pub mod learning;
+pub mod gaps;
+pub mod batch;
+pub mod screening;
+pub mod redact;
+pub mod translate;
 
 pub use resume::ResumeOptimizer;
+pub use versions::VersionDiffer;
+pub use jd_parser::JdParser;
+pub use document::ResumeDocument;
+pub use scoring::ScoringEngine;
+pub use tokenizer::Tokenizer;
+pub use relevance::RelevanceScorer;
+pub use taxonomy::SkillTaxonomy;
+pub use requirements::RequirementExtractor;
+pub use timeline::TimelineAnalyzer;
+pub use bullets::BulletAnalyzer;
+pub use rewrite::BulletRewriter;
+pub use tailor::ResumeTailor;
+pub use guard::HallucinationGuard;
+pub use completeness::CompletenessChecker;
+pub use ats::AtsChecker;
+pub use proofread::Proofreader;
+pub use lint::ResumeLinter;
+pub use seniority::SeniorityAnalyzer;
+pub use learning::LearningCatalog;
+pub use gaps::SkillGapAnalyzer;
+pub use batch::BatchScorer;
+pub use redact::Redactor;
+pub use translate::ResumeTranslator;
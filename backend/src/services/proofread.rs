@@ -0,0 +1,729 @@
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::models::*;
+use crate::services::document::{DocLine, SectionKind};
+use crate::services::timeline::DATE_RANGE;
+use crate::services::tokenizer::is_cjk;
+use crate::services::{ResumeDocument, SkillTaxonomy, Tokenizer};
+
+const EN_WORDS: &str = include_str!("../../data/dict/en_words.txt");
+const EN_VERBS: &str = include_str!("../../data/dict/en_verbs.txt");
+const ZH_CONFUSABLES: &str = include_str!("../../data/dict/zh_confusables.txt");
+
+/// 常见英文拼写错误及正确写法
+const COMMON_MISSPELLINGS: &[(&str, &str)] = &[
+    ("acheive", "achieve"), ("acheived", "achieved"), ("acheivement", "achievement"), ("recieve", "receive"),
+    ("recieved", "received"), ("seperate", "separate"), ("seperately", "separately"), ("managment", "management"),
+    ("developement", "development"), ("enviroment", "environment"), ("enviornment", "environment"),
+    ("implemention", "implementation"), ("implmentation", "implementation"), ("responsable", "responsible"),
+    ("responsibilty", "responsibility"), ("experiance", "experience"), ("expierence", "experience"),
+    ("sucessful", "successful"), ("sucessfully", "successfully"), ("succesful", "successful"),
+    ("occured", "occurred"), ("occurence", "occurrence"), ("begining", "beginning"), ("commited", "committed"),
+    ("comittee", "committee"), ("accomodate", "accommodate"), ("definately", "definitely"),
+    ("maintainance", "maintenance"), ("maintenence", "maintenance"), ("perfomance", "performance"),
+    ("performace", "performance"), ("profesional", "professional"), ("proffesional", "professional"),
+    ("knowlege", "knowledge"), ("langauge", "language"), ("colaborate", "collaborate"),
+    ("colaboration", "collaboration"), ("collegue", "colleague"), ("buisness", "business"),
+    ("bussiness", "business"), ("excelent", "excellent"), ("efficent", "efficient"),
+    ("independant", "independent"), ("intergration", "integration"), ("infrastucture", "infrastructure"),
+    ("architecure", "architecture"), ("arcitecture", "architecture"), ("databse", "database"),
+    ("optimzation", "optimization"), ("optimizaton", "optimization"), ("scalabilty", "scalability"),
+    ("reliabilty", "reliability"), ("availabilty", "availability"), ("deployement", "deployment"),
+    ("requirment", "requirement"), ("stratergy", "strategy"), ("technolgy", "technology"),
+    ("tecnology", "technology"), ("algorythm", "algorithm"), ("algoritm", "algorithm"),
+    ("paramater", "parameter"), ("refered", "referred"), ("transfered", "transferred"), ("untill", "until"),
+    ("wich", "which"), ("thier", "their"), ("alot", "a lot"),
+];
+
+/// 不规则动词的过去式；原形与过去式相同（cut、set）或有歧义（found）的不收录
+const IRREGULAR_PAST: &[(&str, &str)] = &[
+    ("lead", "led"), ("build", "built"), ("run", "ran"), ("drive", "drove"), ("grow", "grew"), ("make", "made"),
+    ("write", "wrote"), ("teach", "taught"), ("sell", "sold"), ("win", "won"), ("bring", "brought"),
+    ("hold", "held"), ("keep", "kept"), ("take", "took"), ("give", "gave"), ("begin", "began"),
+    ("spend", "spent"), ("meet", "met"), ("think", "thought"), ("buy", "bought"), ("pay", "paid"),
+    ("send", "sent"), ("speak", "spoke"), ("understand", "understood"), ("oversee", "oversaw"),
+    ("rebuild", "rebuilt"), ("rewrite", "rewrote"), ("undertake", "undertook"), ("get", "got"), ("go", "went"),
+    ("come", "came"), ("know", "knew"), ("see", "saw"), ("become", "became"), ("choose", "chose"),
+    ("draw", "drew"), ("say", "said"), ("tell", "told"), ("lose", "lost"),
+];
+
+/// 过去式需双写末尾辅音的多音节动词，如 committed
+const DOUBLED_CONSONANT: &[&str] = &[
+    "commit", "submit", "refer", "occur", "transfer", "control", "equip", "admit", "permit", "compel", "propel",
+    "excel", "patrol",
+];
+
+/// 英文常见前缀，去掉后再查词典，如 re-architect、co-founded
+const PREFIXES: &[&str] = &[
+    "re", "co", "un", "non", "pre", "post", "multi", "sub", "over", "under", "self", "cross", "micro", "inter",
+    "mis", "de", "dis", "out", "up",
+];
+
+/// 根据词典给出候选时，单词的最小长度
+const MIN_SUGGEST_LENGTH: usize = 6;
+
+static ENGLISH: LazyLock<EnglishDictionary> = LazyLock::new(|| EnglishDictionary::new(EN_WORDS, EN_VERBS));
+
+/// （错误写法，正确写法）
+static CONFUSABLES: LazyLock<Vec<(String, String)>> = LazyLock::new(|| {
+    ZH_CONFUSABLES
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .filter_map(|l| {
+            let mut parts = l.split_whitespace();
+            Some((parts.next()?.to_string(), parts.next()?.to_string()))
+        })
+        .collect()
+});
+
+/// 以空白分隔的片段，含网址、邮箱、路径的片段不做拼写检查
+static SEGMENT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\S+").unwrap());
+
+static WORD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[A-Za-z]+(?:'[A-Za-z]+)?").unwrap());
+
+static LEADING_WORD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[A-Za-z]+").unwrap());
+
+/// 仍在进行中的经历
+static ONGOING: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)至今|现在|目前|present|current|now|today").unwrap());
+
+/// 状语后应为 “地”，如 “认真的完成” → “认真地完成”
+static DE_ADVERBIAL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(认真|积极|主动|快速|迅速|高效|顺利|独立|有效|成功|按时|准确|及时|持续|稳定|全面|深入|合理|灵活|熟练)的(完成|推进|负责|解决|参与|处理|交付|落地|开发|实现|推动|学习|掌握|应用|运用|沟通|协调|执行)").unwrap()
+});
+
+/// 补语前应为 “得”，如 “完成的很好” → “完成得很好”
+static DE_COMPLEMENT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(完成|做|表现|学|掌握|处理|发展|进展|推进)的(很|非常|十分|比较|相当|更|最|不错|出色)").unwrap()
+});
+
+static HALF_WIDTH_PARENS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\(([^()]*)\)").unwrap());
+
+static REPEATED_PUNCTUATION: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[，。；：,;！？!?]{2,}").unwrap());
+
+/// 离线的拼写、错别字、时态和标点检查
+pub struct Proofreader;
+
+impl Proofreader {
+    pub fn check(doc: &ResumeDocument) -> Vec<ProofreadFinding> {
+        let taxonomy = SkillTaxonomy::global();
+        let tokenizer = Tokenizer::global();
+        // 技能名称和别名中的英文单词视为正确拼写
+        let tech: HashSet<String> = taxonomy
+            .skills()
+            .iter()
+            .flat_map(|s| std::iter::once(&s.name).chain(s.aliases.iter()))
+            .flat_map(|name| name.split(|c: char| !c.is_ascii_alphanumeric()).map(str::to_lowercase).collect::<Vec<_>>())
+            .filter(|w| !w.is_empty())
+            .collect();
+        let is_tech = |word: &str| tech.contains(word) || tokenizer.is_term(word);
+
+        let mut findings = Vec::new();
+        for line in doc.lines() {
+            Self::spelling(line, &is_tech, &mut findings);
+            Self::confusables(line, &mut findings);
+            Self::punctuation(line, &mut findings);
+        }
+        Self::tense(doc, &mut findings);
+
+        findings.sort_by_key(|f| (f.span.line, f.span.start));
+        findings
+    }
+
+    // ========== 英文拼写 ==========
+
+    fn spelling(line: &DocLine, is_tech: &dyn Fn(&str) -> bool, findings: &mut Vec<ProofreadFinding>) {
+        let dictionary = &*ENGLISH;
+        let content_start = line.text.find(line.content()).unwrap_or(0);
+        let mut previous: Option<(String, usize)> = None;
+
+        for segment in SEGMENT.find_iter(&line.text) {
+            let text = segment.as_str();
+            let has_digit = text.chars().any(|c| c.is_ascii_digit());
+            let has_letter = text.chars().any(|c| c.is_ascii_alphabetic());
+            let dotted = text
+                .char_indices()
+                .any(|(i, c)| c == '.' && text[i + 1..].starts_with(|n: char| n.is_ascii_alphanumeric()));
+            // 网址、邮箱、路径和 K8s、Node.js 之类的写法
+            if text.contains(['@', '/', '\\', '_', '#']) || dotted || (has_digit && has_letter) {
+                previous = None;
+                continue;
+            }
+
+            for m in WORD.find_iter(text) {
+                let start = segment.start() + m.start();
+                let end = segment.start() + m.end();
+                let word = m.as_str().trim_end_matches("'s");
+                let lower = word.to_lowercase();
+
+                // 重复的单词，如 “the the”
+                if let Some((prev, prev_end)) = &previous {
+                    if *prev == lower && line.text[*prev_end..start].trim().is_empty() && lower != "that" {
+                        findings.push(finding(
+                            ProofreadKind::Spelling,
+                            FindingSeverity::Warning,
+                            format!("单词「{}」重复，删除其中一个", word),
+                            line.slice_span(start, end),
+                            None,
+                        ));
+                    }
+                }
+                previous = Some((lower.clone(), end));
+
+                if word.len() < 3 || word.contains('\'') || is_tech(&lower) || dictionary.contains(&lower) {
+                    continue;
+                }
+                let capitalized = word.starts_with(|c: char| c.is_ascii_uppercase());
+                if word.chars().skip(1).any(|c| c.is_ascii_uppercase()) {
+                    continue;
+                }
+                // 句中首字母大写的多为公司、人名等专有名词
+                let before = line.text[..start].trim_end();
+                let sentence_start = before.len() <= content_start || before.ends_with(['.', '!', '?', '。', '！', '？']);
+                if capitalized && !sentence_start {
+                    continue;
+                }
+
+                let known = COMMON_MISSPELLINGS.iter().find(|(wrong, _)| *wrong == lower).map(|(_, right)| *right);
+                let (severity, suggestion) = match known {
+                    Some(right) => (FindingSeverity::Warning, right.to_string()),
+                    None if lower.len() >= MIN_SUGGEST_LENGTH => match dictionary.suggest(&lower) {
+                        Some(candidate) => (FindingSeverity::Info, candidate),
+                        None => continue,
+                    },
+                    None => continue,
+                };
+                let suggestion = if capitalized { capitalize(&suggestion) } else { suggestion };
+                findings.push(finding(
+                    ProofreadKind::Spelling,
+                    severity,
+                    format!("「{}」可能拼写错误，应为「{}」", word, suggestion),
+                    line.slice_span(start, start + word.len()),
+                    Some(suggestion),
+                ));
+            }
+        }
+    }
+
+    // ========== 中文错别字 ==========
+
+    fn confusables(line: &DocLine, findings: &mut Vec<ProofreadFinding>) {
+        for (wrong, right) in CONFUSABLES.iter() {
+            for (start, _) in line.text.match_indices(wrong.as_str()) {
+                findings.push(finding(
+                    ProofreadKind::Confusable,
+                    FindingSeverity::Warning,
+                    format!("「{}」应为「{}」", wrong, right),
+                    line.slice_span(start, start + wrong.len()),
+                    Some(right.clone()),
+                ));
+            }
+        }
+
+        let particles = [
+            (&*DE_ADVERBIAL, "地", "修饰动词时应用“地”"),
+            (&*DE_COMPLEMENT, "得", "动词后接程度补语时应用“得”"),
+        ];
+        for (pattern, particle, reason) in particles {
+            for caps in pattern.captures_iter(&line.text) {
+                let whole = caps.get(0).unwrap();
+                let suggestion = format!("{}{}{}", &caps[1], particle, &caps[2]);
+                findings.push(finding(
+                    ProofreadKind::Confusable,
+                    FindingSeverity::Info,
+                    format!("{}，「{}」应为「{}」", reason, whole.as_str(), suggestion),
+                    line.slice_span(whole.start(), whole.end()),
+                    Some(suggestion),
+                ));
+            }
+        }
+    }
+
+    // ========== 标点 ==========
+
+    fn punctuation(line: &DocLine, findings: &mut Vec<ProofreadFinding>) {
+        let text = &line.text;
+        for mismatch in width_mismatches(text) {
+            findings.push(finding(
+                ProofreadKind::Punctuation,
+                FindingSeverity::Info,
+                mismatch.message,
+                line.slice_span(mismatch.start, mismatch.end),
+                Some(mismatch.replacement),
+            ));
+        }
+
+        for m in REPEATED_PUNCTUATION.find_iter(text) {
+            let first = m.as_str().chars().next().unwrap_or_default();
+            findings.push(finding(
+                ProofreadKind::Punctuation,
+                FindingSeverity::Info,
+                format!("标点「{}」重复", m.as_str()),
+                line.slice_span(m.start(), m.end()),
+                Some(first.to_string()),
+            ));
+        }
+    }
+
+    // ========== 英文时态 ==========
+
+    /// 已结束的经历应使用过去式；进行中的经历时态应一致
+    fn tense(doc: &ResumeDocument, findings: &mut Vec<ProofreadFinding>) {
+        let sections = doc
+            .sections
+            .iter()
+            .filter(|s| matches!(s.kind, SectionKind::Experience | SectionKind::Projects));
+        for section in sections {
+            let mut ended = None;
+            let mut verbs: Vec<LeadingVerb> = Vec::new();
+            for line in &section.lines {
+                // 带时间段的行是一段新经历的标题
+                if let Some(range) = DATE_RANGE.find(&line.text) {
+                    Self::tense_findings(ended, &verbs, findings);
+                    ended = Some(!ONGOING.is_match(range.as_str()));
+                    verbs.clear();
+                    continue;
+                }
+                if let Some(verb) = LeadingVerb::parse(line) {
+                    verbs.push(verb);
+                }
+            }
+            Self::tense_findings(ended, &verbs, findings);
+        }
+    }
+
+    fn tense_findings(ended: Option<bool>, verbs: &[LeadingVerb], findings: &mut Vec<ProofreadFinding>) {
+        if ended == Some(true) {
+            for verb in verbs.iter().filter(|v| !v.past) {
+                let past = capitalize(&past_tense(&verb.base));
+                findings.push(finding(
+                    ProofreadKind::Tense,
+                    FindingSeverity::Warning,
+                    format!("已结束的经历建议使用过去式，「{}」可改为「{}」", verb.span.text, past),
+                    verb.span.clone(),
+                    Some(past),
+                ));
+            }
+            return;
+        }
+
+        // 进行中或无时间的经历，标出与多数要点时态不同的要点
+        let past = verbs.iter().filter(|v| v.past).count();
+        let present = verbs.len() - past;
+        if past == 0 || present == 0 || past == present {
+            return;
+        }
+        let majority_past = past > present;
+        for verb in verbs.iter().filter(|v| v.past != majority_past) {
+            let (tense, suggestion) = if majority_past {
+                ("过去式", past_tense(&verb.base))
+            } else {
+                ("现在时", verb.base.clone())
+            };
+            let suggestion = capitalize(&suggestion);
+            findings.push(finding(
+                ProofreadKind::Tense,
+                FindingSeverity::Info,
+                format!("同一段经历中时态不一致，建议统一为{}，「{}」可改为「{}」", tense, verb.span.text, suggestion),
+                verb.span.clone(),
+                Some(suggestion),
+            ));
+        }
+    }
+}
+
+// ========== 英文词典 ==========
+
+struct EnglishDictionary {
+    /// 词典中收录的原形，拼写候选只从这里选
+    base: HashSet<String>,
+    /// 原形及推导出的变形
+    words: HashSet<String>,
+    verbs: HashSet<String>,
+}
+
+impl EnglishDictionary {
+    fn new(words: &str, verbs: &str) -> Self {
+        fn lines(content: &str) -> impl Iterator<Item = String> + '_ {
+            content
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty() && !l.starts_with('#'))
+                .map(str::to_lowercase)
+        }
+        let verbs: HashSet<String> = lines(verbs).collect();
+        let mut base: HashSet<String> = lines(words).chain(verbs.iter().cloned()).collect();
+        base.extend(IRREGULAR_PAST.iter().map(|(_, past)| past.to_string()));
+
+        let mut forms: HashSet<String> = base
+            .iter()
+            .flat_map(|w| inflections(w, verbs.contains(w)))
+            .collect();
+        // 英式拼写，如 optimise、analyse
+        let british: Vec<String> = forms
+            .iter()
+            .filter(|w| w.contains("iz") || w.contains("yz"))
+            .map(|w| w.replace("iz", "is").replace("yz", "ys"))
+            .collect();
+        forms.extend(british);
+
+        Self { base, words: forms, verbs }
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        self.words.contains(word)
+            || PREFIXES
+                .iter()
+                .any(|p| word.strip_prefix(p).is_some_and(|rest| rest.len() >= 4 && self.words.contains(rest)))
+    }
+
+    /// 编辑距离为 1 且首字母相同的词；推导出的变形可能不是真实的词，不作为候选
+    fn suggest(&self, word: &str) -> Option<String> {
+        let first = word.chars().next()?;
+        self.base
+            .iter()
+            .filter(|w| w.starts_with(first) && w.len().abs_diff(word.len()) <= 1 && edit_distance(w, word) == 1)
+            .min()
+            .cloned()
+    }
+
+    /// 动词变形对应的原形及是否为过去式
+    fn verb_form(&self, word: &str) -> Option<(String, bool)> {
+        if let Some((base, _)) = IRREGULAR_PAST.iter().find(|(_, past)| *past == word) {
+            return Some((base.to_string(), true));
+        }
+        if self.verbs.contains(word) {
+            return Some((word.to_string(), false));
+        }
+
+        let candidates = |suffix: &str| -> Vec<String> {
+            let Some(stem) = word.strip_suffix(suffix) else {
+                return Vec::new();
+            };
+            let mut stems = vec![stem.to_string(), format!("{}e", stem)];
+            if let Some(y) = stem.strip_suffix('i') {
+                stems.push(format!("{}y", y));
+            }
+            let chars: Vec<char> = stem.chars().collect();
+            if chars.len() >= 2 && chars[chars.len() - 1] == chars[chars.len() - 2] {
+                stems.push(chars[..chars.len() - 1].iter().collect());
+            }
+            stems
+        };
+        let find = |suffix: &str| candidates(suffix).into_iter().find(|s| self.verbs.contains(s));
+
+        if let Some(base) = find("ed") {
+            return Some((base, true));
+        }
+        find("ing").or_else(|| find("es")).or_else(|| find("s")).map(|base| (base, false))
+    }
+}
+
+/// 词的规则变形：名词复数、动词的时态和施动者名词（manager）以及少数固定的派生规则；
+/// -ation、-ive 等派生词只对部分词成立，需在词典中单独收录
+fn inflections(base: &str, verb: bool) -> Vec<String> {
+    let chars: Vec<char> = base.chars().collect();
+    let n = chars.len();
+    let mut forms = vec![base.to_string()];
+    if n < 2 {
+        return forms;
+    }
+    let vowel = |c: char| "aeiou".contains(c);
+    let last = chars[n - 1];
+    let stem_e = base.strip_suffix('e').filter(|s| !s.ends_with('e'));
+    let stem_y = base.strip_suffix('y').filter(|s| s.chars().last().is_some_and(|c| !vowel(c)));
+
+    // 复数和第三人称单数
+    if let Some(stem) = stem_y {
+        forms.push(format!("{}ies", stem));
+    } else if ["s", "x", "z", "ch", "sh"].iter().any(|s| base.ends_with(s)) {
+        forms.push(format!("{}es", base));
+    } else {
+        forms.push(format!("{}s", base));
+    }
+
+    if verb {
+        let suffixes = ["ed", "ing", "er", "ers", "ment", "ments"];
+        match (stem_e, stem_y) {
+            (Some(stem), _) => forms.extend(suffixes.map(|s| match s {
+                "ing" | "ment" | "ments" => format!("{}{}", base, s).replace("eing", "ing"),
+                _ => format!("{}{}", stem, s),
+            })),
+            (None, Some(stem)) => {
+                forms.extend(["ied", "ier", "iers"].map(|s| format!("{}{}", stem, s)));
+                forms.extend(["ing", "ment", "ments"].map(|s| format!("{}{}", base, s)));
+            }
+            (None, None) => forms.extend(suffixes.map(|s| format!("{}{}", base, s))),
+        }
+        if let Some(stem) = base.strip_suffix("ie") {
+            forms.push(format!("{}ying", stem));
+        }
+        // 末尾辅音双写，如 planned、modelling
+        if n >= 3 && !vowel(last) && !"wxy".contains(last) && vowel(chars[n - 2]) && !vowel(chars[n - 3]) {
+            forms.extend(["ed", "ing", "er", "ers"].map(|s| format!("{}{}{}", base, last, s)));
+        }
+    } else {
+        match (stem_e, stem_y) {
+            (Some(stem), _) => forms.extend(["er", "est", "ly", "ness"].map(|s| match s {
+                "ly" | "ness" => format!("{}{}", base, s),
+                _ => format!("{}{}", stem, s),
+            })),
+            (None, Some(stem)) => forms.extend(["ier", "iest", "ily", "iness"].map(|s| format!("{}{}", stem, s))),
+            (None, None) => forms.extend(["er", "est", "ly", "ness"].map(|s| format!("{}{}", base, s))),
+        }
+    }
+
+    if let Some(stem) = base.strip_suffix("le") {
+        forms.push(format!("{}ly", stem));
+    }
+    if base.ends_with("ic") {
+        forms.push(format!("{}ally", base));
+    }
+    if let Some(stem) = base.strip_suffix("ify") {
+        forms.extend(["ification", "ifications"].map(|s| format!("{}{}", stem, s)));
+    }
+    if let Some(stem) = base.strip_suffix("ble") {
+        forms.extend(["bility", "bilities"].map(|s| format!("{}{}", stem, s)));
+    }
+    if let Some(stem) = base.strip_suffix("ize") {
+        forms.extend(["ization", "izations"].map(|s| format!("{}{}", stem, s)));
+    }
+    if let Some(stem) = base.strip_suffix("ate") {
+        forms.extend(["ation", "ations"].map(|s| format!("{}{}", stem, s)));
+    }
+    forms
+}
+
+/// 中文行中的半角标点和括号、英文行中的全角标点
+pub fn width_mismatches(text: &str) -> Vec<WidthMismatch> {
+    let mut mismatches = Vec::new();
+    if !text.chars().any(is_cjk) {
+        for (i, c) in text.char_indices() {
+            if let Some(half) = half_width(c) {
+                mismatches.push(WidthMismatch {
+                    start: i,
+                    end: i + c.len_utf8(),
+                    replacement: half.to_string(),
+                    message: format!("英文内容中应使用半角标点「{}」", half),
+                });
+            }
+        }
+        return mismatches;
+    }
+
+    for (i, c) in text.char_indices() {
+        let Some(full) = full_width(c) else {
+            continue;
+        };
+        let prev = text[..i].trim_end().chars().last();
+        let next = text[i + c.len_utf8()..].trim_start().chars().next();
+        let between_digits = prev.is_some_and(|p| p.is_ascii_digit()) && next.is_some_and(|n| n.is_ascii_digit());
+        if (prev.is_some_and(is_cjk) || next.is_some_and(is_cjk)) && !between_digits {
+            mismatches.push(WidthMismatch {
+                start: i,
+                end: i + c.len_utf8(),
+                replacement: full.to_string(),
+                message: format!("中文内容中应使用全角标点「{}」", full),
+            });
+        }
+    }
+    for caps in HALF_WIDTH_PARENS.captures_iter(text) {
+        if caps[1].chars().any(is_cjk) {
+            let whole = caps.get(0).unwrap();
+            mismatches.push(WidthMismatch {
+                start: whole.start(),
+                end: whole.end(),
+                replacement: format!("（{}）", &caps[1]),
+                message: "中文内容中应使用全角括号".to_string(),
+            });
+        }
+    }
+    mismatches.sort_by_key(|m| m.start);
+    mismatches
+}
+
+/// 是否为词典中的英文单词（含推导出的变形）
+pub fn is_english_word(word: &str) -> bool {
+    ENGLISH.words.contains(word)
+}
+
+/// 规则动词和常见不规则动词的过去式
+fn past_tense(base: &str) -> String {
+    if let Some((_, past)) = IRREGULAR_PAST.iter().find(|(b, _)| *b == base) {
+        return past.to_string();
+    }
+    let chars: Vec<char> = base.chars().collect();
+    let n = chars.len();
+    let vowel = |c: char| "aeiou".contains(c);
+    if base.ends_with('e') {
+        return format!("{}d", base);
+    }
+    if n >= 2 && chars[n - 1] == 'y' && !vowel(chars[n - 2]) {
+        return format!("{}ied", &base[..base.len() - 1]);
+    }
+    let short_cvc = (3..=4).contains(&n)
+        && !vowel(chars[n - 1])
+        && !"wxy".contains(chars[n - 1])
+        && vowel(chars[n - 2])
+        && !vowel(chars[n - 3]);
+    if short_cvc || DOUBLED_CONSONANT.contains(&base) {
+        return format!("{}{}ed", base, chars[n - 1]);
+    }
+    format!("{}ed", base)
+}
+
+/// 带换位的编辑距离，超过 1 时提前返回
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j] + 1).min(rows[i][j - 1] + 1).min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+        if rows[i].iter().min().is_some_and(|m| *m > 1) {
+            return 2;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn full_width(c: char) -> Option<char> {
+    match c {
+        ',' => Some('，'),
+        ';' => Some('；'),
+        ':' => Some('：'),
+        '?' => Some('？'),
+        '!' => Some('！'),
+        _ => None,
+    }
+}
+
+fn half_width(c: char) -> Option<char> {
+    match c {
+        '，' => Some(','),
+        '。' => Some('.'),
+        '；' => Some(';'),
+        '：' => Some(':'),
+        '（' => Some('('),
+        '）' => Some(')'),
+        '！' => Some('!'),
+        '？' => Some('?'),
+        _ => None,
+    }
+}
+
+fn finding(
+    kind: ProofreadKind,
+    severity: FindingSeverity,
+    message: String,
+    span: EvidenceSpan,
+    suggestion: Option<String>,
+) -> ProofreadFinding {
+    ProofreadFinding {
+        kind,
+        severity,
+        message,
+        span,
+        suggestion,
+    }
+}
+
+// ========== 内部结果结构 ==========
+
+/// 与所在行语言不符的标点：字节起止和应替换为的内容
+pub struct WidthMismatch {
+    pub start: usize,
+    pub end: usize,
+    pub replacement: String,
+    pub message: String,
+}
+
+/// 英文要点开头的动词
+struct LeadingVerb {
+    base: String,
+    past: bool,
+    span: EvidenceSpan,
+}
+
+impl LeadingVerb {
+    fn parse(line: &DocLine) -> Option<Self> {
+        let content = line.content();
+        let offset = line.text.find(content)?;
+        let word = LEADING_WORD.find(content)?;
+        let (base, past) = ENGLISH.verb_form(&word.as_str().to_lowercase())?;
+        Some(Self {
+            base,
+            past,
+            span: line.slice_span(offset + word.start(), offset + word.end()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLEAN_RESUME: &str = "\
+Jane Doe
+jane.doe@example.com | +1 415 555 0100 | linkedin.com/in/janedoe
+
+## Summary
+Finance operations analyst with seven years of experience in accounting, reporting and process automation.
+
+## Experience
+Senior Financial Analyst, Acme Corporation | Mar 2020 - Present
+- Owned the monthly reconciliation of the general ledger across four business units.
+- Automated recurring journal entries, reducing the close cycle from eight days to five.
+- Partnered with engineering teams to deliver reporting deliverables on schedule.
+- Built forecasting models that improved budget accuracy by 12% for regional managers.
+Financial Analyst, Globex Inc. | Jul 2016 - Feb 2020
+- Prepared quarterly statements and variance analysis for senior leadership.
+- Implemented an invoice approval workflow and trained new team members.
+- Coordinated external audits and resolved discrepancies with vendors.
+
+## Education
+Bachelor of Science in Accounting, State University | 2012 - 2016
+
+## Skills
+Excel, SQL, Python, financial modeling, stakeholder communication
+";
+
+    #[test]
+    fn clean_english_resume_has_no_findings() {
+        let findings = Proofreader::check(&ResumeDocument::parse(CLEAN_RESUME));
+        assert!(findings.is_empty(), "unexpected findings: {:#?}", findings);
+    }
+
+    #[test]
+    fn generated_forms_are_not_suggested() {
+        let dictionary = &*ENGLISH;
+        for word in ["reconciliation", "ledger", "deliverables"] {
+            assert!(dictionary.contains(word), "{} should be accepted", word);
+        }
+        assert!(!dictionary.contains("reconcilation"));
+        assert_eq!(dictionary.suggest("reconcilation").as_deref(), Some("reconciliation"));
+    }
+}
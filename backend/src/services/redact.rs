@@ -0,0 +1,360 @@
+use std::sync::LazyLock;
+
+use regex::{Captures, Regex};
+
+use crate::models::*;
+use crate::services::completeness::{CN_LANDLINE, CN_MOBILE, EMAIL_CANDIDATE, INTERNATIONAL, PHONE_CANDIDATE};
+use crate::services::timeline::DATE_RANGE;
+use crate::services::ResumeDocument;
+
+/// 身份证号前 17 位的加权因子和对应的校验位
+const ID_CARD_WEIGHTS: [u32; 17] = [7, 9, 10, 5, 8, 4, 2, 1, 6, 3, 7, 9, 10, 5, 8, 4, 2];
+const ID_CARD_CHECK_DIGITS: &[u8; 11] = b"10X98765432";
+
+/// 地址标签后的内容遇到这些联系方式标签即截止
+const CONTACT_LABELS: &[&str] = &["电话", "手机", "邮箱", "微信", "Email", "E-mail", "Phone", "Mobile", "Tel"];
+
+/// 同一位置命中多种类型时，靠前的优先
+const PRIORITY: [PiiKind; 8] = [
+    PiiKind::Photo,
+    PiiKind::Email,
+    PiiKind::IdCard,
+    PiiKind::Link,
+    PiiKind::Phone,
+    PiiKind::Birthdate,
+    PiiKind::Address,
+    PiiKind::Name,
+];
+
+/// 日期：2000年1月1日、2000.01.01、2000-01、Jan 1, 2000、1 Jan 2000、01/01/2000
+const DATE: &str = r"\d{4}\s*(?:年|[./-])\s*\d{1,2}(?:\s*(?:月|[./-])\s*\d{1,2}\s*日?|\s*月)?|(?:Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec)[a-z]*\.?\s+\d{1,2},?\s+\d{4}|\d{1,2}\s+(?:Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec)[a-z]*\.?\s+\d{4}|\d{1,2}/\d{1,2}/\d{4}";
+
+static ID_CARD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[0-9]{17}[0-9Xx]").unwrap());
+
+static LINK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)(?:https?://|www\.)[^\s,，、。;；|｜()（）<>「」【】\[\]]+|(?:[a-z0-9-]+\.)?(?:github|gitlab|gitee|linkedin|zhihu|juejin|csdn|weibo|twitter|medium|behance|dribbble)\.(?:com|net|cn|io)/[^\s,，、。;；|｜()（）<>「」【】\[\]]+|[a-z0-9-]+\.github\.io[^\s,，、。;；|｜()（）<>「」【】\[\]]*",
+    )
+    .unwrap()
+});
+
+/// Markdown / HTML 图片和图片文件名
+static PHOTO: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?i)!\[[^\]]*\]\([^)]*\)|<img\b[^>]*>|[^\s|｜,，;；:：()（）\[\]<>"']+\.(?:jpe?g|png|gif|bmp|webp|heic)\b"#,
+    )
+    .unwrap()
+});
+
+/// “照片：”后的内容
+static PHOTO_LABELED: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?:照片|头像|证件照|photo|headshot|avatar)\s*[:：]\s*([^\s|｜,，;；]+)").unwrap()
+});
+
+/// 带标签的出生日期和年龄，只替换标签后的值
+static BIRTHDATE_LABELED: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)(?:出生日期|出生年月日?|生日|date\s+of\s+birth|birth\s*date|birthday|d\.o\.b\.?|dob|born(?:\s+on)?)\s*[:：]?\s*({DATE})|(?:年龄|age)\s*[:：]\s*(\d{{1,2}}\s*岁?)"
+    ))
+    .unwrap()
+});
+
+/// “1990年1月出生”
+static BIRTHDATE_SUFFIX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"({DATE})\s*出生")).unwrap());
+
+/// 页眉中不带标签的年龄，如 “男 | 28岁”
+static AGE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\d{2}\s*岁").unwrap());
+
+static ADDRESS_LABELED: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?:家庭住址|通讯地址|联系地址|居住地址|现住址|住址|地址|现居住地|现居地|居住地|address)\s*[:：]\s*([^|｜;；\t]+)")
+        .unwrap()
+});
+
+/// 精确到门牌号的中文地址，或英文街道地址
+static ADDRESS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?:\p{Han}{2,7}(?:省|自治区))?\p{Han}{2,7}市(?:\p{Han}{1,7}(?:区|县))?[\p{Han}\d]{0,15}?(?:路|街|大道|巷|弄|胡同)[\d\-]+号(?:[\p{Han}\d\-]{0,10}(?:室|楼|栋|单元|层))?|\b\d{1,5}\s+(?:[A-Z][a-z]+\s+){1,3}(?:Street|St|Avenue|Ave|Road|Rd|Boulevard|Blvd|Lane|Ln|Drive|Dr|Way|Court|Ct)\b\.?",
+    )
+    .unwrap()
+});
+
+/// “姓名：张三”“Name: Jane Doe”；标签须在行首或分隔符之后，
+/// 避免 “Project Name:”“Username:” 之类的字段被当作姓名
+static NAME_LABELED: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?:^|[|｜,，;；\t])\s*(?:[-*•·]\s*)?(?:姓\s*名|(?i:(?:full\s+)?name))\s*[:：]\s*([^\s|｜,，;；]+(?: [A-Z][a-z]+){0,2})",
+    )
+    .unwrap()
+});
+
+/// 还原时识别替换标记，容忍全角括号和多余空格
+static TOKEN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)[\[【]\s*((?:NAME|PHONE|EMAIL|ID_CARD|ADDRESS|BIRTHDATE|PHOTO|LINK)_\d+)\s*[\]】]").unwrap()
+});
+
+/// 命中的个人信息在全文中的字节位置
+struct Span {
+    start: usize,
+    end: usize,
+    kind: PiiKind,
+}
+
+/// 个人信息脱敏：替换为 [PHONE_1] 等标记，并保留映射以便在本地还原
+pub struct Redactor {
+    kinds: Vec<PiiKind>,
+    mapping: Vec<RedactionEntry>,
+}
+
+impl Redactor {
+    /// kinds 为空时全部脱敏
+    pub fn new(kinds: &[PiiKind]) -> Self {
+        Self {
+            kinds: if kinds.is_empty() { PiiKind::ALL.to_vec() } else { kinds.to_vec() },
+            mapping: Vec::new(),
+        }
+    }
+
+    /// 脱敏整份简历：除各类规则外，页眉中的姓名和年龄也会识别
+    pub fn redact_resume(&mut self, text: &str) -> String {
+        self.redact(text, true)
+    }
+
+    /// 脱敏简历片段（如单条要点）：不按页眉识别，已识别的姓名仍会替换
+    pub fn redact_fragment(&mut self, text: &str) -> String {
+        self.redact(text, false)
+    }
+
+    pub fn mapping(&self) -> &[RedactionEntry] {
+        &self.mapping
+    }
+
+    pub fn into_mapping(self) -> Vec<RedactionEntry> {
+        self.mapping
+    }
+
+    /// 把标记替换回原文，返回还原后的文本和映射中没有的标记
+    pub fn restore(text: &str, mapping: &[RedactionEntry]) -> (String, Vec<String>) {
+        let mut unresolved: Vec<String> = Vec::new();
+        let restored = TOKEN.replace_all(text, |caps: &Captures| {
+            let token = format!("[{}]", caps[1].to_uppercase());
+            match mapping.iter().find(|e| e.token == token) {
+                Some(entry) => entry.value.clone(),
+                None => {
+                    if !unresolved.contains(&token) {
+                        unresolved.push(token);
+                    }
+                    caps[0].to_string()
+                }
+            }
+        });
+        (restored.into_owned(), unresolved)
+    }
+
+    // ========== 辅助方法 ==========
+
+    fn redact(&mut self, text: &str, whole_resume: bool) -> String {
+        let mut names: Vec<String> = self
+            .mapping
+            .iter()
+            .filter(|e| e.kind == PiiKind::Name)
+            .map(|e| e.value.clone())
+            .collect();
+        // 页眉为第一个小节标题之前的行
+        let mut header_lines = 0;
+        if whole_resume {
+            let doc = ResumeDocument::parse(text);
+            names.extend(doc.candidate_name());
+            header_lines = doc
+                .sections
+                .iter()
+                .find_map(|s| s.heading.as_ref())
+                .map(|h| h.number - 1)
+                .unwrap_or(usize::MAX);
+        }
+
+        let mut spans = Vec::new();
+        let mut offset = 0;
+        for (i, line) in text.split('\n').enumerate() {
+            Self::detect_line(line, offset, i < header_lines, &mut spans, &mut names);
+            offset += line.len() + 1;
+        }
+        names.sort();
+        names.dedup();
+        let mut offset = 0;
+        for line in text.split('\n') {
+            for name in &names {
+                spans.extend(line.match_indices(name.as_str()).map(|(start, _)| Span {
+                    start: offset + start,
+                    end: offset + start + name.len(),
+                    kind: PiiKind::Name,
+                }));
+            }
+            offset += line.len() + 1;
+        }
+
+        // 按优先级和长度取互不重叠的片段
+        spans.retain(|s| self.kinds.contains(&s.kind) && s.start < s.end);
+        spans.sort_by_key(|s| {
+            let priority = PRIORITY.iter().position(|k| *k == s.kind).unwrap_or(PRIORITY.len());
+            (priority, s.start, std::cmp::Reverse(s.end - s.start))
+        });
+        let mut accepted: Vec<Span> = Vec::new();
+        for span in spans {
+            if accepted.iter().all(|a| span.end <= a.start || span.start >= a.end) {
+                accepted.push(span);
+            }
+        }
+        accepted.sort_by_key(|s| s.start);
+
+        let mut redacted = String::with_capacity(text.len());
+        let mut last = 0;
+        for span in &accepted {
+            redacted.push_str(&text[last..span.start]);
+            let token = self.token_for(span.kind, &text[span.start..span.end]);
+            redacted.push_str(&token);
+            last = span.end;
+        }
+        redacted.push_str(&text[last..]);
+        redacted
+    }
+
+    fn detect_line(line: &str, offset: usize, in_header: bool, spans: &mut Vec<Span>, names: &mut Vec<String>) {
+        let mut push = |start: usize, end: usize, kind: PiiKind| {
+            spans.push(Span {
+                start: offset + start,
+                end: offset + end,
+                kind,
+            })
+        };
+
+        for m in PHOTO.find_iter(line) {
+            push(m.start(), m.end(), PiiKind::Photo);
+        }
+        for caps in PHOTO_LABELED.captures_iter(line) {
+            let value = caps.get(1).expect("photo group");
+            push(value.start(), value.end(), PiiKind::Photo);
+        }
+        for m in EMAIL_CANDIDATE.find_iter(line) {
+            let value = m.as_str().trim_end_matches(['.', '。']);
+            if value.rsplit(['@', '＠']).next().is_some_and(|domain| domain.contains('.')) {
+                push(m.start(), m.start() + value.len(), PiiKind::Email);
+            }
+        }
+        for m in ID_CARD.find_iter(line) {
+            let before = line[..m.start()].chars().next_back();
+            let after = line[m.end()..].chars().next();
+            let bounded = !before.is_some_and(|c| c.is_ascii_alphanumeric())
+                && !after.is_some_and(|c| c.is_ascii_alphanumeric());
+            if bounded && is_valid_id_card(m.as_str()) {
+                push(m.start(), m.end(), PiiKind::IdCard);
+            }
+        }
+        for m in LINK.find_iter(line) {
+            let value = m.as_str().trim_end_matches(['.', '。']);
+            push(m.start(), m.start() + value.len(), PiiKind::Link);
+        }
+        for m in PHONE_CANDIDATE.find_iter(line).filter(|m| !DATE_RANGE.is_match(m.as_str())) {
+            let value = m.as_str().trim_end();
+            let digits: String = value
+                .chars()
+                .filter(|c| c.is_ascii_digit() || *c == '+' || *c == '＋')
+                .map(|c| if c == '＋' { '+' } else { c })
+                .collect();
+            if CN_MOBILE.is_match(&digits) || CN_LANDLINE.is_match(&digits) || INTERNATIONAL.is_match(&digits) {
+                push(m.start(), m.start() + value.len(), PiiKind::Phone);
+            }
+        }
+
+        for caps in BIRTHDATE_LABELED.captures_iter(line) {
+            if let Some(value) = caps.get(1).or_else(|| caps.get(2)) {
+                push(value.start(), value.start() + value.as_str().trim_end().len(), PiiKind::Birthdate);
+            }
+        }
+        for caps in BIRTHDATE_SUFFIX.captures_iter(line) {
+            let value = caps.get(1).expect("date group");
+            push(value.start(), value.end(), PiiKind::Birthdate);
+        }
+        if in_header {
+            for m in AGE.find_iter(line) {
+                push(m.start(), m.end(), PiiKind::Birthdate);
+            }
+        }
+
+        for caps in ADDRESS_LABELED.captures_iter(line) {
+            let value = caps.get(1).expect("address group");
+            let mut end = value.as_str().find("  ").unwrap_or(value.len());
+            for label in CONTACT_LABELS {
+                if let Some(position) = value.as_str()[..end].find(label) {
+                    end = position;
+                }
+            }
+            let trimmed = value.as_str()[..end].trim_end_matches(|c: char| c.is_whitespace() || ",，".contains(c));
+            push(value.start(), value.start() + trimmed.len(), PiiKind::Address);
+        }
+        for m in ADDRESS.find_iter(line) {
+            push(m.start(), m.end(), PiiKind::Address);
+        }
+
+        for caps in NAME_LABELED.captures_iter(line) {
+            let value = caps.get(1).expect("name group");
+            push(value.start(), value.end(), PiiKind::Name);
+            names.push(value.as_str().to_string());
+        }
+    }
+
+    /// 同一类型的同一原文使用同一标记
+    fn token_for(&mut self, kind: PiiKind, value: &str) -> String {
+        if let Some(entry) = self.mapping.iter().find(|e| e.kind == kind && e.value == value) {
+            return entry.token.clone();
+        }
+        let index = self.mapping.iter().filter(|e| e.kind == kind).count() + 1;
+        let token = format!("[{}_{}]", kind.token_prefix(), index);
+        self.mapping.push(RedactionEntry {
+            token: token.clone(),
+            kind,
+            value: value.to_string(),
+        });
+        token
+    }
+}
+
+/// 18 位身份证号：出生日期合理且校验位正确
+fn is_valid_id_card(id: &str) -> bool {
+    let bytes = id.as_bytes();
+    if bytes.len() != 18 || !bytes[..17].iter().all(u8::is_ascii_digit) {
+        return false;
+    }
+    let month: u32 = id[10..12].parse().unwrap_or(0);
+    let day: u32 = id[12..14].parse().unwrap_or(0);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return false;
+    }
+    let sum: u32 = bytes[..17]
+        .iter()
+        .zip(ID_CARD_WEIGHTS)
+        .map(|(b, w)| (b - b'0') as u32 * w)
+        .sum();
+    ID_CARD_CHECK_DIGITS[(sum % 11) as usize] == bytes[17].to_ascii_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_width_digits_are_not_an_id_card() {
+        let text = "身份证：１１０１０１１９９００３０７１２３４";
+        let mut redactor = Redactor::new(&[PiiKind::IdCard]);
+        assert_eq!(redactor.redact_resume(text), text);
+        assert!(!is_valid_id_card("１１０１０１１９９００３０７１２３４"));
+    }
+
+    #[test]
+    fn valid_id_card_is_redacted() {
+        let mut redactor = Redactor::new(&[PiiKind::IdCard]);
+        let redacted = redactor.redact_resume("身份证：11010519491231002X");
+        assert!(!redacted.contains("11010519491231002X"), "{}", redacted);
+        assert_eq!(redactor.mapping().len(), 1);
+    }
+}
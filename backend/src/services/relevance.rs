@@ -0,0 +1,206 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, LazyLock, RwLock};
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use sqlx::PgPool;
+
+use crate::models::*;
+use crate::services::{SkillTaxonomy, Tokenizer};
+
+/// 内置 JD 语料
+const BUNDLED_CORPUS: &str = include_str!("../../data/jd_corpus.json");
+
+/// BM25 中 JD 词频的饱和参数
+const K1: f64 = 1.2;
+
+/// 简历词频的饱和参数：出现 1 次约得 2/3，多次出现逐渐趋近满分
+const RESUME_K: f64 = 0.5;
+
+/// 返回的贡献词和缺失词数量
+const TOP_TERMS: usize = 10;
+
+/// 数据库语料的缓存时间
+const CORPUS_TTL: Duration = Duration::from_secs(600);
+
+/// 参与统计的已保存 JD 数量上限
+const MAX_STORED_DOCUMENTS: i64 = 5000;
+
+static BUNDLED: LazyLock<Corpus> = LazyLock::new(|| {
+    let file: CorpusFile = serde_json::from_str(BUNDLED_CORPUS).expect("invalid bundled JD corpus");
+    Corpus::from_documents(file.documents.iter().map(String::as_str))
+});
+
+/// 加载时间和语料
+type CachedCorpus = Option<(Instant, Arc<Corpus>)>;
+
+static CACHE: LazyLock<RwLock<CachedCorpus>> = LazyLock::new(|| RwLock::new(None));
+
+#[derive(Deserialize)]
+struct CorpusFile {
+    documents: Vec<String>,
+}
+
+/// 词项文档频率统计
+#[derive(Debug, Clone, Default)]
+pub struct Corpus {
+    doc_count: usize,
+    doc_freq: HashMap<String, usize>,
+}
+
+impl Corpus {
+    pub fn from_documents<'a>(documents: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut corpus = Self::default();
+        for document in documents {
+            corpus.add_document(document);
+        }
+        corpus
+    }
+
+    /// 内置语料
+    pub fn bundled() -> &'static Corpus {
+        &BUNDLED
+    }
+
+    pub fn add_document(&mut self, text: &str) {
+        self.doc_count += 1;
+        for term in term_frequencies(text).into_keys() {
+            *self.doc_freq.entry(term).or_insert(0) += 1;
+        }
+    }
+
+    pub fn document_count(&self) -> usize {
+        self.doc_count
+    }
+
+    /// BM25 形式的逆文档频率，未出现过的词权重最高
+    pub fn idf(&self, term: &str) -> f64 {
+        let n = self.doc_count as f64;
+        let df = self.doc_freq.get(term).copied().unwrap_or(0) as f64;
+        (1.0 + (n - df + 0.5) / (df + 0.5)).ln()
+    }
+}
+
+/// 基于 BM25 权重的简历-JD 相关度
+pub struct RelevanceScorer;
+
+impl RelevanceScorer {
+    /// JD 词项按 BM25 的 IDF 和词频饱和度加权，简历中出现的词项按词频饱和度计分
+    pub fn score(resume: &str, jd: &str, corpus: &Corpus) -> RelevanceReport {
+        let jd_terms = term_frequencies(jd);
+        let resume_terms = term_frequencies(resume);
+        let resume_lower = resume.to_lowercase();
+
+        let weights: Vec<(String, f64)> = jd_terms
+            .into_iter()
+            .map(|(term, qf)| {
+                let qf = qf as f64;
+                let weight = corpus.idf(&term) * qf * (K1 + 1.0) / (qf + K1);
+                (term, weight)
+            })
+            .collect();
+        let total_weight: f64 = weights.iter().map(|(_, w)| w).sum();
+
+        if total_weight <= 0.0 {
+            return RelevanceReport {
+                score: 0.0,
+                top_terms: Vec::new(),
+                missing_terms: Vec::new(),
+                corpus_size: corpus.document_count(),
+            };
+        }
+
+        let mut matched = Vec::new();
+        let mut missing = Vec::new();
+        for (term, weight) in weights {
+            // 中文词在简历中可能被切分成不同的词，按子串兜底
+            let tf = match resume_terms.get(&term) {
+                Some(tf) => *tf as f64,
+                None if !term.is_ascii() && resume_lower.contains(&term) => 1.0,
+                None => 0.0,
+            };
+
+            let share = weight / total_weight;
+            if tf > 0.0 {
+                let saturation = tf / (tf + RESUME_K);
+                matched.push(RelevanceTerm {
+                    term,
+                    weight: share as f32,
+                    contribution: (share * saturation * 100.0) as f32,
+                });
+            } else {
+                missing.push(RelevanceTerm {
+                    term,
+                    weight: share as f32,
+                    contribution: 0.0,
+                });
+            }
+        }
+
+        let score: f32 = matched.iter().map(|t| t.contribution).sum();
+
+        matched.sort_by(|a, b| b.contribution.total_cmp(&a.contribution));
+        missing.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+        matched.truncate(TOP_TERMS);
+        missing.truncate(TOP_TERMS);
+
+        RelevanceReport {
+            score: (score * 10.0).round() / 10.0,
+            top_terms: matched,
+            missing_terms: missing,
+            corpus_size: corpus.document_count(),
+        }
+    }
+}
+
+/// 分词后的关键词及出现次数；技能别名统一为标准名称，如 golang -> go
+fn term_frequencies(text: &str) -> HashMap<String, usize> {
+    let tokenizer = Tokenizer::global();
+    let taxonomy = SkillTaxonomy::global();
+    let mut counts = HashMap::new();
+    for token in tokenizer.tokenize(text) {
+        if tokenizer.is_keyword(&token) {
+            let term = match taxonomy.lookup(&token.text) {
+                Some(skill) => skill.name.to_lowercase(),
+                None => token.normalized(),
+            };
+            *counts.entry(term).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+// ========== 语料持久化 ==========
+
+/// 内置语料加上已保存的 JD，按固定时间缓存
+pub async fn load_corpus(pool: &PgPool) -> Result<Arc<Corpus>, sqlx::Error> {
+    if let Some((loaded_at, corpus)) = CACHE.read().unwrap().as_ref() {
+        if loaded_at.elapsed() < CORPUS_TTL {
+            return Ok(corpus.clone());
+        }
+    }
+
+    let documents: Vec<String> = sqlx::query_scalar(
+        "SELECT raw_text FROM job_descriptions ORDER BY created_at DESC LIMIT $1",
+    )
+    .bind(MAX_STORED_DOCUMENTS)
+    .fetch_all(pool)
+    .await?;
+
+    // 同一份 JD 可能被多个用户保存，只计一次
+    let mut seen = HashSet::new();
+    let mut corpus = Corpus::bundled().clone();
+    for document in documents.iter().filter(|d| seen.insert(d.trim())) {
+        corpus.add_document(document);
+    }
+
+    let corpus = Arc::new(corpus);
+    *CACHE.write().unwrap() = Some((Instant::now(), corpus.clone()));
+
+    Ok(corpus)
+}
+
+/// JD 增删后丢弃缓存，下次请求重新统计
+pub fn invalidate_corpus() {
+    *CACHE.write().unwrap() = None;
+}
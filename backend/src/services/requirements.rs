@@ -0,0 +1,331 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::models::*;
+use crate::services::document::{DocLine, SectionKind};
+use crate::services::{JdParser, ResumeDocument, SkillTaxonomy, TimelineAnalyzer};
+
+/// 出现这些词时视为加分项
+const PREFERRED_MARKERS: &[&str] = &[
+    "优先", "加分", "更佳", "者佳", "preferred", "nice to have", "a plus", "bonus",
+];
+
+/// 出现这些词时视为必备项，即使位于加分小节
+const MUST_MARKERS: &[&str] = &["必须", "必备", "硬性要求", "must", "required"];
+
+/// 必备项与加分项的基础权重
+const MUST_WEIGHT: f32 = 1.0;
+const PREFERRED_WEIGHT: f32 = 0.5;
+
+/// 年限上限，超过的多为年份（如 2019 年）
+const MAX_YEARS: u32 = 30;
+
+const EDUCATION_TERMS: &[(EducationLevel, &[&str])] = &[
+    (EducationLevel::Doctorate, &["博士", "phd", "ph.d", "doctorate", "doctoral"]),
+    (EducationLevel::Master, &["硕士", "研究生", "master", "master's", "msc", "mba"]),
+    (EducationLevel::Bachelor, &[
+        "本科", "学士", "bachelor", "bachelor's", "bsc", "undergraduate", "college degree",
+    ]),
+    (EducationLevel::Associate, &["大专", "专科", "associate degree"]),
+    (EducationLevel::HighSchool, &["高中", "中专", "high school"]),
+];
+
+/// 年限：3年以上、三年及以上、3-5年、5+ years、at least five years
+static YEARS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)(\d+|[一二两三四五六七八九十]+|one|two|three|four|five|six|seven|eight|nine|ten)\s*(?:(?:-|~|～|到|至)\s*(?:\d+|[一二两三四五六七八九十]+)\s*)?(?:\+|＋)?\s*(?:年|years?\b|yrs?\b)",
+    )
+    .unwrap()
+});
+
+/// 子句分隔符；英文逗号多用于列举，不作分隔
+static CLAUSE_SEPARATOR: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[，；;。！!]|\.\s").unwrap());
+
+/// JD 要求的提取与核对
+pub struct RequirementExtractor;
+
+impl RequirementExtractor {
+    /// 从必备和加分小节的行中逐句提取要求；句中的“优先”“必须”等词覆盖小节的默认分类
+    pub fn extract(required_lines: &[String], preferred_lines: &[String]) -> Vec<JdRequirement> {
+        let taxonomy = SkillTaxonomy::global();
+        let mut requirements: Vec<JdRequirement> = Vec::new();
+
+        let lines = required_lines
+            .iter()
+            .map(|l| (l, RequirementPriority::Must))
+            .chain(preferred_lines.iter().map(|l| (l, RequirementPriority::Preferred)));
+
+        for (line, default_priority) in lines {
+            for clause in CLAUSE_SEPARATOR.split(line).map(str::trim).filter(|c| !c.is_empty()) {
+                let lower = clause.to_lowercase();
+                let priority = if PREFERRED_MARKERS.iter().any(|m| lower.contains(m)) {
+                    RequirementPriority::Preferred
+                } else if MUST_MARKERS.iter().any(|m| JdParser::contains_term(&lower, m)) {
+                    RequirementPriority::Must
+                } else {
+                    default_priority
+                };
+                let base = match priority {
+                    RequirementPriority::Must => MUST_WEIGHT,
+                    RequirementPriority::Preferred => PREFERRED_WEIGHT,
+                };
+
+                let skills: Vec<&SkillDefinition> = taxonomy
+                    .extract(clause)
+                    .into_iter()
+                    .filter(|skill| taxonomy.is_technical(skill))
+                    .collect();
+                let years = Self::min_years(clause);
+
+                // 只提到一项技能时，年限视为该技能的使用年限，如 “5 年以上 Go 开发经验”
+                let skill_years = if skills.len() == 1 { years } else { None };
+                for skill in &skills {
+                    requirements.push(JdRequirement {
+                        kind: RequirementKind::Skill,
+                        priority,
+                        skill: Some(skill.name.clone()),
+                        min_years: skill_years,
+                        education: None,
+                        // 词典重要程度 0-100 映射为 0.5-1 倍
+                        weight: base * (0.5 + skill.importance as f32 / 200.0),
+                        source: line.clone(),
+                    });
+                }
+
+                if let Some(years) = years.filter(|_| skill_years.is_none()) {
+                    requirements.push(JdRequirement {
+                        kind: RequirementKind::Experience,
+                        priority,
+                        skill: None,
+                        min_years: Some(years),
+                        education: None,
+                        weight: base,
+                        source: line.clone(),
+                    });
+                }
+
+                // 同一句列出多个学历时取最低的，如 “本科或硕士”
+                if let Some(level) = Self::education_levels(clause).into_iter().min() {
+                    requirements.push(JdRequirement {
+                        kind: RequirementKind::Education,
+                        priority,
+                        skill: None,
+                        min_years: None,
+                        education: Some(level),
+                        weight: base,
+                        source: line.clone(),
+                    });
+                }
+            }
+        }
+
+        Self::dedup(requirements)
+    }
+
+    /// 对照简历逐条核对，按权重从高到低返回
+    pub fn evaluate(
+        doc: &ResumeDocument,
+        timeline: &ExperienceTimeline,
+        requirements: &[JdRequirement],
+    ) -> Vec<RequirementGap> {
+        let taxonomy = SkillTaxonomy::global();
+        let years = timeline.total_years;
+        let education = Self::resume_education(doc);
+
+        let mut gaps: Vec<RequirementGap> = requirements
+            .iter()
+            .map(|requirement| {
+                let (met, evidence, detail) = match requirement.kind {
+                    RequirementKind::Skill => {
+                        let name = requirement.skill.as_deref().unwrap_or_default();
+                        // 子技能可以体现父技能，如 React 体现 JavaScript
+                        let found = doc.find_skill(&taxonomy, name).or_else(|| {
+                            taxonomy
+                                .descendants(name)
+                                .iter()
+                                .find_map(|child| doc.find_skill(&taxonomy, &child.name))
+                        });
+                        let skill_years = TimelineAnalyzer::skill_years(timeline, name);
+                        match (found, requirement.min_years, skill_years) {
+                            (None, _, _) => (false, None, format!("简历中未体现 {}", name)),
+                            (Some(span), Some(required), None) => (
+                                false,
+                                Some(span),
+                                format!("要求 {} 年以上 {} 经验，简历的工作经历中未体现使用年限", required, name),
+                            ),
+                            (Some(span), Some(required), Some(skill_years)) if skill_years < required as f32 => (
+                                false,
+                                Some(span),
+                                format!(
+                                    "要求 {} 年以上 {} 经验，简历中相关经历约 {:.1} 年",
+                                    required, name, skill_years
+                                ),
+                            ),
+                            (Some(span), _, _) => (true, Some(span), format!("已体现 {}", name)),
+                        }
+                    }
+                    RequirementKind::Experience => {
+                        let required = requirement.min_years.unwrap_or_default();
+                        let evidence = timeline.entries.first().map(|e| e.evidence.clone());
+                        if evidence.is_none() {
+                            (false, None, format!("要求 {} 年以上经验，简历中未识别到任职时间", required))
+                        } else if years < required as f32 {
+                            (false, evidence, format!("要求 {} 年以上经验，简历约 {:.1} 年", required, years))
+                        } else {
+                            (true, evidence, format!("约 {:.1} 年经验，满足 {} 年以上的要求", years, required))
+                        }
+                    }
+                    RequirementKind::Education => {
+                        let required = requirement.education.unwrap_or(EducationLevel::Bachelor);
+                        match &education {
+                            Some((level, span)) if *level >= required => (
+                                true,
+                                Some(span.clone()),
+                                format!("{}学历，满足{}及以上的要求", level.label(), required.label()),
+                            ),
+                            Some((level, span)) => (
+                                false,
+                                Some(span.clone()),
+                                format!("要求{}及以上学历，简历为{}", required.label(), level.label()),
+                            ),
+                            None => (false, None, format!("要求{}及以上学历，简历中未识别到学历", required.label())),
+                        }
+                    }
+                };
+                RequirementGap {
+                    requirement: requirement.clone(),
+                    met,
+                    evidence,
+                    detail,
+                }
+            })
+            .collect();
+
+        gaps.sort_by(|a, b| b.requirement.weight.total_cmp(&a.requirement.weight));
+        gaps
+    }
+
+    /// 文本中提到的学历
+    pub fn education_levels(text: &str) -> Vec<EducationLevel> {
+        EDUCATION_TERMS
+            .iter()
+            .filter(|(_, terms)| terms.iter().any(|t| JdParser::contains_term(text, t)))
+            .map(|(level, _)| *level)
+            .collect()
+    }
+
+    // ========== 辅助方法 ==========
+
+    /// 句中的最低年限要求，如 “3-5年” 取 3
+    fn min_years(clause: &str) -> Option<u32> {
+        YEARS
+            .captures_iter(clause)
+            .filter_map(|c| parse_number(&c[1]))
+            .filter(|years| (1..=MAX_YEARS).contains(years))
+            .max()
+    }
+
+    /// 简历中的最高学历，优先看教育背景小节
+    fn resume_education(doc: &ResumeDocument) -> Option<(EducationLevel, EvidenceSpan)> {
+        let find = |lines: Vec<&DocLine>| {
+            lines
+                .into_iter()
+                .filter_map(|line| {
+                    Self::education_levels(&line.text)
+                        .into_iter()
+                        .max()
+                        .map(|level| (level, line.span()))
+                })
+                .max_by_key(|(level, _)| *level)
+        };
+
+        find(doc.section_lines(SectionKind::Education).collect()).or_else(|| find(doc.lines().collect()))
+    }
+
+    /// 同一技能只保留一条：必备优先，年限取较高者；年限和学历的加分项只在高于必备要求时保留
+    fn dedup(requirements: Vec<JdRequirement>) -> Vec<JdRequirement> {
+        let mut result: Vec<JdRequirement> = Vec::new();
+
+        for requirement in requirements {
+            let same = |r: &&mut JdRequirement| {
+                r.kind == requirement.kind
+                    && match requirement.kind {
+                        RequirementKind::Skill => r.skill == requirement.skill,
+                        _ => r.priority == requirement.priority,
+                    }
+            };
+            match result.iter_mut().find(same) {
+                Some(existing) => {
+                    if existing.priority == RequirementPriority::Preferred
+                        && requirement.priority == RequirementPriority::Must
+                    {
+                        existing.priority = RequirementPriority::Must;
+                        existing.weight = requirement.weight;
+                        existing.source = requirement.source;
+                    }
+                    existing.min_years = existing.min_years.max(requirement.min_years);
+                    existing.education = existing.education.max(requirement.education);
+                }
+                None => result.push(requirement),
+            }
+        }
+
+        let must = |kind: RequirementKind| {
+            result
+                .iter()
+                .find(|r| r.kind == kind && r.priority == RequirementPriority::Must)
+                .map(|r| (r.min_years, r.education))
+        };
+        let must_experience = must(RequirementKind::Experience);
+        let must_education = must(RequirementKind::Education);
+
+        result.retain(|r| {
+            if r.priority != RequirementPriority::Preferred {
+                return true;
+            }
+            match r.kind {
+                RequirementKind::Skill => true,
+                RequirementKind::Experience => must_experience.is_none_or(|(years, _)| r.min_years > years),
+                RequirementKind::Education => must_education.is_none_or(|(_, level)| r.education > level),
+            }
+        });
+
+        result
+    }
+}
+
+/// 解析阿拉伯数字、中文数字（十以内及几十几）和英文数字
+pub fn parse_number(text: &str) -> Option<u32> {
+    if let Ok(n) = text.parse() {
+        return Some(n);
+    }
+
+    const EN: &[&str] = &["one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten"];
+    if let Some(i) = EN.iter().position(|w| w.eq_ignore_ascii_case(text)) {
+        return Some(i as u32 + 1);
+    }
+
+    let digit = |c: char| match c {
+        '一' => Some(1),
+        '二' | '两' => Some(2),
+        '三' => Some(3),
+        '四' => Some(4),
+        '五' => Some(5),
+        '六' => Some(6),
+        '七' => Some(7),
+        '八' => Some(8),
+        '九' => Some(9),
+        _ => None,
+    };
+
+    let chars: Vec<char> = text.chars().collect();
+    match chars.as_slice() {
+        [c] if *c == '十' => Some(10),
+        [c] => digit(*c),
+        ['十', c] => Some(10 + digit(*c)?),
+        [c, '十'] => Some(digit(*c)? * 10),
+        [a, '十', b] => Some(digit(*a)? * 10 + digit(*b)?),
+        _ => None,
+    }
+}
@@ -1,109 +1,74 @@
 use crate::models::*;
+use crate::services::relevance::Corpus;
+use crate::services::{
+    HallucinationGuard, JdParser, RelevanceScorer, RequirementExtractor, ResumeDocument, ResumeTailor, ScoringEngine,
+    SkillTaxonomy, TimelineAnalyzer,
+};
+
+/// 优化建议中最多列出的要求差距数
+const MAX_GAP_SUGGESTIONS: usize = 6;
 
 pub struct ResumeOptimizer;
 
 impl ResumeOptimizer {
     /// 优化简历
-    pub async fn optimize(resume: &str, jd: &str) -> OptimizeResult {
-        // 提取 JD 关键词
-        let jd_keywords = Self::extract_keywords_from_text(jd);
-        
-        // 分析简历
-        let mut changes = Vec::new();
-        
-        // 模拟优化建议
-        if jd_keywords.iter().any(|k| k.contains("Python")) && !resume.contains("Python") {
-            changes.push("建议添加 Python 相关经验".to_string());
-        }
-        
-        if jd_keywords.iter().any(|k| k.contains("团队")) && !resume.contains("团队") {
-            changes.push("建议强调团队协作经验".to_string());
-        }
+    pub async fn optimize(resume: &str, jd: &str, corpus: &Corpus) -> OptimizeResult {
+        let doc = ResumeDocument::parse(resume);
+        let parsed_jd = JdParser::parse(jd);
+        let timeline = TimelineAnalyzer::analyze(&doc);
+        let requirement_gaps = RequirementExtractor::evaluate(&doc, &timeline, &parsed_jd.requirements);
+        
+        // 按权重从高到低，针对未满足的 JD 要求给出建议；缺少的技能不会写入简历
+        let mut suggestions: Vec<String> = requirement_gaps
+            .iter()
+            .filter(|g| !g.met)
+            .take(MAX_GAP_SUGGESTIONS)
+            .map(Self::gap_suggestion)
+            .collect();
         
         if resume.len() < 500 {
-            changes.push("简历内容过短，建议补充更多细节".to_string());
+            suggestions.push("简历内容过短，建议补充更多细节".to_string());
         }
         
         if !resume.contains("项目") && !resume.contains("Project") {
-            changes.push("建议添加项目经验部分".to_string());
+            suggestions.push("建议添加项目经验部分".to_string());
         }
         
-        // 计算匹配度
-        let match_score = Self::calculate_match_score(resume, jd);
+        let (tailored, changes) = ResumeTailor::tailor(&doc, &parsed_jd, jd, &timeline, corpus);
         
-        // 模拟优化后的简历
-        let optimized = format!(
-            r#"# 优化后的简历
-
-{}
-
-## 💡 优化建议
-
-{}
-"#,
-            resume,
-            changes.join("\n- ")
-        );
+        // 最终结果再与原简历核对一遍，去掉原简历中没有的事实
+        let (optimized_resume, violations) = HallucinationGuard::new(&doc).strip(&tailored);
+        
+        // 计算优化后简历的相关度
+        let relevance = RelevanceScorer::score(&optimized_resume, jd, corpus);
         
         OptimizeResult {
-            optimized_resume: optimized,
+            optimized_resume,
             changes,
-            match_score,
+            suggestions,
+            violations,
+            relevance,
+            requirement_gaps,
         }
     }
     
     /// 简历评分
     pub async fn score(resume: &str, jd: &str) -> ScoreResult {
-        let overall_score = Self::calculate_match_score(resume, jd);
-        
-        let categories = vec![
-            ScoreCategory {
-                name: "技能匹配".to_string(),
-                score: if resume.contains("技能") || resume.contains("技术") { 85 } else { 60 },
-                feedback: "技能描述清晰，建议突出与职位相关的核心技能".to_string(),
-            },
-            ScoreCategory {
-                name: "经验相关度".to_string(),
-                score: if resume.contains("年") || resume.contains("经验") { 80 } else { 50 },
-                feedback: "工作经验与职位要求匹配度较高".to_string(),
-            },
-            ScoreCategory {
-                name: "关键词覆盖".to_string(),
-                score: Self::calculate_keyword_coverage(resume, jd),
-                feedback: "已覆盖大部分职位关键词".to_string(),
-            },
-            ScoreCategory {
-                name: "格式规范".to_string(),
-                score: if resume.len() > 300 { 75 } else { 50 },
-                feedback: "简历结构清晰，建议使用更专业的排版".to_string(),
-            },
-        ];
-        
-        let suggestions = vec![
-            "建议在开头添加个人简介，突出核心竞争力".to_string(),
-            "使用量化数据展示工作成果（如：提升效率 30%）".to_string(),
-            "将最相关的经验放在前面".to_string(),
-            "确保联系方式完整且专业".to_string(),
-        ];
-        
-        ScoreResult {
-            overall_score,
-            categories,
-            suggestions,
-        }
+        ScoringEngine::score(resume, jd)
     }
     
     /// 生成求职信
     pub async fn generate_cover_letter(resume: &str, jd: &str) -> String {
-        let _company = Self::extract_company(jd).unwrap_or("贵公司");
-        let position = Self::extract_position(jd).unwrap_or("该职位");
+        let parsed = JdParser::parse(jd);
+        let company = parsed.company.as_deref().unwrap_or("贵公司");
+        let position = parsed.title.as_deref().unwrap_or("该");
         
         format!(
             r#"尊敬的招聘经理：
 
 您好！
 
-我写信是希望申请贵公司的{}职位。通过仔细阅读职位描述，我发现自己具备该岗位所需的核心能力和经验。
+我写信是希望申请{}的{}职位。通过仔细阅读职位描述，我发现自己具备该岗位所需的核心能力和经验。
 
 ## 核心优势
 
@@ -125,98 +90,39 @@ impl ResumeOptimizer {
 
 [您的姓名]
 [日期]"#,
+            company,
             position,
             resume.lines().take(5).collect::<Vec<_>>().join("\n")
         )
     }
     
-    /// 提取关键词
+    /// 提取关键词（词典中的技能，按重要程度排序）
     pub async fn extract_keywords(text: &str) -> Vec<Keyword> {
-        let common_keywords = vec![
-            ("Python", "编程语言", 90),
-            ("JavaScript", "编程语言", 85),
-            ("Rust", "编程语言", 80),
-            ("团队协作", "软技能", 75),
-            ("项目管理", "管理能力", 70),
-            ("数据分析", "技能", 85),
-            ("沟通能力", "软技能", 65),
-            ("问题解决", "软技能", 70),
-            ("领导力", "管理能力", 75),
-            ("创新", "软技能", 60),
-        ];
-        
-        common_keywords
+        let taxonomy = SkillTaxonomy::global();
+        let mut keywords: Vec<Keyword> = taxonomy
+            .extract(text)
             .into_iter()
-            .filter(|(word, _, _)| text.contains(word))
-            .map(|(word, category, importance)| Keyword {
-                word: word.to_string(),
-                importance,
-                category: category.to_string(),
+            .map(|skill| Keyword {
+                word: skill.name.clone(),
+                importance: skill.importance,
+                category: taxonomy.category_name(&skill.category).to_string(),
             })
-            .collect()
+            .collect();
+        keywords.sort_by_key(|k| std::cmp::Reverse(k.importance));
+        keywords
     }
     
     // ========== 辅助方法 ==========
     
-    fn calculate_match_score(resume: &str, jd: &str) -> u8 {
-        let resume_lower = resume.to_lowercase();
-        let jd_lower = jd.to_lowercase();
-        
-        let jd_words: Vec<&str> = jd_lower.split_whitespace().collect();
-        let matched = jd_words
-            .iter()
-            .filter(|word| resume_lower.contains(*word))
-            .count();
-        
-        if jd_words.is_empty() {
-            return 50;
-        }
-        
-        let score = (matched as f64 / jd_words.len() as f64 * 100.0) as u8;
-        score.min(95).max(30)
-    }
-    
-    fn calculate_keyword_coverage(resume: &str, jd: &str) -> u8 {
-        let keywords = Self::extract_keywords_from_text(jd);
-        let matched = keywords
-            .iter()
-            .filter(|k| resume.to_lowercase().contains(&k.to_lowercase()))
-            .count();
-        
-        if keywords.is_empty() {
-            return 70;
-        }
-        
-        ((matched as f64 / keywords.len() as f64 * 100.0) as u8).min(95)
-    }
-    
-    fn extract_keywords_from_text(text: &str) -> Vec<String> {
-        let common_tech = vec![
-            "Python", "JavaScript", "Rust", "Go", "Java", "TypeScript",
-            "React", "Vue", "Node", "Docker", "Kubernetes", "AWS",
-            "Git", "Linux", "SQL", "MongoDB", "Redis", "PostgreSQL",
-            "团队", "管理", "领导", "沟通", "分析", "设计",
-        ];
-        
-        common_tech
-            .into_iter()
-            .filter(|keyword| text.contains(keyword))
-            .map(|s| s.to_string())
-            .collect()
-    }
-    
-    fn extract_company(jd: &str) -> Option<&str> {
-        if jd.contains("阿里巴巴") { Some("阿里巴巴") }
-        else if jd.contains("腾讯") { Some("腾讯") }
-        else if jd.contains("字节") { Some("字节跳动") }
-        else { None }
-    }
-    
-    fn extract_position(jd: &str) -> Option<&str> {
-        if jd.contains("工程师") { Some("工程师") }
-        else if jd.contains("开发") { Some("开发") }
-        else if jd.contains("经理") { Some("经理") }
-        else { None }
+    /// 未满足的要求对应的修改建议
+    fn gap_suggestion(gap: &RequirementGap) -> String {
+        let advice = match (gap.requirement.kind, &gap.evidence) {
+            (RequirementKind::Skill, None) => "如有相关经验请在技能和经历中写明",
+            (RequirementKind::Skill, Some(_)) => "可在经历中写明使用该技能的时间和项目",
+            (RequirementKind::Experience, _) => "可突出相关项目和实习经历弥补差距",
+            (RequirementKind::Education, _) => "可突出相关证书、培训或进修经历",
+        };
+        format!("[{}] {}，{}", gap.requirement.priority.label(), gap.detail, advice)
     }
 }
 
@@ -224,12 +130,19 @@ impl ResumeOptimizer {
 
 pub struct OptimizeResult {
     pub optimized_resume: String,
-    pub changes: Vec<String>,
-    pub match_score: u8,
+    pub changes: Vec<ResumeChange>,
+    pub suggestions: Vec<String>,
+    pub violations: Vec<GuardViolation>,
+    pub relevance: RelevanceReport,
+    pub requirement_gaps: Vec<RequirementGap>,
 }
 
 pub struct ScoreResult {
     pub overall_score: u8,
     pub categories: Vec<ScoreCategory>,
     pub suggestions: Vec<String>,
+    pub timeline: ExperienceTimeline,
+    pub bullets: Vec<BulletAnalysis>,
+    pub completeness: CompletenessReport,
+    pub seniority: SeniorityReport,
 }
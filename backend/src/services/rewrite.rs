@@ -0,0 +1,429 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::models::*;
+use crate::services::analysis::{BUILTIN_MODEL, BUILTIN_PROVIDER};
+use crate::services::llm::{extract_json, LlmClient};
+use crate::services::{BulletAnalyzer, HallucinationGuard, JdParser, Redactor, ResumeDocument, SkillTaxonomy};
+
+/// 默认和最多的候选数
+const DEFAULT_ALTERNATIVES: usize = 3;
+const MAX_ALTERNATIVES: usize = 5;
+
+/// 一次最多改写的要点数
+const MAX_BULLETS: usize = 10;
+
+/// 每条候选最多加入的 JD 关键词数
+const MAX_KEYWORDS: usize = 3;
+
+/// 中文弱动词对应的替换动词；未收录的用 “主导”
+const VERB_UPGRADES_ZH: &[(&str, &str)] = &[
+    ("负责", "主导"), ("参与", "推动"), ("协助", "协同推进"), ("配合", "协同推进"), ("帮助", "推动"),
+    ("支持", "支撑"), ("跟进", "推进"), ("维护", "优化"), ("处理", "解决"), ("进行", "完成"),
+    ("做", "完成"), ("了解", "运用"), ("熟悉", "运用"), ("学习", "运用"), ("接触", "运用"),
+];
+
+/// 英文弱动词对应的替换动词；未收录的用 Led
+const VERB_UPGRADES_EN: &[(&str, &str)] = &[
+    ("responsible for", "Led"), ("helped", "Drove"), ("assisted", "Co-led"), ("participated", "Delivered"),
+    ("worked on", "Built"), ("involved in", "Drove"), ("handled", "Owned"), ("supported", "Enabled"),
+    ("contributed to", "Delivered"), ("tasked with", "Led"), ("duties included", "Led"),
+    ("was part of", "Drove"), ("familiar with", "Applied"), ("exposure to", "Applied"), ("tried", "Piloted"),
+    ("did", "Delivered"), ("worked with", "Applied"),
+];
+
+/// 要点末尾的模糊收尾，改写时去掉
+const TRAILING_VAGUE: &[&str] = &["等等", "等工作", "等", "etc.", "etc", "and so on"];
+
+/// 占位内容：【X%】、[X%]
+static PLACEHOLDER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"【[^】]+】|\[[^\]]+\]").unwrap());
+
+const SYSTEM_PROMPT: &str = "你是资深的简历顾问。请把每条工作经历要点改写为 STAR 结构（情境 Situation、任务 Task、行动 Action、结果 Result），\
+以有力的动作动词开头，并在符合事实的前提下自然融入给出的 JD 关键词。\
+不得编造原文没有的数字、公司、项目或技能：缺少的信息用占位符表示（中文用【】，英文用 []），如【X%】、[X users]。\
+[LINK_1]、[EMAIL_1] 等大写标记代表已脱敏的个人信息，请原样保留。\
+保持原要点的语言。只输出 JSON，格式为 \
+{\"bullets\":[{\"id\":0,\"alternatives\":[{\"text\":\"完整要点\",\"situation\":\"\",\"task\":\"\",\"action\":\"\",\"result\":\"\"}]}]}。";
+
+#[derive(Deserialize)]
+struct LlmReply {
+    bullets: Vec<LlmBullet>,
+}
+
+#[derive(Deserialize)]
+struct LlmBullet {
+    id: usize,
+    #[serde(default)]
+    alternatives: Vec<LlmAlternative>,
+}
+
+#[derive(Deserialize)]
+struct LlmAlternative {
+    text: String,
+    #[serde(flatten)]
+    star: StarParts,
+}
+
+/// 经历要点的 STAR 改写：优先使用 LLM，未配置或调用失败时使用规则模板
+pub struct BulletRewriter;
+
+impl BulletRewriter {
+    pub async fn rewrite(resume: &str, jd: &str, lines: &[usize], alternatives: Option<usize>) -> RewriteResult {
+        let doc = ResumeDocument::parse(resume);
+        let parsed_jd = JdParser::parse(jd);
+        let count = alternatives.unwrap_or(DEFAULT_ALTERNATIVES).clamp(1, MAX_ALTERNATIVES);
+
+        // JD 中的技能要求，按权重从高到低
+        let mut requirements: Vec<&JdRequirement> = parsed_jd
+            .requirements
+            .iter()
+            .filter(|r| r.kind == RequirementKind::Skill)
+            .collect();
+        requirements.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+        let keywords: Vec<String> = requirements.iter().filter_map(|r| r.skill.clone()).collect();
+
+        // 未指定行号时，改写有警告或错误的要点
+        let targets: Vec<BulletAnalysis> = BulletAnalyzer::analyze(&doc)
+            .bullets
+            .into_iter()
+            .filter(|b| {
+                if lines.is_empty() {
+                    b.severity >= Some(FindingSeverity::Warning)
+                } else {
+                    lines.contains(&b.line.line)
+                }
+            })
+            .take(MAX_BULLETS)
+            .collect();
+
+        let candidates: Vec<Vec<String>> = targets
+            .iter()
+            .map(|b| Self::candidate_keywords(&doc, b, &keywords))
+            .collect();
+
+        let generated = match LlmClient::global() {
+            Some(client) if !targets.is_empty() => {
+                Self::llm_alternatives(client, resume, &targets, &candidates, count).await
+            }
+            _ => None,
+        };
+        let (provider, model) = match (&generated, LlmClient::global()) {
+            (Some(_), Some(client)) => (client.provider().to_string(), client.model().to_string()),
+            _ => (BUILTIN_PROVIDER.to_string(), BUILTIN_MODEL.to_string()),
+        };
+
+        let guard = HallucinationGuard::new(&doc);
+        let bullets = targets
+            .into_iter()
+            .zip(candidates)
+            .enumerate()
+            .map(|(i, (bullet, candidates))| {
+                let original = JdParser::strip_bullet(&bullet.line.text).to_string();
+                let generated = generated
+                    .as_ref()
+                    .and_then(|g| g.iter().find(|b| b.id == i))
+                    .map(|b| b.alternatives.iter().map(|a| (a.text.clone(), a.star.clone())).collect::<Vec<_>>())
+                    .filter(|a| !a.is_empty())
+                    .unwrap_or_else(|| Self::rule_alternatives(&bullet, &candidates));
+
+                // LLM 可能编造数字或经历：数字替换为占位，其他事实所在的候选直接去掉
+                let mut alternatives: Vec<RewriteAlternative> = Vec::new();
+                let mut violations: Vec<GuardViolation> = Vec::new();
+                for (text, mut star) in generated {
+                    let (text, found) = guard.strip(text.trim());
+                    let dropped = found.iter().any(|v| v.kind != EntityKind::Number);
+                    violations.extend(found);
+                    if dropped || text.is_empty() || alternatives.iter().any(|a| a.text == text) {
+                        continue;
+                    }
+                    for part in [&mut star.situation, &mut star.task, &mut star.result].into_iter().flatten() {
+                        *part = guard.strip(part).0;
+                    }
+                    star.action = guard.strip(&star.action).0;
+                    alternatives.push(RewriteAlternative {
+                        keywords_added: Self::keywords_added(&original, &text, &keywords),
+                        placeholders: PLACEHOLDER.find_iter(&text).map(|m| m.as_str().to_string()).collect(),
+                        text,
+                        star,
+                    });
+                }
+                alternatives.truncate(count);
+
+                BulletRewrite {
+                    issues: bullet.findings.iter().map(|f| f.issue).collect(),
+                    line: bullet.line,
+                    alternatives,
+                    violations,
+                }
+            })
+            .collect();
+
+        RewriteResult {
+            bullets,
+            keywords,
+            provider,
+            model,
+        }
+    }
+
+    /// 只替换开头的弱动词并去掉末尾的 “等等”，不加入关键词和占位；不是弱动词开头时为 None
+    pub fn strengthen(bullet: &BulletAnalysis) -> Option<String> {
+        if bullet.verb_strength != VerbStrength::Weak {
+            return None;
+        }
+        let content = JdParser::strip_bullet(&bullet.line.text);
+        let english = content.starts_with(|c: char| c.is_ascii_alphabetic());
+        let (verb, task) = Self::split_verb(content, bullet, english);
+
+        let ending = content.chars().last().filter(|c| "。.；;".contains(*c));
+        let mut text = if english { format!("{} {}", verb, task) } else { format!("{}{}", verb, task) };
+        text.extend(ending);
+        Some(text)
+    }
+
+    // ========== 辅助方法 ==========
+
+    /// 可以加入该要点的 JD 关键词：简历其他部分已体现的技能，以及要点中技能的上级技能（如 React 之于 JavaScript）
+    fn candidate_keywords(doc: &ResumeDocument, bullet: &BulletAnalysis, keywords: &[String]) -> Vec<String> {
+        let taxonomy = SkillTaxonomy::global();
+        let in_bullet = taxonomy.extract(&bullet.line.text);
+
+        let mut ancestors: Vec<&str> = Vec::new();
+        for skill in &in_bullet {
+            let mut parent = skill.parent.as_deref();
+            while let Some(name) = parent.filter(|p| !ancestors.contains(p)) {
+                ancestors.push(name);
+                parent = taxonomy.lookup(name).and_then(|s| s.parent.as_deref());
+            }
+        }
+
+        keywords
+            .iter()
+            .filter(|k| !in_bullet.iter().any(|s| s.name.eq_ignore_ascii_case(k)))
+            .filter(|k| {
+                ancestors.iter().any(|a| a.eq_ignore_ascii_case(k))
+                    || doc
+                        .lines()
+                        .filter(|l| l.number != bullet.line.line)
+                        .any(|l| taxonomy.lookup(k).is_some_and(|s| taxonomy.find(&l.text, s).is_some()))
+            })
+            .take(MAX_KEYWORDS)
+            .cloned()
+            .collect()
+    }
+
+    /// 改写后新出现的 JD 关键词
+    fn keywords_added(original: &str, text: &str, keywords: &[String]) -> Vec<String> {
+        let taxonomy = SkillTaxonomy::global();
+        keywords
+            .iter()
+            .filter(|k| {
+                taxonomy
+                    .lookup(k)
+                    .is_some_and(|s| taxonomy.find(text, s).is_some() && taxonomy.find(original, s).is_none())
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// 发送给 LLM 的要点先脱敏，返回的候选再还原
+    async fn llm_alternatives(
+        client: &LlmClient,
+        resume: &str,
+        targets: &[BulletAnalysis],
+        candidates: &[Vec<String>],
+        count: usize,
+    ) -> Option<Vec<LlmBullet>> {
+        let mut redactor = Redactor::new(&[]);
+        redactor.redact_resume(resume);
+        let bullets: Vec<serde_json::Value> = targets
+            .iter()
+            .zip(candidates)
+            .enumerate()
+            .map(|(i, (bullet, keywords))| {
+                json!({
+                    "id": i,
+                    "text": redactor.redact_fragment(JdParser::strip_bullet(&bullet.line.text)),
+                    "issues": bullet.findings.iter().map(|f| f.message.as_str()).collect::<Vec<_>>(),
+                    "keywords": keywords,
+                })
+            })
+            .collect();
+        let user = json!({ "alternatives": count, "bullets": bullets }).to_string();
+
+        let reply = match client.chat(SYSTEM_PROMPT, &user).await {
+            Ok(reply) => reply,
+            Err(e) => {
+                tracing::warn!("Bullet rewrite via LLM failed, using rules: {}", e);
+                return None;
+            }
+        };
+        match serde_json::from_str::<LlmReply>(extract_json(&reply)) {
+            Ok(mut parsed) => {
+                let mapping = redactor.mapping();
+                let restore = |text: &str| Redactor::restore(text, mapping).0;
+                for alternative in parsed.bullets.iter_mut().flat_map(|b| b.alternatives.iter_mut()) {
+                    alternative.text = restore(&alternative.text);
+                    let star = &mut alternative.star;
+                    star.action = restore(&star.action);
+                    for part in [&mut star.situation, &mut star.task, &mut star.result].into_iter().flatten() {
+                        *part = restore(part);
+                    }
+                }
+                Some(parsed.bullets)
+            }
+            Err(e) => {
+                tracing::warn!("Unparseable LLM rewrite reply, using rules: {}", e);
+                None
+            }
+        }
+    }
+
+    /// 规则模板：替换弱动词，加入关键词，缺少结果时补充占位
+    fn rule_alternatives(bullet: &BulletAnalysis, keywords: &[String]) -> Vec<(String, StarParts)> {
+        let content = JdParser::strip_bullet(&bullet.line.text);
+        let english = content.starts_with(|c: char| c.is_ascii_alphabetic());
+        let (verb, task) = Self::split_verb(content, bullet, english);
+        let metrics: Vec<&str> = bullet.metrics.iter().map(|m| m.span.text.as_str()).collect();
+
+        // 三种写法分别加入前两个、全部和第一个关键词
+        let keyword_sets = [
+            &keywords[..keywords.len().min(2)],
+            keywords,
+            &keywords[..keywords.len().min(1)],
+        ];
+
+        let mut alternatives = Vec::new();
+        for (style, keywords) in keyword_sets.iter().enumerate() {
+            let using = Self::keyword_clause(keywords, english);
+            let action = if english {
+                format!("{} {}{}", verb, task, using)
+            } else {
+                format!("{}{}{}", verb, task, using)
+            };
+            let result = if metrics.is_empty() {
+                None
+            } else if english {
+                Some(metrics.join(", "))
+            } else {
+                Some(metrics.join("、"))
+            };
+
+            let (situation, result_clause) = match (style, english) {
+                (0, false) => (None, "使【关键指标】提升【X%】"),
+                (1, false) => (Some("针对【业务背景或问题】"), "最终使【关键指标】提升【X%】"),
+                (_, false) => (Some("在【项目名称】中"), "支撑【业务规模，如 X 万用户】"),
+                (0, true) => (None, "improving [key metric] by [X%]"),
+                (1, true) => (Some("To address [business problem]"), "resulting in [measurable outcome]"),
+                (_, true) => (Some("At [project or team]"), "supporting [scale, e.g. X users]"),
+            };
+
+            // 原文已有量化数据时不再补充结果占位
+            let mut parts: Vec<String> = Vec::new();
+            if let Some(situation) = situation {
+                parts.push(situation.to_string());
+            }
+            parts.push(match (english, situation.is_some()) {
+                (true, true) => lowercase_first(&action),
+                _ => action.clone(),
+            });
+            if result.is_none() {
+                parts.push(result_clause.to_string());
+            }
+            let separator = if english { ", " } else { "，" };
+            let mut text = parts.join(separator);
+            if english {
+                text.push('.');
+            }
+
+            alternatives.push((
+                text,
+                StarParts {
+                    situation: situation.map(str::to_string),
+                    task: Some(task.clone()),
+                    action,
+                    result: result.or_else(|| Some(result_clause.to_string())),
+                },
+            ));
+        }
+        alternatives
+    }
+
+    /// 拆出开头的动词（弱动词替换为有力动词）和其后的内容
+    fn split_verb(content: &str, bullet: &BulletAnalysis, english: bool) -> (String, String) {
+        let upgrades = if english { VERB_UPGRADES_EN } else { VERB_UPGRADES_ZH };
+        let default_verb = if english { "Led" } else { "主导" };
+
+        let (verb, rest) = match (&bullet.verb, bullet.verb_strength) {
+            (Some(verb), VerbStrength::Weak) => {
+                let lower = verb.to_lowercase();
+                let upgraded = upgrades
+                    .iter()
+                    .find(|(weak, _)| lower.starts_with(weak))
+                    .map(|(_, strong)| *strong)
+                    .unwrap_or(default_verb);
+                let rest = content
+                    .find(verb.as_str())
+                    .map(|i| &content[i + verb.len()..])
+                    .unwrap_or(content);
+                (upgraded.to_string(), rest)
+            }
+            (Some(verb), VerbStrength::Strong) => {
+                let rest = content.find(verb.as_str()).map(|i| &content[i + verb.len()..]).unwrap_or(content);
+                (content[..content.len() - rest.len()].trim().to_string(), rest)
+            }
+            _ => (default_verb.to_string(), content),
+        };
+
+        let mut task = rest.trim().trim_end_matches(['。', '；', ';', '，', ',', '.', ' ']).to_string();
+        if english {
+            // participated in、worked on 等短语残留的介词
+            for preposition in ["in ", "on ", "with ", "to "] {
+                if let Some(stripped) = task.strip_prefix(preposition) {
+                    task = stripped.to_string();
+                }
+            }
+            // Led developing X 不通顺，改为 Led efforts in developing X
+            if task.split_whitespace().next().is_some_and(|w| w.ends_with("ing")) {
+                task = format!("efforts in {}", task);
+            }
+        }
+        for vague in TRAILING_VAGUE {
+            if let Some(stripped) = task.strip_suffix(vague) {
+                task = stripped.trim_end().trim_end_matches([',', '，', '、']).to_string();
+                break;
+            }
+        }
+
+        (verb, task)
+    }
+
+    fn keyword_clause(keywords: &[String], english: bool) -> String {
+        match (keywords, english) {
+            ([], _) => String::new(),
+            ([only], true) => format!(" using {}", only),
+            ([init @ .., last], true) => format!(" using {} and {}", init.join(", "), last),
+            (_, false) => format!("，使用 {}", keywords.join("、")),
+        }
+    }
+}
+
+fn lowercase_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+// ========== 内部结果结构 ==========
+
+pub struct RewriteResult {
+    pub bullets: Vec<BulletRewrite>,
+    pub keywords: Vec<String>,
+    pub provider: String,
+    pub model: String,
+}
@@ -0,0 +1,543 @@
+use std::collections::HashMap;
+
+use crate::models::*;
+use crate::services::document::SectionKind;
+use crate::services::resume::ScoreResult;
+use crate::services::tokenizer::TokenKind;
+use crate::services::{
+    BulletAnalyzer, CompletenessChecker, JdParser, RequirementExtractor, ResumeDocument, SeniorityAnalyzer,
+    SkillTaxonomy, TimelineAnalyzer, Tokenizer,
+};
+
+/// 关键词覆盖最多考察的 JD 关键词数
+const MAX_JD_KEYWORDS: usize = 20;
+
+/// 达到该月数的空档会扣分
+const LONG_GAP_MONTHS: u32 = 6;
+
+/// 单个维度的评分结果
+struct SubScore {
+    key: &'static str,
+    name: &'static str,
+    weight: f32,
+    score: u8,
+    feedback: String,
+    evidence: Vec<EvidenceSpan>,
+    suggestions: Vec<String>,
+}
+
+/// 确定性的 ATS 风格评分引擎：每个维度给出得分、证据和基于实际差距的建议
+pub struct ScoringEngine;
+
+impl ScoringEngine {
+    pub fn score(resume: &str, jd: &str) -> ScoreResult {
+        let doc = ResumeDocument::parse(resume);
+        let parsed_jd = JdParser::parse(jd);
+
+        let timeline = TimelineAnalyzer::analyze(&doc);
+        let bullets = BulletAnalyzer::analyze(&doc);
+        let gaps = RequirementExtractor::evaluate(&doc, &timeline, &parsed_jd.requirements);
+        let completeness = CompletenessChecker::check(&doc);
+        let seniority = SeniorityAnalyzer::compare(&doc, &timeline, &parsed_jd, jd);
+
+        let sub_scores = vec![
+            Self::keyword_coverage(&doc, jd, &parsed_jd.requirements),
+            Self::required_skill_match(&gaps),
+            Self::experience_years(&timeline, &gaps),
+            Self::education_match(&gaps),
+            Self::seniority_alignment(&seniority),
+            Self::section_completeness(&completeness),
+            Self::quantified_achievements(&bullets),
+            Self::formatting_length(&doc),
+        ];
+
+        let total_weight: f32 = sub_scores.iter().map(|s| s.weight).sum();
+        let weighted: f32 = sub_scores.iter().map(|s| s.weight * s.score as f32).sum();
+        let overall_score = (weighted / total_weight).round() as u8;
+
+        // 按加权失分从高到低汇总建议
+        let mut ranked: Vec<&SubScore> = sub_scores.iter().collect();
+        ranked.sort_by(|a, b| {
+            let gap = |s: &SubScore| (100 - s.score) as f32 * s.weight;
+            gap(b).total_cmp(&gap(a))
+        });
+        let suggestions: Vec<String> = ranked
+            .iter()
+            .flat_map(|s| s.suggestions.iter().take(2).cloned())
+            .take(8)
+            .collect();
+
+        let categories = sub_scores
+            .into_iter()
+            .map(|s| ScoreCategory {
+                key: s.key.to_string(),
+                name: s.name.to_string(),
+                score: s.score,
+                weight: s.weight,
+                feedback: s.feedback,
+                evidence: s.evidence,
+                suggestions: s.suggestions,
+            })
+            .collect();
+
+        ScoreResult {
+            overall_score,
+            categories,
+            suggestions,
+            timeline,
+            bullets: bullets.bullets,
+            completeness,
+            seniority,
+        }
+    }
+
+    // ========== 各维度评分 ==========
+
+    fn keyword_coverage(doc: &ResumeDocument, jd: &str, requirements: &[JdRequirement]) -> SubScore {
+        let taxonomy = SkillTaxonomy::global();
+        let keywords = Self::jd_keywords(&taxonomy, jd);
+
+        let mut evidence = Vec::new();
+        let mut missing = Vec::new();
+        let mut total: f32 = 0.0;
+        let mut matched: f32 = 0.0;
+        for keyword in &keywords {
+            let weight = Self::keyword_weight(keyword, requirements);
+            total += weight;
+            match doc.find_skill(&taxonomy, keyword) {
+                Some(span) => {
+                    matched += weight;
+                    evidence.push(span);
+                }
+                None => missing.push((keyword.as_str(), weight)),
+            }
+        }
+
+        let (score, feedback) = if keywords.is_empty() {
+            (70, "职位描述中未识别到常见关键词".to_string())
+        } else {
+            (
+                (matched / total * 100.0).round() as u8,
+                format!("覆盖 {}/{} 个职位关键词", evidence.len(), keywords.len()),
+            )
+        };
+
+        missing.sort_by(|a, b| b.1.total_cmp(&a.1));
+        let suggestions = if missing.is_empty() {
+            Vec::new()
+        } else {
+            let missing: Vec<&str> = missing.iter().map(|(k, _)| *k).collect();
+            vec![format!("在相关经历中体现这些职位关键词：{}", missing.join("、"))]
+        };
+
+        SubScore {
+            key: "keyword_coverage",
+            name: "关键词覆盖",
+            weight: 0.20,
+            score,
+            feedback,
+            evidence,
+            suggestions,
+        }
+    }
+
+    fn required_skill_match(gaps: &[RequirementGap]) -> SubScore {
+        let skill_gaps: Vec<&RequirementGap> = gaps
+            .iter()
+            .filter(|g| g.requirement.kind == RequirementKind::Skill)
+            .collect();
+        let count = |priority: RequirementPriority, met: bool| {
+            skill_gaps
+                .iter()
+                .filter(|g| g.requirement.priority == priority && (!met || g.met))
+                .count()
+        };
+
+        // 按要求权重计分：必备高于加分，词典中重要的技能高于次要技能
+        let total: f32 = skill_gaps.iter().map(|g| g.requirement.weight).sum();
+        let matched: f32 = skill_gaps.iter().filter(|g| g.met).map(|g| g.requirement.weight).sum();
+
+        let (score, feedback) = if skill_gaps.is_empty() {
+            (70, "职位描述中未识别到明确的技能要求".to_string())
+        } else {
+            (
+                (matched / total * 100.0).round() as u8,
+                format!(
+                    "必备技能匹配 {}/{}，加分技能匹配 {}/{}",
+                    count(RequirementPriority::Must, true),
+                    count(RequirementPriority::Must, false),
+                    count(RequirementPriority::Preferred, true),
+                    count(RequirementPriority::Preferred, false),
+                ),
+            )
+        };
+
+        let missing = |priority: RequirementPriority| {
+            skill_gaps
+                .iter()
+                .filter(|g| g.requirement.priority == priority && g.evidence.is_none())
+                .filter_map(|g| g.requirement.skill.as_deref())
+                .collect::<Vec<_>>()
+        };
+        let missing_required = missing(RequirementPriority::Must);
+        let missing_preferred = missing(RequirementPriority::Preferred);
+
+        let mut suggestions = Vec::new();
+        if !missing_required.is_empty() {
+            suggestions.push(format!(
+                "职位必备技能缺失：{}，如有相关经验请在技能和经历中写明",
+                missing_required.join("、")
+            ));
+        }
+        // 技能已体现但年限不足
+        suggestions.extend(
+            skill_gaps
+                .iter()
+                .filter(|g| !g.met && g.evidence.is_some())
+                .map(|g| format!("{}，可在经历中写明使用该技能的时间和项目", g.detail)),
+        );
+        if !missing_preferred.is_empty() {
+            suggestions.push(format!("可补充加分技能：{}", missing_preferred.join("、")));
+        }
+
+        SubScore {
+            key: "required_skill_match",
+            name: "技能匹配",
+            weight: 0.25,
+            score,
+            feedback,
+            evidence: skill_gaps.iter().filter_map(|g| g.evidence.clone()).collect(),
+            suggestions,
+        }
+    }
+
+    fn experience_years(timeline: &ExperienceTimeline, gaps: &[RequirementGap]) -> SubScore {
+        // 取必备要求中最高的年限，包括针对单项技能的年限
+        let required_years = gaps
+            .iter()
+            .filter(|g| g.requirement.priority == RequirementPriority::Must)
+            .filter_map(|g| g.requirement.min_years)
+            .max();
+
+        let years = timeline.total_years;
+        let evidence: Vec<EvidenceSpan> = timeline.entries.iter().map(|e| e.evidence.clone()).collect();
+
+        let (score, mut feedback, mut suggestions) = match required_years {
+            Some(required) if evidence.is_empty() => (
+                40,
+                format!("职位要求 {} 年以上经验，简历中未识别到任职时间", required),
+                vec!["为每段工作经历标注起止时间，如 2019.03 - 至今".to_string()],
+            ),
+            Some(required) => {
+                let score = ((years / required as f32).min(1.0) * 100.0).round() as u8;
+                let suggestions = if years < required as f32 {
+                    vec![format!(
+                        "职位要求 {} 年以上经验，简历约 {:.1} 年，可突出相关项目和实习经历弥补差距",
+                        required, years
+                    )]
+                } else {
+                    Vec::new()
+                };
+                (score, format!("约 {:.1} 年经验，职位要求 {} 年以上", years, required), suggestions)
+            }
+            None if evidence.is_empty() => (
+                50,
+                "简历中未识别到任职时间".to_string(),
+                vec!["为每段工作经历标注起止时间，如 2019.03 - 至今".to_string()],
+            ),
+            None => (80, format!("约 {:.1} 年经验，职位未明确年限要求", years), Vec::new()),
+        };
+
+        // 较长的空档每段扣 5 分，最多扣 15 分
+        let long_gaps: Vec<&TimelineGap> = timeline.gaps.iter().filter(|g| g.months >= LONG_GAP_MONTHS).collect();
+        let penalty = (long_gaps.len().min(3) * 5) as u8;
+        suggestions.extend(long_gaps.iter().map(|g| {
+            format!("{} 至 {} 有 {} 个月空档，可简要说明（进修、创业、家庭等）", g.start, g.end, g.months)
+        }));
+        suggestions.extend(timeline.overlaps.iter().map(|o| {
+            format!("「{}」与「{}」时间重叠 {} 个月，如为兼职或过渡期请注明", o.first, o.second, o.months)
+        }));
+
+        if !timeline.gaps.is_empty() || !timeline.overlaps.is_empty() {
+            feedback.push_str(&format!(
+                "，{} 段空档，{} 处时间重叠",
+                timeline.gaps.len(),
+                timeline.overlaps.len()
+            ));
+        }
+
+        SubScore {
+            key: "experience_years",
+            name: "经验相关度",
+            weight: 0.15,
+            score: score.saturating_sub(penalty),
+            feedback,
+            evidence,
+            suggestions,
+        }
+    }
+
+    fn education_match(gaps: &[RequirementGap]) -> SubScore {
+        let gap = gaps
+            .iter()
+            .filter(|g| g.requirement.kind == RequirementKind::Education)
+            .find(|g| g.requirement.priority == RequirementPriority::Must)
+            .or_else(|| gaps.iter().find(|g| g.requirement.kind == RequirementKind::Education));
+
+        let (score, feedback, suggestions) = match gap {
+            None => (80, "职位未明确学历要求".to_string(), Vec::new()),
+            Some(gap) if gap.met => (100, gap.detail.clone(), Vec::new()),
+            // 加分项中的学历要求未满足，扣分较少
+            Some(gap) if gap.requirement.priority == RequirementPriority::Preferred => {
+                (75, gap.detail.clone(), Vec::new())
+            }
+            Some(gap) if gap.evidence.is_none() => (
+                50,
+                gap.detail.clone(),
+                vec!["在教育背景中写明学历、学校和专业，如“本科 · 计算机科学与技术”".to_string()],
+            ),
+            Some(gap) => (
+                30,
+                gap.detail.clone(),
+                vec![format!("{}，可突出相关工作经验、证书或在读进修弥补", gap.detail)],
+            ),
+        };
+
+        SubScore {
+            key: "education_match",
+            name: "学历匹配",
+            weight: 0.05,
+            score,
+            feedback,
+            evidence: gap.and_then(|g| g.evidence.clone()).into_iter().collect(),
+            suggestions,
+        }
+    }
+
+    fn seniority_alignment(report: &SeniorityReport) -> SubScore {
+        let basis = |assessment: &SeniorityAssessment| {
+            assessment.signals.iter().map(|s| s.detail.clone()).collect::<Vec<_>>().join("，")
+        };
+        let (score, feedback) = match (report.job.level.as_deref(), report.resume.level.as_deref()) {
+            (None, _) => (75, "职位未明确职级要求".to_string()),
+            (Some(target), None) => (60, format!("职位要求 {} 级别，简历中未体现职级", target)),
+            (Some(target), Some(current)) => (
+                (100 - report.gap.abs() * 25).max(0) as u8,
+                format!(
+                    "简历职级 {}（{}），职位要求 {}（{}）",
+                    current,
+                    basis(&report.resume),
+                    target,
+                    basis(&report.job)
+                ),
+            ),
+        };
+
+        SubScore {
+            key: "seniority_alignment",
+            name: "职级匹配",
+            weight: 0.10,
+            score,
+            feedback,
+            evidence: report.resume.signals.iter().filter_map(|s| s.evidence.clone()).collect(),
+            suggestions: report.suggestions.clone(),
+        }
+    }
+
+    fn section_completeness(report: &CompletenessReport) -> SubScore {
+        let present = report.sections.iter().filter(|s| s.present).count();
+        let invalid = report.contacts.iter().filter(|c| !c.valid).count();
+
+        let mut feedback = format!("包含 {}/{} 个标准部分", present, report.sections.len());
+        if invalid > 0 {
+            feedback.push_str(&format!("，{} 项联系方式格式有误", invalid));
+        }
+
+        let evidence = report
+            .sections
+            .iter()
+            .filter_map(|s| s.evidence.clone())
+            .chain(report.contacts.iter().map(|c| c.span.clone()))
+            .collect();
+
+        SubScore {
+            key: "section_completeness",
+            name: "结构完整度",
+            weight: 0.10,
+            score: report.score,
+            feedback,
+            evidence,
+            suggestions: report.issues.clone(),
+        }
+    }
+
+    fn quantified_achievements(report: &BulletReport) -> SubScore {
+        let total = report.bullets.len();
+
+        // 半数以上的要点包含量化数据即可拿满量化部分（70%），其余看是否以有力动词开头
+        let (score, feedback) = if total == 0 {
+            (40, "未识别到经历描述".to_string())
+        } else {
+            let quantified = Self::ratio_score(report.quantified * 2, total) as f32;
+            let verbs = Self::ratio_score(report.strong_verbs, total) as f32;
+            (
+                (quantified * 0.7 + verbs * 0.3).round() as u8,
+                format!(
+                    "{}/{} 条经历描述包含量化数据，{} 条以有力动词开头",
+                    report.quantified, total, report.strong_verbs
+                ),
+            )
+        };
+
+        // 问题最严重的几条
+        let mut flagged: Vec<&BulletAnalysis> = report.bullets.iter().filter(|b| b.severity.is_some()).collect();
+        flagged.sort_by_key(|b| std::cmp::Reverse(b.severity));
+        let suggestions = flagged
+            .iter()
+            .take(3)
+            .map(|b| {
+                let excerpt: String = b.line.text.chars().take(24).collect();
+                let messages: Vec<&str> = b.findings.iter().take(2).map(|f| f.message.as_str()).collect();
+                format!("第 {} 行「{}」：{}", b.line.line, excerpt, messages.join("；"))
+            })
+            .collect();
+
+        SubScore {
+            key: "quantified_achievements",
+            name: "量化成果",
+            weight: 0.10,
+            score,
+            feedback,
+            evidence: report
+                .bullets
+                .iter()
+                .flat_map(|b| b.metrics.iter().map(|m| m.span.clone()))
+                .collect(),
+            suggestions,
+        }
+    }
+
+    fn formatting_length(doc: &ResumeDocument) -> SubScore {
+        let length = doc.text.chars().filter(|c| !c.is_whitespace()).count();
+        let mut score: i32 = 100;
+        let mut notes = Vec::new();
+        let mut suggestions = Vec::new();
+        let mut evidence = Vec::new();
+
+        if length < 300 {
+            score -= 50;
+            suggestions.push(format!("简历仅 {} 字，内容过短，建议补充经历细节", length));
+        } else if length < 500 {
+            score -= 25;
+            suggestions.push(format!("简历约 {} 字，建议补充项目和成果细节", length));
+        } else if length > 5000 {
+            score -= 30;
+            suggestions.push(format!("简历约 {} 字，篇幅过长，建议精简到两页以内", length));
+        } else if length > 3000 {
+            score -= 15;
+            suggestions.push(format!("简历约 {} 字，建议删减与职位无关的内容", length));
+        }
+        notes.push(format!("全文约 {} 字", length));
+
+        let long_lines: Vec<_> = doc.lines().filter(|l| l.text.chars().count() > 120).collect();
+        if !long_lines.is_empty() {
+            score -= (long_lines.len() as i32 * 5).min(20);
+            notes.push(format!("{} 行过长", long_lines.len()));
+            suggestions.push("将过长的段落拆分为要点，每条不超过两行".to_string());
+            evidence.extend(long_lines.iter().map(|l| l.span()));
+        }
+
+        let experience: Vec<_> = doc.section_lines(SectionKind::Experience).collect();
+        if experience.len() > 3 && !experience.iter().any(|l| l.is_bullet()) {
+            score -= 15;
+            notes.push("工作经历未使用要点列表".to_string());
+            suggestions.push("工作经历使用项目符号（•、-）逐条列出职责和成果".to_string());
+        }
+
+        SubScore {
+            key: "formatting_length",
+            name: "格式规范",
+            weight: 0.10,
+            score: score.clamp(0, 100) as u8,
+            feedback: notes.join("，"),
+            evidence,
+            suggestions,
+        }
+    }
+
+    // ========== 辅助方法 ==========
+
+    /// JD 关键词中简历未体现的，按权重从高到低
+    pub fn missing_keywords(doc: &ResumeDocument, jd: &str, requirements: &[JdRequirement]) -> Vec<String> {
+        let taxonomy = SkillTaxonomy::global();
+        let mut missing: Vec<(String, f32)> = Self::jd_keywords(&taxonomy, jd)
+            .into_iter()
+            .filter(|keyword| doc.find_skill(&taxonomy, keyword).is_none())
+            .map(|keyword| {
+                let weight = Self::keyword_weight(&keyword, requirements);
+                (keyword, weight)
+            })
+            .collect();
+        missing.sort_by(|a, b| b.1.total_cmp(&a.1));
+        missing.into_iter().map(|(keyword, _)| keyword).collect()
+    }
+
+    /// 必备技能权重高于一般关键词，加分技能权重最低
+    fn keyword_weight(keyword: &str, requirements: &[JdRequirement]) -> f32 {
+        let priority = requirements
+            .iter()
+            .find(|r| r.skill.as_deref().is_some_and(|s| s.eq_ignore_ascii_case(keyword)))
+            .map(|r| r.priority);
+        match priority {
+            Some(RequirementPriority::Must) => 1.0,
+            Some(RequirementPriority::Preferred) => 0.5,
+            None => 0.75,
+        }
+    }
+
+    /// JD 中的关键词：分词得到的技术术语和较长的中文词，加上词典中的技能，按出现次数排序；
+    /// 技能的别名统一为标准名称
+    fn jd_keywords(taxonomy: &SkillTaxonomy, jd: &str) -> Vec<String> {
+        let tokenizer = Tokenizer::global();
+
+        // (原文写法, 出现次数, 首次出现位置)
+        let mut counts: HashMap<String, (String, usize, usize)> = HashMap::new();
+        let tokens = tokenizer.tokenize(jd);
+        let token_count = tokens.len();
+        for (i, token) in tokens.into_iter().enumerate() {
+            let relevant = match token.kind {
+                TokenKind::Term => true,
+                TokenKind::Word => token.text.chars().count() >= 3,
+                TokenKind::Latin | TokenKind::Number => false,
+            };
+            if !relevant || !tokenizer.is_keyword(&token) {
+                continue;
+            }
+            let text = match taxonomy.lookup(&token.text) {
+                Some(skill) => skill.name.clone(),
+                None => token.text,
+            };
+            counts
+                .entry(text.to_lowercase())
+                .or_insert((text, 0, i))
+                .1 += 1;
+        }
+
+        for (i, skill) in taxonomy.extract(jd).into_iter().enumerate() {
+            counts
+                .entry(skill.name.to_lowercase())
+                .or_insert((skill.name.clone(), 1, token_count + i));
+        }
+
+        let mut ranked: Vec<(String, usize, usize)> = counts.into_values().collect();
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.2.cmp(&b.2)));
+        ranked.into_iter().take(MAX_JD_KEYWORDS).map(|(text, _, _)| text).collect()
+    }
+
+    fn ratio_score(matched: usize, total: usize) -> u8 {
+        if total == 0 {
+            return 0;
+        }
+        ((matched as f32 / total as f32).min(1.0) * 100.0).round() as u8
+    }
+}
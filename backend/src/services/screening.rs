@@ -0,0 +1,394 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+use std::sync::Arc;
+
+use sqlx::types::Json;
+use sqlx::PgPool;
+use thiserror::Error;
+use tokio::task::{self, JoinError, JoinSet};
+use uuid::Uuid;
+use zip::result::ZipError;
+use zip::ZipArchive;
+
+use crate::models::*;
+use crate::services::{AtsChecker, CompletenessChecker, JdParser, RequirementExtractor, ResumeDocument, ScoringEngine};
+
+/// 压缩包中最多的简历数
+pub const MAX_CANDIDATES: usize = 200;
+
+/// 单份简历解压后的大小上限
+const MAX_RESUME_BYTES: u64 = 10 * 1024 * 1024;
+
+/// 全部简历解压后的大小上限
+const MAX_EXTRACTED_BYTES: u64 = 200 * 1024 * 1024;
+
+/// 同时处理的简历数
+const CONCURRENCY: usize = 4;
+
+/// 每个用户同时排队或运行的筛选任务数
+pub const MAX_ACTIVE_JOBS: i64 = 3;
+
+/// 每位候选人返回的缺失关键词数
+const MAX_MISSING_KEYWORDS: usize = 5;
+
+#[derive(Debug, Error)]
+pub enum ScreeningError {
+    #[error("Invalid zip archive: {0}")]
+    Archive(#[from] ZipError),
+    #[error("No PDF or DOCX resumes found in archive")]
+    Empty,
+    #[error("Too many resumes in archive, at most {0}")]
+    TooMany(usize),
+    #[error("Resume {0} is too large, at most 10 MB")]
+    FileTooLarge(String),
+    #[error("Archive is too large when extracted, at most 200 MB")]
+    TooLarge,
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+    #[error(transparent)]
+    Task(#[from] JoinError),
+}
+
+/// 压缩包中的一份简历
+pub struct ResumeFile {
+    pub name: String,
+    pub bytes: Vec<u8>,
+}
+
+/// 取出压缩包中的 PDF / DOCX 文件，按路径排序；解压较慢，需在阻塞线程中调用
+pub fn extract_resumes(archive: &[u8]) -> Result<Vec<ResumeFile>, ScreeningError> {
+    let mut archive = ZipArchive::new(Cursor::new(archive))?;
+    let mut files = Vec::new();
+    let mut extracted: u64 = 0;
+    for i in 0..archive.len() {
+        let file = archive.by_index(i)?;
+        let name = file.name().to_string();
+        if file.is_dir() || !is_resume_file(&name) {
+            continue;
+        }
+        if files.len() == MAX_CANDIDATES {
+            return Err(ScreeningError::TooMany(MAX_CANDIDATES));
+        }
+        // 先按声明的大小检查，读取时再按实际大小检查
+        if file.size() > MAX_RESUME_BYTES {
+            return Err(ScreeningError::FileTooLarge(name));
+        }
+        if extracted + file.size() > MAX_EXTRACTED_BYTES {
+            return Err(ScreeningError::TooLarge);
+        }
+        let mut bytes = Vec::new();
+        file.take(MAX_RESUME_BYTES + 1).read_to_end(&mut bytes).map_err(ZipError::Io)?;
+        if bytes.len() as u64 > MAX_RESUME_BYTES {
+            return Err(ScreeningError::FileTooLarge(name));
+        }
+        extracted += bytes.len() as u64;
+        if extracted > MAX_EXTRACTED_BYTES {
+            return Err(ScreeningError::TooLarge);
+        }
+        files.push(ResumeFile { name, bytes });
+    }
+
+    if files.is_empty() {
+        return Err(ScreeningError::Empty);
+    }
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(files)
+}
+
+/// 跳过 macOS 压缩时生成的 __MACOSX 目录和隐藏文件
+fn is_resume_file(name: &str) -> bool {
+    let base = name.rsplit('/').next().unwrap_or(name);
+    if name.starts_with("__MACOSX/") || base.starts_with('.') {
+        return false;
+    }
+    let lower = base.to_lowercase();
+    lower.ends_with(".pdf") || lower.ends_with(".docx")
+}
+
+/// 按一个 JD 评估候选人简历：评分、必备要求核对和联系方式
+pub struct CandidateScreener;
+
+impl CandidateScreener {
+    pub fn screen(file: &ResumeFile, jd: &str, parsed: &ParsedJobDescription) -> ScreeningCandidate {
+        match AtsChecker::inspect(&file.bytes) {
+            Ok(inspection) => Self::screen_text(&file.name, &inspection.text, jd, parsed),
+            Err(e) => Self::failed(&file.name, e.to_string()),
+        }
+    }
+
+    /// 无法解析的文件，不参与评分
+    pub fn failed(file_name: &str, error: String) -> ScreeningCandidate {
+        ScreeningCandidate {
+            rank: 0,
+            file_name: file_name.to_string(),
+            name: None,
+            email: None,
+            phone: None,
+            overall_score: 0,
+            must_have_passed: false,
+            must_haves: Vec::new(),
+            categories: Vec::new(),
+            total_years: 0.0,
+            matched_skills: Vec::new(),
+            missing_keywords: Vec::new(),
+            error: Some(error),
+        }
+    }
+
+    pub fn screen_text(file_name: &str, text: &str, jd: &str, parsed: &ParsedJobDescription) -> ScreeningCandidate {
+        let doc = ResumeDocument::parse(text);
+        let result = ScoringEngine::score(text, jd);
+        let gaps = RequirementExtractor::evaluate(&doc, &result.timeline, &parsed.requirements);
+
+        let must_haves: Vec<MustHaveCheck> = gaps
+            .iter()
+            .filter(|g| g.requirement.priority == RequirementPriority::Must)
+            .map(|g| MustHaveCheck {
+                requirement: requirement_label(&g.requirement),
+                met: g.met,
+                detail: g.detail.clone(),
+            })
+            .collect();
+        let matched_skills = gaps
+            .iter()
+            .filter(|g| g.met)
+            .filter_map(|g| g.requirement.skill.clone())
+            .collect();
+
+        let contacts = CompletenessChecker::contacts(&doc);
+        let contact = |kind: ContactKind| {
+            contacts
+                .iter()
+                .find(|c| c.kind == kind && c.valid)
+                .map(|c| c.value.clone())
+        };
+
+        ScreeningCandidate {
+            rank: 0,
+            file_name: file_name.to_string(),
+            name: doc.candidate_name(),
+            email: contact(ContactKind::Email),
+            phone: contact(ContactKind::Phone),
+            overall_score: result.overall_score,
+            must_have_passed: must_haves.iter().all(|m| m.met),
+            must_haves,
+            categories: result
+                .categories
+                .into_iter()
+                .map(|c| BatchCategoryScore { key: c.key, score: c.score })
+                .collect(),
+            total_years: result.timeline.total_years,
+            matched_skills,
+            missing_keywords: ScoringEngine::missing_keywords(&doc, jd, &parsed.requirements)
+                .into_iter()
+                .take(MAX_MISSING_KEYWORDS)
+                .collect(),
+            error: None,
+        }
+    }
+
+    /// 通过全部必备要求的在前，再按总分排序；无法解析的文件排在最后
+    pub fn rank(candidates: &mut [ScreeningCandidate]) {
+        candidates.sort_by(|a, b| {
+            (a.error.is_some(), !a.must_have_passed, Reverse(a.overall_score))
+                .cmp(&(b.error.is_some(), !b.must_have_passed, Reverse(b.overall_score)))
+                .then_with(|| a.file_name.cmp(&b.file_name))
+        });
+        for (i, candidate) in candidates.iter_mut().enumerate() {
+            candidate.rank = i + 1;
+        }
+    }
+}
+
+/// 要求的简短说明，如 “Go”“5 年以上经验”“本科及以上”
+fn requirement_label(requirement: &JdRequirement) -> String {
+    match (requirement.kind, &requirement.skill, requirement.min_years, requirement.education) {
+        (RequirementKind::Skill, Some(skill), _, _) => skill.clone(),
+        (RequirementKind::Experience, _, Some(years), _) => format!("{} 年以上经验", years),
+        (RequirementKind::Education, _, _, Some(level)) => format!("{}及以上", level.label()),
+        _ => requirement.source.clone(),
+    }
+}
+
+/// 候选人导出为 CSV，各维度得分各占一列
+pub fn candidates_to_csv(candidates: &[ScreeningCandidate]) -> Result<String, csv::Error> {
+    let category_keys: Vec<String> = candidates
+        .iter()
+        .find(|c| !c.categories.is_empty())
+        .map(|c| c.categories.iter().map(|s| s.key.clone()).collect())
+        .unwrap_or_default();
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    let mut header: Vec<String> = [
+        "rank",
+        "file_name",
+        "name",
+        "email",
+        "phone",
+        "overall_score",
+        "must_have_passed",
+        "unmet_must_haves",
+        "total_years",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    header.extend(category_keys.iter().cloned());
+    header.extend(["matched_skills", "missing_keywords", "error"].iter().map(|s| s.to_string()));
+    writer.write_record(&header)?;
+
+    for candidate in candidates {
+        let mut record = vec![
+            candidate.rank.to_string(),
+            candidate.file_name.clone(),
+            candidate.name.clone().unwrap_or_default(),
+            candidate.email.clone().unwrap_or_default(),
+            candidate.phone.clone().unwrap_or_default(),
+            candidate.overall_score.to_string(),
+            candidate.must_have_passed.to_string(),
+            candidate
+                .must_haves
+                .iter()
+                .filter(|m| !m.met)
+                .map(|m| m.requirement.as_str())
+                .collect::<Vec<_>>()
+                .join("|"),
+            format!("{:.1}", candidate.total_years),
+        ];
+        for key in &category_keys {
+            let score = candidate.categories.iter().find(|s| &s.key == key).map(|s| s.score);
+            record.push(score.map(|s| s.to_string()).unwrap_or_default());
+        }
+        record.push(candidate.matched_skills.join("|"));
+        record.push(candidate.missing_keywords.join("|"));
+        record.push(candidate.error.clone().unwrap_or_default());
+        writer.write_record(&record)?;
+    }
+
+    let bytes = writer.into_inner().map_err(|e| e.into_error())?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+// ========== 后台任务 ==========
+
+pub async fn find_screening_job(
+    pool: &PgPool,
+    user_id: Uuid,
+    job_id: Uuid,
+) -> Result<Option<ScreeningJob>, sqlx::Error> {
+    sqlx::query_as::<_, ScreeningJob>("SELECT * FROM screening_jobs WHERE id = $1 AND user_id = $2")
+        .bind(job_id)
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await
+}
+
+/// 用户排队中和运行中的任务数
+pub async fn count_active_jobs(pool: &PgPool, user_id: Uuid) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar("SELECT COUNT(*) FROM screening_jobs WHERE user_id = $1 AND status IN ($2, $3)")
+        .bind(user_id)
+        .bind(ScreeningStatus::Pending.as_str())
+        .bind(ScreeningStatus::Running.as_str())
+        .fetch_one(pool)
+        .await
+}
+
+/// 启动时把上次进程遗留的未完成任务标记为失败（任务只在内存中运行，重启后无法继续）
+pub async fn fail_interrupted_jobs(pool: &PgPool) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        "UPDATE screening_jobs SET status = $1, error = $2, updated_at = NOW() WHERE status IN ($3, $4)",
+    )
+    .bind(ScreeningStatus::Failed.as_str())
+    .bind("interrupted by restart")
+    .bind(ScreeningStatus::Pending.as_str())
+    .bind(ScreeningStatus::Running.as_str())
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// 后台处理筛选任务：逐份评估简历并更新进度，完成后保存排序结果；出错时记录原因
+pub async fn run_screening(pool: PgPool, job_id: Uuid, jd: String, files: Vec<ResumeFile>) {
+    if let Err(e) = process_screening(&pool, job_id, jd, files).await {
+        tracing::error!("Screening job {} failed: {}", job_id, e);
+        let result = sqlx::query(
+            "UPDATE screening_jobs SET status = $1, error = $2, updated_at = NOW() WHERE id = $3",
+        )
+        .bind(ScreeningStatus::Failed.as_str())
+        .bind(e.to_string())
+        .bind(job_id)
+        .execute(&pool)
+        .await;
+        if let Err(e) = result {
+            tracing::error!("Failed to mark screening job {} as failed: {}", job_id, e);
+        }
+    }
+}
+
+async fn process_screening(
+    pool: &PgPool,
+    job_id: Uuid,
+    jd: String,
+    files: Vec<ResumeFile>,
+) -> Result<(), ScreeningError> {
+    sqlx::query("UPDATE screening_jobs SET status = $1, updated_at = NOW() WHERE id = $2")
+        .bind(ScreeningStatus::Running.as_str())
+        .bind(job_id)
+        .execute(pool)
+        .await?;
+
+    let parsed = Arc::new(JdParser::parse(&jd));
+    let jd: Arc<str> = Arc::from(jd);
+    // 记录任务对应的文件，单个文件解析时 panic 只记为该候选人出错
+    let mut names: HashMap<task::Id, String> = HashMap::new();
+    let spawn = |tasks: &mut JoinSet<ScreeningCandidate>, names: &mut HashMap<task::Id, String>, file: ResumeFile| {
+        let (jd, parsed) = (Arc::clone(&jd), Arc::clone(&parsed));
+        let name = file.name.clone();
+        let handle = tasks.spawn_blocking(move || CandidateScreener::screen(&file, &jd, &parsed));
+        names.insert(handle.id(), name);
+    };
+
+    let mut pending = files.into_iter();
+    let mut tasks = JoinSet::new();
+    for file in pending.by_ref().take(CONCURRENCY) {
+        spawn(&mut tasks, &mut names, file);
+    }
+
+    let mut candidates = Vec::new();
+    while let Some(joined) = tasks.join_next_with_id().await {
+        let candidate = match joined {
+            Ok((_, candidate)) => candidate,
+            Err(e) => {
+                let name = names.get(&e.id()).cloned().unwrap_or_default();
+                tracing::warn!("Screening job {} failed on {}: {}", job_id, name, e);
+                CandidateScreener::failed(&name, "File could not be parsed".to_string())
+            }
+        };
+        candidates.push(candidate);
+        if let Some(file) = pending.next() {
+            spawn(&mut tasks, &mut names, file);
+        }
+        sqlx::query("UPDATE screening_jobs SET processed = $1, updated_at = NOW() WHERE id = $2")
+            .bind(candidates.len() as i32)
+            .bind(job_id)
+            .execute(pool)
+            .await?;
+    }
+
+    CandidateScreener::rank(&mut candidates);
+    sqlx::query(
+        r#"
+        UPDATE screening_jobs
+        SET status = $1, candidates = $2, updated_at = NOW(), completed_at = NOW()
+        WHERE id = $3
+        "#,
+    )
+    .bind(ScreeningStatus::Completed.as_str())
+    .bind(Json(&candidates))
+    .bind(job_id)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,349 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::models::*;
+use crate::services::document::{DocLine, SectionKind};
+use crate::services::{JdParser, ResumeDocument};
+
+/// 职级由低到高的排序
+pub const SENIORITY_RANKS: &[(&str, i32)] = &[
+    ("intern", 0),
+    ("junior", 1),
+    ("mid", 2),
+    ("senior", 3),
+    ("lead", 4),
+    ("staff", 4),
+    ("manager", 4),
+    ("principal", 5),
+];
+
+/// 各排序默认对应的职级名称
+const RANK_NAMES: &[&str] = &["intern", "junior", "mid", "senior", "lead", "principal"];
+
+/// 工作年限对应的职级：（最少年数，职级）
+const YEARS_LEVELS: &[(f32, &str)] = &[(0.0, "junior"), (2.0, "mid"), (5.0, "senior"), (8.0, "staff"), (12.0, "principal")];
+
+/// 范围词：（职级，词），同一行按从高到低取第一个命中的
+const SCOPE_WORDS: &[(&str, &[&str])] = &[
+    ("lead", &[
+        "主导", "带领", "牵头", "架构设计", "技术选型", "技术规划", "统筹", "从0到1", "从 0 到 1", "跨部门", "指导",
+        "培养", "led", "architected", "spearheaded", "mentored", "drove", "roadmap", "cross-functional",
+    ]),
+    ("senior", &["负责", "独立", "设计", "推动", "owned", "designed", "end-to-end", "端到端"]),
+    ("junior", &["参与", "协助", "配合", "辅助", "assisted", "helped", "participated", "supported", "contributed to"]),
+];
+
+/// 范围词至少命中的行数，否则不作为依据
+const MIN_SCOPE_LINES: usize = 2;
+
+/// 合并各依据时的权重
+const SIGNAL_WEIGHTS: &[(SenioritySignalKind, f32)] = &[
+    (SenioritySignalKind::Title, 0.4),
+    (SenioritySignalKind::Years, 0.3),
+    (SenioritySignalKind::Scope, 0.2),
+    (SenioritySignalKind::TeamSize, 0.1),
+];
+
+/// 带领或管理的团队人数，如 “带领 5 人团队”、“managed 8 engineers”、“led a team of 6”
+static TEAM_SIZE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)(?:带领|管理|领导|组建|带)了?(?:一支)?\s*(\d{1,3})\s*(?:人|名|位)|(?:led|lead|leading|managed|manage|managing|built|grew)\s+(?:a\s+)?team\s+of\s+(\d{1,3})|(?:led|managed|mentored|mentoring|managing|leading)\s+(\d{1,3})\+?\s+(?:engineers|developers|people|members|reports|direct reports|designers|analysts)",
+    )
+    .unwrap()
+});
+
+/// 简历和职位的职级推断
+pub struct SeniorityAnalyzer;
+
+impl SeniorityAnalyzer {
+    /// 比较简历与职位的职级，给出差距和修改建议
+    pub fn compare(
+        doc: &ResumeDocument,
+        timeline: &ExperienceTimeline,
+        jd: &ParsedJobDescription,
+        jd_text: &str,
+    ) -> SeniorityReport {
+        let resume = Self::assess_resume(doc, timeline);
+        let job = Self::assess_job(jd, jd_text);
+        let gap = match (job.level.as_deref().and_then(rank), resume.level.as_deref().and_then(rank)) {
+            (Some(target), Some(current)) => target - current,
+            _ => 0,
+        };
+        let suggestions = Self::suggestions(&resume, &job, gap, timeline);
+
+        SeniorityReport {
+            resume,
+            job,
+            gap,
+            suggestions,
+        }
+    }
+
+    pub fn assess_resume(doc: &ResumeDocument, timeline: &ExperienceTimeline) -> SeniorityAssessment {
+        let mut signals = Vec::new();
+
+        // 最近一段经历的职位名称；没有时间线时取工作经历的前几行
+        let recent = timeline.entries.iter().max_by_key(|e| (e.current, e.start.clone()));
+        let title = match recent {
+            Some(entry) => JdParser::infer_seniority(None, &entry.title)
+                .map(|level| (level, format!("最近职位「{}」", entry.title), entry.evidence.clone())),
+            None => doc.section_lines(SectionKind::Experience).take(3).find_map(|line| {
+                JdParser::infer_seniority(None, &line.text)
+                    .map(|level| (level, format!("职位「{}」", line.content()), line.span()))
+            }),
+        };
+        if let Some((level, detail, evidence)) = title {
+            signals.push(signal(SenioritySignalKind::Title, &level, detail, Some(evidence)));
+        }
+
+        if !timeline.entries.is_empty() {
+            signals.push(signal(
+                SenioritySignalKind::Years,
+                years_level(timeline.total_years),
+                format!("累计工作 {:.1} 年", timeline.total_years),
+                None,
+            ));
+        }
+
+        let lines: Vec<&DocLine> = doc
+            .section_lines(SectionKind::Experience)
+            .chain(doc.section_lines(SectionKind::Projects))
+            .chain(doc.section_lines(SectionKind::Summary))
+            .collect();
+        signals.extend(Self::scope(&lines, false));
+        signals.extend(Self::team_size(&lines));
+
+        SeniorityAssessment {
+            level: combine(&signals),
+            signals,
+        }
+    }
+
+    pub fn assess_job(jd: &ParsedJobDescription, jd_text: &str) -> SeniorityAssessment {
+        let doc = ResumeDocument::parse(jd_text);
+        let lines: Vec<&DocLine> = doc.lines().collect();
+        let mut signals = Vec::new();
+
+        // 职位名称中的职级优先，其次是正文中的职级描述
+        let title_level = jd.title.as_deref().and_then(|t| JdParser::infer_seniority(Some(t), ""));
+        match (title_level, jd.seniority.as_deref()) {
+            (Some(level), _) => {
+                let title = jd.title.clone().unwrap_or_default();
+                let evidence = lines.iter().find(|l| l.text.contains(&title)).map(|l| l.span());
+                signals.push(signal(SenioritySignalKind::Title, &level, format!("职位「{}」", title), evidence));
+            }
+            (None, Some(level)) => {
+                let evidence = lines
+                    .iter()
+                    .find(|l| JdParser::infer_seniority(None, &l.text).as_deref() == Some(level))
+                    .map(|l| l.span());
+                signals.push(signal(SenioritySignalKind::Title, level, "JD 中的职级描述".to_string(), evidence));
+            }
+            (None, None) => {}
+        }
+
+        let years = jd
+            .requirements
+            .iter()
+            .filter(|r| r.kind == RequirementKind::Experience)
+            .filter_map(|r| r.min_years.map(|y| (y, r)))
+            .max_by_key(|(y, _)| *y);
+        if let Some((years, requirement)) = years {
+            let source = requirement.source.trim();
+            let evidence = lines
+                .iter()
+                .find(|l| l.text.contains(source) || source.contains(l.content()))
+                .map(|l| l.span());
+            signals.push(signal(
+                SenioritySignalKind::Years,
+                years_level(years as f32),
+                format!("要求 {} 年以上经验", years),
+                evidence,
+            ));
+        }
+
+        // JD 的职责描述普遍用 “负责”，只有带团队、定方向的描述才体现职级
+        signals.extend(Self::scope(&lines, true));
+        signals.extend(Self::team_size(&lines));
+
+        SeniorityAssessment {
+            level: combine(&signals),
+            signals,
+        }
+    }
+
+    // ========== 辅助方法 ==========
+
+    /// 范围词推断的职级；lead_only 时只看主导、带领类的词
+    fn scope(lines: &[&DocLine], lead_only: bool) -> Option<SenioritySignal> {
+        let tiers = if lead_only { &SCOPE_WORDS[..1] } else { SCOPE_WORDS };
+        let mut hits: Vec<(&str, &str, &DocLine)> = Vec::new();
+        for line in lines {
+            let hit = tiers.iter().find_map(|(level, words)| {
+                words
+                    .iter()
+                    .find(|w| JdParser::contains_term(&line.text, w))
+                    .map(|w| (*level, *w, *line))
+            });
+            hits.extend(hit);
+        }
+        if hits.len() < MIN_SCOPE_LINES {
+            return None;
+        }
+
+        let average = hits.iter().filter_map(|(level, _, _)| rank(level)).sum::<i32>() as f32 / hits.len() as f32;
+        let level = RANK_NAMES[(average.round() as usize).min(RANK_NAMES.len() - 1)];
+        let count = |tier: &str| hits.iter().filter(|(l, _, _)| *l == tier).count();
+        let detail = tiers
+            .iter()
+            .filter(|(tier, _)| count(tier) > 0)
+            .map(|(tier, _)| {
+                let word = hits.iter().find(|(l, _, _)| l == tier).map(|(_, w, _)| *w).unwrap_or_default();
+                format!("「{}」等 {} 处", word, count(tier))
+            })
+            .collect::<Vec<_>>()
+            .join("，");
+        // 以职级最高的一行为证据
+        let evidence = hits
+            .iter()
+            .max_by_key(|(l, _, line)| (rank(l), std::cmp::Reverse(line.number)))
+            .map(|(_, _, line)| line.span());
+        Some(signal(SenioritySignalKind::Scope, level, detail, evidence))
+    }
+
+    /// 最大的团队规模；1-2 人视为指导新人，15 人以上视为管理岗
+    fn team_size(lines: &[&DocLine]) -> Option<SenioritySignal> {
+        let (size, line) = lines
+            .iter()
+            .flat_map(|line| {
+                TEAM_SIZE.captures_iter(&line.text).filter_map(move |caps| {
+                    let size: u32 = caps.iter().skip(1).flatten().next()?.as_str().parse().ok()?;
+                    Some((size, *line))
+                })
+            })
+            .max_by_key(|(size, line)| (*size, std::cmp::Reverse(line.number)))?;
+        let level = match size {
+            0 => return None,
+            1..=2 => "senior",
+            3..=14 => "lead",
+            _ => "manager",
+        };
+        Some(signal(SenioritySignalKind::TeamSize, level, format!("带领 {} 人", size), Some(line.span())))
+    }
+
+    fn suggestions(
+        resume: &SeniorityAssessment,
+        job: &SeniorityAssessment,
+        gap: i32,
+        timeline: &ExperienceTimeline,
+    ) -> Vec<String> {
+        let (Some(target), Some(current)) = (job.level.as_deref(), resume.level.as_deref()) else {
+            return match job.level.as_deref() {
+                Some(target) => vec![format!(
+                    "职位要求 {} 级别，简历中未体现职级，建议在职位名称和经历中写明职级与负责范围，如“高级工程师”“主导”“带领 5 人团队”",
+                    target
+                )],
+                None => Vec::new(),
+            };
+        };
+        if gap < 0 {
+            return vec![format!(
+                "简历职级（{}）高于职位要求（{}），可在求职信中说明动机，简历侧重与职位匹配的动手实践",
+                current, target
+            )];
+        }
+        if gap == 0 {
+            return Vec::new();
+        }
+
+        let target_rank = rank(target).unwrap_or_default();
+        let below = |kind: SenioritySignalKind| {
+            resume
+                .signals
+                .iter()
+                .find(|s| s.kind == kind)
+                .map(|s| rank(&s.level).unwrap_or_default() < target_rank)
+        };
+
+        let mut suggestions = vec![format!(
+            "职位要求 {} 级别，简历读起来偏 {}，建议突出主导的项目、决策范围和团队规模",
+            target, current
+        )];
+        if below(SenioritySignalKind::Title) != Some(false) {
+            suggestions.push(format!(
+                "最近的职位名称未体现 {} 级别；如实际承担了更高职责，可在简介中写明，如“技术负责人，负责支付系统架构”",
+                target
+            ));
+        }
+        if below(SenioritySignalKind::Scope) != Some(false) {
+            suggestions.push(
+                "经历要点多为“参与”“协助”，将本人主导的工作改为“主导 / 设计 / 负责”并写明决策范围，如“主导订单系统架构设计，完成技术选型”"
+                    .to_string(),
+            );
+        }
+        if below(SenioritySignalKind::TeamSize) != Some(false) && target_rank >= 4 {
+            suggestions.push("写明带领或指导的团队规模，如“带领 5 人团队”“指导 2 名初级工程师”".to_string());
+        }
+        let job_years = job.signals.iter().find(|s| s.kind == SenioritySignalKind::Years);
+        if let Some(required) = job_years {
+            if below(SenioritySignalKind::Years) == Some(true)
+                && rank(&required.level).unwrap_or_default() > rank(years_level(timeline.total_years)).unwrap_or_default()
+            {
+                suggestions.push(format!(
+                    "工作年限 {:.1} 年，职位{}，可突出项目复杂度、影响范围和成果弥补年限差距",
+                    timeline.total_years, required.detail
+                ));
+            }
+        }
+        suggestions
+    }
+}
+
+pub fn rank(level: &str) -> Option<i32> {
+    SENIORITY_RANKS
+        .iter()
+        .find(|(name, _)| *name == level)
+        .map(|(_, rank)| *rank)
+}
+
+fn years_level(years: f32) -> &'static str {
+    YEARS_LEVELS
+        .iter()
+        .rev()
+        .find(|(min, _)| years >= *min)
+        .map(|(_, level)| *level)
+        .unwrap_or("junior")
+}
+
+/// 按权重合并各依据；与职位名称或团队规模同级时沿用其名称（如 staff、manager）
+fn combine(signals: &[SenioritySignal]) -> Option<String> {
+    let weighted: Vec<(f32, i32)> = signals
+        .iter()
+        .filter_map(|s| {
+            let weight = SIGNAL_WEIGHTS.iter().find(|(k, _)| *k == s.kind)?.1;
+            Some((weight, rank(&s.level)?))
+        })
+        .collect();
+    let total: f32 = weighted.iter().map(|(w, _)| w).sum();
+    if total == 0.0 {
+        return None;
+    }
+    let combined = (weighted.iter().map(|(w, r)| w * *r as f32).sum::<f32>() / total).round() as i32;
+
+    let named = [SenioritySignalKind::Title, SenioritySignalKind::TeamSize]
+        .iter()
+        .filter_map(|kind| signals.iter().find(|s| s.kind == *kind))
+        .find(|s| rank(&s.level) == Some(combined))
+        .map(|s| s.level.clone());
+    named.or_else(|| RANK_NAMES.get(combined.max(0) as usize).map(|n| n.to_string()))
+}
+
+fn signal(kind: SenioritySignalKind, level: &str, detail: String, evidence: Option<EvidenceSpan>) -> SenioritySignal {
+    SenioritySignal {
+        kind,
+        level: level.to_string(),
+        detail,
+        evidence,
+    }
+}
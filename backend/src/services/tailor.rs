@@ -0,0 +1,478 @@
+use std::collections::HashMap;
+use crate::models::*;
+use crate::services::document::{DocLine, ResumeSection, SectionKind};
+use crate::services::relevance::Corpus;
+use crate::services::tokenizer::is_cjk;
+use crate::services::{
+    BulletAnalyzer, BulletRewriter, HallucinationGuard, JdParser, RelevanceScorer, ResumeDocument, SkillTaxonomy,
+};
+
+/// 相关度相差不足该分数的小节或经历保持原顺序
+const SECTION_MARGIN: f32 = 10.0;
+const ENTRY_MARGIN: f32 = 5.0;
+
+/// 简介中最多补充的技能数
+const MAX_SUMMARY_SKILLS: usize = 5;
+
+/// 始终放在最前面的小节
+const PINNED_SECTIONS: &[SectionKind] = &[SectionKind::Header, SectionKind::Contact, SectionKind::Summary];
+
+/// 改写过程中的小节；经历类小节按条目分组
+struct Section {
+    kind: SectionKind,
+    heading: Option<String>,
+    entries: Vec<Vec<String>>,
+}
+
+impl Section {
+    fn label(&self) -> String {
+        section_label(self.heading.as_deref())
+    }
+
+    fn text(&self) -> String {
+        self.entries.iter().flatten().cloned().collect::<Vec<_>>().join("\n")
+    }
+}
+
+/// 按 JD 定制简历：调整小节和经历顺序、改写简介、补充关键词、改写弱动词要点。
+/// 不新增原简历中没有的公司、职位、时间、学历、证书和数字。
+pub struct ResumeTailor;
+
+impl ResumeTailor {
+    pub fn tailor(
+        doc: &ResumeDocument,
+        jd: &ParsedJobDescription,
+        jd_text: &str,
+        timeline: &ExperienceTimeline,
+        corpus: &Corpus,
+    ) -> (String, Vec<ResumeChange>) {
+        let english = is_english(&doc.text);
+        let guard = HallucinationGuard::new(doc);
+        let mut changes: Vec<ResumeChange> = Vec::new();
+
+        // 先按行改写要点，再按小节重组
+        let mut rewritten: HashMap<usize, String> = HashMap::new();
+        for bullet in BulletAnalyzer::analyze(doc).bullets {
+            let Some(replacement) = BulletRewriter::strengthen(&bullet) else {
+                continue;
+            };
+            let Some(line) = doc.lines().find(|l| l.number == bullet.line.line) else {
+                continue;
+            };
+            let content = line.content();
+            let prefix = &line.text[..line.text.find(content).unwrap_or(0)];
+            let change = ResumeChange {
+                kind: ChangeKind::RewriteBullet,
+                section: Self::section_of(doc, line.number),
+                original: Some(content.to_string()),
+                replacement: replacement.clone(),
+                reason: format!(
+                    "「{}」只体现职责或参与，改为体现主导的动词",
+                    bullet.verb.as_deref().unwrap_or_default()
+                ),
+            };
+            if Self::accept(&guard, &mut changes, change) {
+                rewritten.insert(line.number, format!("{}{}", prefix, replacement));
+            }
+        }
+
+        let mut sections = Self::sections(doc, &rewritten);
+        let heading_prefix = Self::heading_prefix(doc);
+
+        Self::add_keywords(doc, jd, english, &heading_prefix, &mut sections, &mut changes);
+        Self::rewrite_summary(doc, jd, timeline, &guard, &heading_prefix, &mut sections, &mut changes);
+
+        for section in sections
+            .iter_mut()
+            .filter(|s| matches!(s.kind, SectionKind::Experience | SectionKind::Projects))
+        {
+            let scores: Vec<f32> = section
+                .entries
+                .iter()
+                .map(|e| RelevanceScorer::score(&e.join("\n"), jd_text, corpus).score)
+                .collect();
+            let order = relevance_order((0..section.entries.len()).collect(), &scores, ENTRY_MARGIN);
+            if order.iter().enumerate().all(|(i, j)| i == *j) {
+                continue;
+            }
+
+            let title = |i: usize| JdParser::strip_bullet(&section.entries[i][0]).to_string();
+            changes.push(ResumeChange {
+                kind: ChangeKind::ReorderEntries,
+                section: section.label(),
+                original: Some((0..order.len()).map(title).collect::<Vec<_>>().join(" / ")),
+                replacement: order.iter().map(|&i| title(i)).collect::<Vec<_>>().join(" / "),
+                reason: format!(
+                    "按与 JD 的相关度排序：{}",
+                    order
+                        .iter()
+                        .map(|&i| format!("{}（{:.0}）", title(i), scores[i]))
+                        .collect::<Vec<_>>()
+                        .join("、")
+                ),
+            });
+            let mut entries: Vec<Option<Vec<String>>> = section.entries.drain(..).map(Some).collect();
+            section.entries = order.iter().filter_map(|&i| entries[i].take()).collect();
+        }
+
+        // 页眉、联系方式和简介之后的小节按相关度排序
+        let pinned = sections.iter().take_while(|s| PINNED_SECTIONS.contains(&s.kind)).count();
+        let scores: Vec<f32> = sections
+            .iter()
+            .map(|s| RelevanceScorer::score(&s.text(), jd_text, corpus).score)
+            .collect();
+        let order = relevance_order((pinned..sections.len()).collect(), &scores, SECTION_MARGIN);
+        if order.iter().enumerate().any(|(i, j)| pinned + i != *j) {
+            let labels = |indexes: &mut dyn Iterator<Item = usize>| {
+                indexes.map(|i| sections[i].label()).collect::<Vec<_>>().join(" → ")
+            };
+            changes.push(ResumeChange {
+                kind: ChangeKind::ReorderSections,
+                section: String::new(),
+                original: Some(labels(&mut (pinned..sections.len()))),
+                replacement: labels(&mut order.iter().copied()),
+                reason: "与 JD 相关度高的小节前移".to_string(),
+            });
+            let mut moved: Vec<Option<Section>> = sections.drain(pinned..).map(Some).collect();
+            sections.extend(order.iter().filter_map(|&i| moved[i - pinned].take()));
+        }
+
+        (Self::render(&sections), changes)
+    }
+
+    /// 把技能补进技能小节（没有时新增），写法与定制简历时补充关键词相同
+    pub fn add_skills(doc: &ResumeDocument, skills: &[String]) -> String {
+        let mut sections = Self::sections(doc, &HashMap::new());
+        Self::append_skills(&mut sections, skills, is_english(&doc.text), &Self::heading_prefix(doc));
+        Self::render(&sections)
+    }
+
+    // ========== 辅助方法 ==========
+
+    /// 补充简历已体现但未按 JD 写法写明的技能：别名写法（如 Golang 之于 Go）和由子技能体现的上级技能
+    fn add_keywords(
+        doc: &ResumeDocument,
+        jd: &ParsedJobDescription,
+        english: bool,
+        heading_prefix: &str,
+        sections: &mut Vec<Section>,
+        changes: &mut Vec<ResumeChange>,
+    ) {
+        let taxonomy = SkillTaxonomy::global();
+        let mut requirements: Vec<&JdRequirement> = jd
+            .requirements
+            .iter()
+            .filter(|r| r.kind == RequirementKind::Skill)
+            .collect();
+        requirements.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+
+        let mut added: Vec<String> = Vec::new();
+        let mut reasons: Vec<String> = Vec::new();
+        for name in requirements.iter().filter_map(|r| r.skill.as_deref()) {
+            if JdParser::contains_term(&doc.text, name) {
+                continue;
+            }
+            if let Some(span) = doc.find_skill(&taxonomy, name) {
+                reasons.push(format!("简历中写作「{}」，补充 JD 中的写法 {}", span.text, name));
+            } else if let Some(child) = taxonomy
+                .descendants(name)
+                .into_iter()
+                .find(|child| doc.find_skill(&taxonomy, &child.name).is_some())
+            {
+                reasons.push(format!("简历中的 {} 体现了 {}", child.name, name));
+            } else {
+                continue;
+            }
+            added.push(name.to_string());
+        }
+        if added.is_empty() {
+            return;
+        }
+
+        let (section, original, replacement) = Self::append_skills(sections, &added, english, heading_prefix);
+        changes.push(ResumeChange {
+            kind: ChangeKind::AddKeywords,
+            section,
+            original,
+            replacement,
+            reason: reasons.join("；"),
+        });
+    }
+
+    /// 技能接在技能小节末行之后，返回小节名称、原行和新行
+    fn append_skills(
+        sections: &mut Vec<Section>,
+        added: &[String],
+        english: bool,
+        heading_prefix: &str,
+    ) -> (String, Option<String>, String) {
+        let separator = if english { ", " } else { "、" };
+        let section = match sections.iter_mut().find(|s| s.kind == SectionKind::Skills) {
+            Some(section) => section,
+            None => {
+                let heading = if english { "Skills" } else { "专业技能" };
+                sections.push(Section {
+                    kind: SectionKind::Skills,
+                    heading: Some(format!("{}{}", heading_prefix, heading)),
+                    entries: vec![Vec::new()],
+                });
+                sections.last_mut().unwrap()
+            }
+        };
+
+        // 末行是逗号分隔的技能列表时接在后面，否则按相同格式新起一行
+        let last = section.entries.iter_mut().flatten().last();
+        let (original, replacement) = match last {
+            Some(line) if !JdParser::strip_bullet(line).is_empty() && (line.contains(',') || line.contains('、')) => {
+                let separator = if line.contains('、') { "、" } else { ", " };
+                let original = line.clone();
+                line.push_str(separator);
+                line.push_str(&added.join(separator));
+                (Some(original), line.clone())
+            }
+            last => {
+                let prefix = last
+                    .map(|l| l[..l.find(JdParser::strip_bullet(l)).unwrap_or(0)].to_string())
+                    .unwrap_or_default();
+                let line = format!("{}{}", prefix, added.join(separator));
+                match section.entries.last_mut() {
+                    Some(entry) => entry.push(line.clone()),
+                    None => section.entries.push(vec![line.clone()]),
+                }
+                (None, line)
+            }
+        };
+
+        (section.label(), original, replacement)
+    }
+
+    /// 在简介中写明简历已体现的 JD 技能；没有简介时新增一段
+    fn rewrite_summary(
+        doc: &ResumeDocument,
+        jd: &ParsedJobDescription,
+        timeline: &ExperienceTimeline,
+        guard: &HallucinationGuard,
+        heading_prefix: &str,
+        sections: &mut Vec<Section>,
+        changes: &mut Vec<ResumeChange>,
+    ) {
+        let taxonomy = SkillTaxonomy::global();
+        let english = is_english(&doc.text);
+        let summary_text: String = doc
+            .section_lines(SectionKind::Summary)
+            .map(|l| l.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut requirements: Vec<&JdRequirement> = jd
+            .requirements
+            .iter()
+            .filter(|r| r.kind == RequirementKind::Skill)
+            .collect();
+        requirements.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+        let skills: Vec<&str> = requirements
+            .iter()
+            .filter_map(|r| r.skill.as_deref())
+            .filter(|name| {
+                let in_summary = taxonomy
+                    .lookup(name)
+                    .is_some_and(|s| taxonomy.find(&summary_text, s).is_some());
+                !in_summary && doc.find_skill(&taxonomy, name).is_some()
+            })
+            .take(MAX_SUMMARY_SKILLS)
+            .collect();
+        if skills.is_empty() {
+            return;
+        }
+        let list = match (english, skills.as_slice()) {
+            (true, [init @ .., last]) if !init.is_empty() => format!("{} and {}", init.join(", "), last),
+            (true, _) => skills.join(", "),
+            (false, _) => skills.join("、"),
+        };
+
+        let index = sections.iter().position(|s| s.kind == SectionKind::Summary);
+        let (original, replacement) = match index {
+            Some(index) => {
+                let sentence = if english {
+                    format!(" Also experienced with {}.", list)
+                } else {
+                    format!("同时熟悉 {}。", list)
+                };
+                let original = sections[index].text();
+                (Some(original.clone()), original + &sentence)
+            }
+            None => {
+                let years = timeline.total_years;
+                let line = match (english, years >= 1.0) {
+                    (true, true) => format!("{} years of experience with {}.", years, list),
+                    (true, false) => format!("Experienced with {}.", list),
+                    (false, true) => format!("约 {} 年工作经验，熟悉 {}。", years, list),
+                    (false, false) => format!("熟悉 {}。", list),
+                };
+                (None, line)
+            }
+        };
+
+        let label = match index {
+            Some(index) => sections[index].label(),
+            None if english => "Summary".to_string(),
+            None => "个人简介".to_string(),
+        };
+        let change = ResumeChange {
+            kind: ChangeKind::RewriteSummary,
+            section: label.clone(),
+            original,
+            replacement: replacement.clone(),
+            reason: format!("在简介中突出简历已体现的 JD 技能：{}", skills.join("、")),
+        };
+        if !Self::accept(guard, changes, change) {
+            return;
+        }
+
+        let lines: Vec<String> = replacement.split('\n').map(str::to_string).collect();
+        match index {
+            Some(index) => sections[index].entries = vec![lines],
+            None => {
+                // 放在页眉和联系方式之后
+                let at = sections
+                    .iter()
+                    .take_while(|s| matches!(s.kind, SectionKind::Header | SectionKind::Contact))
+                    .count();
+                sections.insert(
+                    at,
+                    Section {
+                        kind: SectionKind::Summary,
+                        heading: Some(format!("{}{}", heading_prefix, label)),
+                        entries: vec![lines],
+                    },
+                );
+            }
+        }
+    }
+
+    /// 修改未引入原简历中没有的事实时记录并返回 true
+    fn accept(guard: &HallucinationGuard, changes: &mut Vec<ResumeChange>, change: ResumeChange) -> bool {
+        if let Some(violation) = guard.check(&change.replacement).first() {
+            tracing::debug!("Dropped resume change: {:?} {}", violation.kind, violation.value);
+            return false;
+        }
+        changes.push(change);
+        true
+    }
+
+    /// 经历类小节按条目拆分：项目符号行之后的第一个非项目符号行开始新条目；其他小节整体作为一个条目
+    fn entries(section: &ResumeSection) -> Vec<Vec<&DocLine>> {
+        if !matches!(section.kind, SectionKind::Experience | SectionKind::Projects) {
+            return vec![section.lines.iter().collect()];
+        }
+
+        let mut entries: Vec<Vec<&DocLine>> = Vec::new();
+        for line in &section.lines {
+            let starts_entry = !line.is_bullet()
+                && entries
+                    .last()
+                    .and_then(|e| e.last())
+                    .is_none_or(|previous| previous.is_bullet());
+            match entries.last_mut() {
+                Some(entry) if !starts_entry => entry.push(line),
+                _ => entries.push(vec![line]),
+            }
+        }
+        entries
+    }
+
+    fn section_of(doc: &ResumeDocument, line: usize) -> String {
+        doc.sections
+            .iter()
+            .find(|s| s.lines.iter().any(|l| l.number == line))
+            .map(|s| section_label(s.heading.as_ref().map(|h| h.text.as_str())))
+            .unwrap_or_default()
+    }
+
+    /// 经历类小节按条目分组，rewritten 为改写后的行
+    fn sections(doc: &ResumeDocument, rewritten: &HashMap<usize, String>) -> Vec<Section> {
+        doc.sections
+            .iter()
+            .filter(|s| s.heading.is_some() || !s.lines.is_empty())
+            .map(|s| Section {
+                kind: s.kind,
+                heading: s.heading.as_ref().map(|h| h.text.clone()),
+                entries: Self::entries(s)
+                    .into_iter()
+                    .map(|entry| {
+                        entry
+                            .into_iter()
+                            .map(|l| rewritten.get(&l.number).cloned().unwrap_or_else(|| l.text.clone()))
+                            .collect()
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// 小节标题前的 Markdown 标记，新增小节时沿用
+    fn heading_prefix(doc: &ResumeDocument) -> String {
+        doc.sections
+            .iter()
+            .find_map(|s| s.heading.as_ref())
+            .map(|h| h.text.chars().take_while(|c| *c == '#' || c.is_whitespace()).collect())
+            .unwrap_or_default()
+    }
+
+    /// 小节之间、经历条目之间空一行
+    fn render(sections: &[Section]) -> String {
+        let blocks: Vec<String> = sections
+            .iter()
+            .map(|section| {
+                let body = section
+                    .entries
+                    .iter()
+                    .filter(|e| !e.is_empty())
+                    .map(|e| e.join("\n"))
+                    .collect::<Vec<_>>()
+                    .join(if matches!(section.kind, SectionKind::Experience | SectionKind::Projects) {
+                        "\n\n"
+                    } else {
+                        "\n"
+                    });
+                match &section.heading {
+                    Some(heading) if body.is_empty() => heading.clone(),
+                    Some(heading) => format!("{}\n{}", heading, body),
+                    None => body,
+                }
+            })
+            .collect();
+        blocks.join("\n\n") + "\n"
+    }
+}
+
+/// 去掉 Markdown 标记的小节标题；页眉没有标题
+fn section_label(heading: Option<&str>) -> String {
+    match heading {
+        Some(heading) => heading.trim().trim_start_matches('#').trim().to_string(),
+        None => "页眉".to_string(),
+    }
+}
+
+/// 英文字母远多于汉字时按英文简历处理
+fn is_english(text: &str) -> bool {
+    let cjk = text.chars().filter(|c| is_cjk(*c)).count();
+    let latin = text.chars().filter(|c| c.is_ascii_alphabetic()).count();
+    cjk * 10 < latin
+}
+
+/// 按相关度从高到低排序：只有后一项比前一项高出 margin 及以上时才交换相邻两项，
+/// 相差不足 margin 的保持原顺序
+fn relevance_order(mut order: Vec<usize>, scores: &[f32], margin: f32) -> Vec<usize> {
+    let mut swapped = true;
+    while swapped {
+        swapped = false;
+        for i in 1..order.len() {
+            if scores[order[i]] - scores[order[i - 1]] >= margin {
+                order.swap(i - 1, i);
+                swapped = true;
+            }
+        }
+    }
+    order
+}
@@ -0,0 +1,413 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, LazyLock, RwLock};
+
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::*;
+
+/// 内置技能词典
+const BUNDLED_SKILLS: &str = include_str!("../../data/skills.json");
+
+static GLOBAL: LazyLock<RwLock<Arc<SkillTaxonomy>>> =
+    LazyLock::new(|| RwLock::new(Arc::new(SkillTaxonomy::bundled())));
+
+/// 技能分类体系：标准名、别名、分类和父子关系
+#[derive(Debug, Clone)]
+pub struct SkillTaxonomy {
+    pub version: u32,
+    categories: Vec<CategoryDefinition>,
+    skills: Vec<SkillDefinition>,
+    /// 小写的名称和别名 -> 技能下标
+    index: HashMap<String, usize>,
+}
+
+impl SkillTaxonomy {
+    pub fn new(dictionary: SkillDictionary) -> Self {
+        let mut index = HashMap::new();
+        for (i, skill) in dictionary.skills.iter().enumerate() {
+            // 名称和别名冲突时先出现的优先
+            for term in std::iter::once(&skill.name).chain(&skill.aliases) {
+                index.entry(term.to_lowercase()).or_insert(i);
+            }
+        }
+
+        Self {
+            version: dictionary.version,
+            categories: dictionary.categories,
+            skills: dictionary.skills,
+            index,
+        }
+    }
+
+    /// 内置词典
+    pub fn bundled() -> Self {
+        Self::new(Self::bundled_dictionary())
+    }
+
+    pub fn bundled_dictionary() -> SkillDictionary {
+        serde_json::from_str(BUNDLED_SKILLS).expect("invalid bundled skill dictionary")
+    }
+
+    /// 当前生效的词典
+    pub fn global() -> Arc<SkillTaxonomy> {
+        GLOBAL.read().unwrap().clone()
+    }
+
+    /// 替换当前生效的词典
+    pub fn install(taxonomy: SkillTaxonomy) -> Arc<SkillTaxonomy> {
+        let taxonomy = Arc::new(taxonomy);
+        *GLOBAL.write().unwrap() = taxonomy.clone();
+        taxonomy
+    }
+
+    pub fn skills(&self) -> &[SkillDefinition] {
+        &self.skills
+    }
+
+    /// 导出为词典文件格式
+    pub fn to_dictionary(&self) -> SkillDictionary {
+        SkillDictionary {
+            version: self.version,
+            categories: self.categories.clone(),
+            skills: self.skills.clone(),
+        }
+    }
+
+    /// 按名称或别名查找技能，不区分大小写
+    pub fn lookup(&self, term: &str) -> Option<&SkillDefinition> {
+        self.index.get(&term.trim().to_lowercase()).map(|&i| &self.skills[i])
+    }
+
+    pub fn category(&self, key: &str) -> Option<&CategoryDefinition> {
+        self.categories.iter().find(|c| c.key == key)
+    }
+
+    /// 分类的显示名称，未知分类返回分类键
+    pub fn category_name<'a>(&'a self, key: &'a str) -> &'a str {
+        self.category(key).map(|c| c.name.as_str()).unwrap_or(key)
+    }
+
+    pub fn is_technical(&self, skill: &SkillDefinition) -> bool {
+        self.category(&skill.category).is_none_or(|c| c.technical)
+    }
+
+    /// 所有子孙技能，如 JavaScript -> React -> Next.js
+    pub fn descendants(&self, name: &str) -> Vec<&SkillDefinition> {
+        let mut found: Vec<&SkillDefinition> = Vec::new();
+        let mut seen: HashSet<&str> = HashSet::from([name]);
+        let mut frontier = vec![name];
+
+        while let Some(current) = frontier.pop() {
+            for skill in &self.skills {
+                let is_child = skill
+                    .parent
+                    .as_deref()
+                    .is_some_and(|p| p.eq_ignore_ascii_case(current));
+                if is_child && seen.insert(skill.name.as_str()) {
+                    found.push(skill);
+                    frontier.push(skill.name.as_str());
+                }
+            }
+        }
+
+        found
+    }
+
+    /// 技能在文本中首次出现的位置（字符偏移、字符长度），同一位置取最长的别名
+    pub fn find(&self, text: &str, skill: &SkillDefinition) -> Option<(usize, usize)> {
+        let lower = text.to_lowercase();
+        search_terms(skill)
+            .filter_map(|(term, case_sensitive)| {
+                let (haystack, needle) = if case_sensitive {
+                    (text, term.to_string())
+                } else {
+                    (lower.as_str(), term.to_lowercase())
+                };
+                let first = find_all(haystack, &needle).next();
+                first.map(|i| (haystack[..i].chars().count(), needle.chars().count()))
+            })
+            .min_by_key(|&(start, len)| (start, std::cmp::Reverse(len)))
+    }
+
+    /// 识别文本中的技能，按首次出现顺序返回；被更长的匹配覆盖的片段不计
+    /// （如 "Spring Boot" 中的 "Spring"、"数据分析" 中的 "分析"）
+    pub fn extract(&self, text: &str) -> Vec<&SkillDefinition> {
+        let lower = text.to_lowercase();
+
+        // (字节起点, 字节长度, 技能下标)
+        let mut candidates: Vec<(usize, usize, usize)> = Vec::new();
+        for (i, skill) in self.skills.iter().enumerate() {
+            for (term, case_sensitive) in search_terms(skill) {
+                let (haystack, needle) = if case_sensitive {
+                    (text, term.to_string())
+                } else {
+                    (lower.as_str(), term.to_lowercase())
+                };
+                candidates.extend(find_all(haystack, &needle).map(|start| (start, needle.len(), i)));
+            }
+        }
+        candidates.sort_by_key(|&(start, len, _)| (start, std::cmp::Reverse(len)));
+
+        let mut found: Vec<usize> = Vec::new();
+        let mut covered_until = 0;
+        for (start, len, i) in candidates {
+            if start < covered_until {
+                continue;
+            }
+            covered_until = start + len;
+            if !found.contains(&i) {
+                found.push(i);
+            }
+        }
+
+        found.into_iter().map(|i| &self.skills[i]).collect()
+    }
+}
+
+/// 技能的名称和别名，以及是否区分大小写；case_sensitive 只作用于名称
+fn search_terms(skill: &SkillDefinition) -> impl Iterator<Item = (&str, bool)> {
+    std::iter::once((skill.name.as_str(), skill.case_sensitive))
+        .chain(skill.aliases.iter().map(|a| (a.as_str(), false)))
+}
+
+/// 所有出现位置（字节偏移）；英文别名要求词边界，避免 Go 命中 Google
+fn find_all<'a>(haystack: &'a str, needle: &'a str) -> impl Iterator<Item = usize> + 'a {
+    let needs_boundary = needle.is_ascii();
+    haystack
+        .match_indices(needle)
+        .filter(move |(i, _)| {
+            if needle.is_empty() {
+                return false;
+            }
+            if !needs_boundary {
+                return true;
+            }
+            let before = haystack[..*i].chars().next_back();
+            let after = haystack[i + needle.len()..].chars().next();
+            let joins_before = needle.starts_with(|c: char| c.is_ascii_alphanumeric())
+                && before.is_some_and(|c| c.is_ascii_alphanumeric());
+            let joins_after = needle.ends_with(|c: char| c.is_ascii_alphanumeric())
+                && after.is_some_and(|c| c.is_ascii_alphanumeric());
+            !joins_before && !joins_after
+        })
+        .map(|(i, _)| i)
+}
+
+// ========== CSV 导入导出 ==========
+
+/// CSV 中的一行；别名以 | 分隔
+#[derive(Debug, Serialize, Deserialize)]
+struct SkillCsvRecord {
+    name: String,
+    category: String,
+    parent: Option<String>,
+    importance: Option<u8>,
+    case_sensitive: Option<bool>,
+    aliases: Option<String>,
+}
+
+/// 技能导出为 CSV（分类只在 JSON 格式中导出）
+pub fn skills_to_csv(skills: &[SkillDefinition]) -> Result<String, csv::Error> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for skill in skills {
+        writer.serialize(SkillCsvRecord {
+            name: skill.name.clone(),
+            category: skill.category.clone(),
+            parent: skill.parent.clone(),
+            importance: Some(skill.importance),
+            case_sensitive: Some(skill.case_sensitive),
+            aliases: Some(skill.aliases.join("|")),
+        })?;
+    }
+
+    let bytes = writer.into_inner().map_err(|e| e.into_error())?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+pub fn skills_from_csv(content: &str) -> Result<Vec<SkillDefinition>, csv::Error> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(content.as_bytes());
+
+    reader
+        .deserialize::<SkillCsvRecord>()
+        .map(|record| {
+            let record = record?;
+            Ok(SkillDefinition {
+                name: record.name,
+                category: record.category,
+                parent: record.parent.filter(|p| !p.is_empty()),
+                importance: record.importance.unwrap_or(60),
+                case_sensitive: record.case_sensitive.unwrap_or(false),
+                aliases: record
+                    .aliases
+                    .unwrap_or_default()
+                    .split('|')
+                    .map(str::trim)
+                    .filter(|a| !a.is_empty())
+                    .map(String::from)
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+// ========== 词典持久化 ==========
+
+/// 技能及其别名
+pub const SKILL_SELECT: &str = r#"
+    SELECT s.*,
+        COALESCE(
+            array_agg(a.alias::TEXT ORDER BY a.alias) FILTER (WHERE a.id IS NOT NULL),
+            ARRAY[]::TEXT[]
+        ) AS aliases
+    FROM skills s
+    LEFT JOIN skill_aliases a ON a.skill_id = s.id
+"#;
+
+/// 内置词典合并数据库中的覆盖：同名分类和技能以数据库为准，disabled 的技能被移除
+pub async fn load_taxonomy(pool: &PgPool) -> Result<SkillTaxonomy, sqlx::Error> {
+    let categories = sqlx::query_as::<_, SkillCategory>("SELECT * FROM skill_categories ORDER BY key")
+        .fetch_all(pool)
+        .await?;
+
+    let skills = sqlx::query_as::<_, Skill>(&format!("{} GROUP BY s.id ORDER BY s.name", SKILL_SELECT))
+        .fetch_all(pool)
+        .await?;
+
+    let mut dictionary = SkillTaxonomy::bundled_dictionary();
+
+    for category in categories {
+        let definition = CategoryDefinition {
+            key: category.key,
+            name: category.name,
+            technical: category.technical,
+        };
+        match dictionary.categories.iter_mut().find(|c| c.key == definition.key) {
+            Some(existing) => *existing = definition,
+            None => dictionary.categories.push(definition),
+        }
+    }
+
+    for skill in skills {
+        dictionary.skills.retain(|s| !s.name.eq_ignore_ascii_case(&skill.name));
+        if skill.disabled {
+            continue;
+        }
+        // 数据库中的别名优先，从内置技能中移除同名别名
+        for alias in &skill.aliases {
+            for existing in dictionary.skills.iter_mut() {
+                existing.aliases.retain(|a| !a.eq_ignore_ascii_case(alias));
+            }
+        }
+        dictionary.skills.insert(0, SkillDefinition {
+            name: skill.name,
+            category: skill.category,
+            aliases: skill.aliases,
+            parent: skill.parent,
+            importance: skill.importance.clamp(0, 100) as u8,
+            case_sensitive: skill.case_sensitive,
+        });
+    }
+
+    Ok(SkillTaxonomy::new(dictionary))
+}
+
+/// 从数据库重新加载并替换当前生效的词典
+pub async fn reload_taxonomy(pool: &PgPool) -> Result<Arc<SkillTaxonomy>, sqlx::Error> {
+    let taxonomy = load_taxonomy(pool).await?;
+    Ok(SkillTaxonomy::install(taxonomy))
+}
+
+/// 查询数据库中的某个技能
+pub async fn find_skill(pool: &PgPool, skill_id: Uuid) -> Result<Option<Skill>, sqlx::Error> {
+    sqlx::query_as::<_, Skill>(&format!("{} WHERE s.id = $1 GROUP BY s.id", SKILL_SELECT))
+        .bind(skill_id)
+        .fetch_optional(pool)
+        .await
+}
+
+/// 导入词典：分类按 key、技能按名称（不区分大小写）覆盖，技能的别名整体替换；
+/// 别名已属于其他技能时改归当前技能
+pub async fn import_dictionary(
+    pool: &PgPool,
+    categories: &[CategoryDefinition],
+    skills: &[SkillDefinition],
+) -> Result<ImportSkillsResponse, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    let mut result = ImportSkillsResponse::default();
+
+    for category in categories {
+        sqlx::query(
+            r#"
+            INSERT INTO skill_categories (key, name, technical)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (key) DO UPDATE SET name = EXCLUDED.name, technical = EXCLUDED.technical
+            "#,
+        )
+        .bind(&category.key)
+        .bind(&category.name)
+        .bind(category.technical)
+        .execute(&mut *tx)
+        .await?;
+        result.categories += 1;
+    }
+
+    for skill in skills {
+        let skill_id: Uuid = sqlx::query_scalar(
+            r#"
+            INSERT INTO skills (id, name, category, parent, importance, case_sensitive)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT ((LOWER(name))) DO UPDATE SET
+                name = EXCLUDED.name,
+                category = EXCLUDED.category,
+                parent = EXCLUDED.parent,
+                importance = EXCLUDED.importance,
+                case_sensitive = EXCLUDED.case_sensitive,
+                disabled = FALSE
+            RETURNING id
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(&skill.name)
+        .bind(&skill.category)
+        .bind(&skill.parent)
+        .bind(skill.importance as i16)
+        .bind(skill.case_sensitive)
+        .fetch_one(&mut *tx)
+        .await?;
+        result.skills += 1;
+
+        sqlx::query("DELETE FROM skill_aliases WHERE skill_id = $1")
+            .bind(skill_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let mut seen = HashSet::new();
+        for alias in &skill.aliases {
+            let key = alias.to_lowercase();
+            if alias.trim().is_empty() || key == skill.name.to_lowercase() || !seen.insert(key) {
+                continue;
+            }
+            sqlx::query(
+                r#"
+                INSERT INTO skill_aliases (id, skill_id, alias)
+                VALUES ($1, $2, $3)
+                ON CONFLICT ((LOWER(alias))) DO UPDATE SET skill_id = EXCLUDED.skill_id, alias = EXCLUDED.alias
+                "#,
+            )
+            .bind(Uuid::new_v4())
+            .bind(skill_id)
+            .bind(alias.trim())
+            .execute(&mut *tx)
+            .await?;
+            result.aliases += 1;
+        }
+    }
+
+    tx.commit().await?;
+
+    Ok(result)
+}
@@ -0,0 +1,241 @@
+use std::sync::LazyLock;
+
+use chrono::Datelike;
+use regex::Regex;
+
+use crate::models::*;
+use crate::services::document::{DocLine, SectionKind};
+use crate::services::{ResumeDocument, SkillTaxonomy};
+
+/// 超过该月数的间隔视为空档
+const GAP_MONTHS: u32 = 3;
+
+/// 超过该月数的重叠才提示，允许交接期
+const OVERLAP_MONTHS: u32 = 2;
+
+/// 单个日期：2019.03、2019年3月、2019-03、Mar 2019、03/2019、2019
+const DATE: &str = r"(?:(?:jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)[a-z]*\.?\s*(?:19|20)\d{2}|\d{1,2}\s*/\s*(?:19|20)\d{2}|(?:19|20)\d{2}(?:\s*[./\-年]\s*\d{1,2})?(?:\s*月)?)";
+
+/// 任职时间段，如 2019.03 - 至今、Mar 2019 – Present
+pub static DATE_RANGE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)({DATE})\s*(?:-|–|—|~|～|至|到|to)\s*({DATE}|至今|现在|今|present|now|current)"
+    ))
+    .unwrap()
+});
+
+/// 单个日期的年、月
+pub static DATE_PARTS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)^(?:(?P<name>[a-z]{3})[a-z]*\.?\s*(?P<y1>\d{4})|(?P<m2>\d{1,2})\s*/\s*(?P<y2>\d{4})|(?P<y3>\d{4})(?:\s*[./\-年]\s*(?P<m3>\d{1,2}))?)",
+    )
+    .unwrap()
+});
+
+pub const MONTH_NAMES: &[&str] = &["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+
+/// 以月为单位的时间段，month = 年 * 12 + 月 - 1，两端都包含
+#[derive(Debug, Clone, Copy)]
+struct Period {
+    start: i32,
+    end: i32,
+}
+
+impl Period {
+    fn months(&self) -> u32 {
+        (self.end - self.start + 1).max(0) as u32
+    }
+}
+
+/// 工作经历时间线分析
+pub struct TimelineAnalyzer;
+
+impl TimelineAnalyzer {
+    pub fn analyze(doc: &ResumeDocument) -> ExperienceTimeline {
+        let taxonomy = SkillTaxonomy::global();
+        let now = chrono::Utc::now().date_naive();
+        let current_month = now.year() * 12 + now.month0() as i32;
+
+        // 每段经历从日期行开始，到下一个日期行之前结束
+        let lines: Vec<&DocLine> = doc.section_lines(SectionKind::Experience).collect();
+        let mut entries: Vec<(Period, TimelineEntry)> = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            let Some((period, current, range)) = Self::parse_range(&line.text, current_month) else {
+                continue;
+            };
+
+            let block_end = lines[i + 1..]
+                .iter()
+                .position(|l| DATE_RANGE.is_match(&l.text))
+                .map(|p| i + 1 + p)
+                .unwrap_or(lines.len());
+            let block: Vec<&str> = lines[i..block_end].iter().map(|l| l.text.as_str()).collect();
+            let skills = taxonomy
+                .extract(&block.join("\n"))
+                .into_iter()
+                .filter(|skill| taxonomy.is_technical(skill))
+                .map(|skill| skill.name.clone())
+                .collect();
+
+            let title = line.text.replace(&range, "");
+            let title = title.trim_matches(|c: char| c.is_whitespace() || "|｜·,，-–—()（）[]【】".contains(c));
+            // 日期单独成行时，以上一行作为标题
+            let title = match (title.is_empty(), i.checked_sub(1)) {
+                (true, Some(prev)) => lines[prev].content().to_string(),
+                _ => title.to_string(),
+            };
+
+            entries.push((
+                period,
+                TimelineEntry {
+                    title,
+                    start: format_month(period.start),
+                    end: (!current).then(|| format_month(period.end)),
+                    current,
+                    months: period.months(),
+                    skills,
+                    evidence: line.span(),
+                },
+            ));
+        }
+        entries.sort_by_key(|(period, _)| period.start);
+
+        let periods: Vec<Period> = entries.iter().map(|(p, _)| *p).collect();
+        let merged = merge(&periods);
+        let total_months: u32 = merged.iter().map(Period::months).sum();
+
+        let gaps = merged
+            .windows(2)
+            .map(|pair| Period {
+                start: pair[0].end + 1,
+                end: pair[1].start - 1,
+            })
+            .chain(merged.last().map(|last| Period {
+                start: last.end + 1,
+                end: current_month,
+            }))
+            .filter(|gap| gap.months() > GAP_MONTHS)
+            .map(|gap| TimelineGap {
+                start: format_month(gap.start),
+                end: format_month(gap.end),
+                months: gap.months(),
+            })
+            .collect();
+
+        let mut overlaps = Vec::new();
+        for (i, (a, first)) in entries.iter().enumerate() {
+            for (b, second) in &entries[i + 1..] {
+                let overlap = Period {
+                    start: a.start.max(b.start),
+                    end: a.end.min(b.end),
+                };
+                if overlap.months() >= OVERLAP_MONTHS {
+                    overlaps.push(TimelineOverlap {
+                        first: first.title.clone(),
+                        second: second.title.clone(),
+                        start: format_month(overlap.start),
+                        end: format_month(overlap.end),
+                        months: overlap.months(),
+                    });
+                }
+            }
+        }
+
+        let mut skill_years: Vec<SkillYears> = Vec::new();
+        for (_, entry) in &entries {
+            for skill in &entry.skills {
+                if skill_years.iter().any(|s| &s.skill == skill) {
+                    continue;
+                }
+                let periods: Vec<Period> = entries
+                    .iter()
+                    .filter(|(_, e)| e.skills.contains(skill))
+                    .map(|(p, _)| *p)
+                    .collect();
+                let months: u32 = merge(&periods).iter().map(Period::months).sum();
+                skill_years.push(SkillYears {
+                    skill: skill.clone(),
+                    years: to_years(months),
+                });
+            }
+        }
+        skill_years.sort_by(|a, b| b.years.total_cmp(&a.years));
+
+        ExperienceTimeline {
+            entries: entries.into_iter().map(|(_, e)| e).collect(),
+            total_years: to_years(total_months),
+            skill_years,
+            gaps,
+            overlaps,
+        }
+    }
+
+    /// 某项技能（含子技能）的使用年限；经历中未出现时为 None
+    pub fn skill_years(timeline: &ExperienceTimeline, skill: &str) -> Option<f32> {
+        let taxonomy = SkillTaxonomy::global();
+        let mut names = vec![skill.to_string()];
+        names.extend(taxonomy.descendants(skill).iter().map(|s| s.name.clone()));
+
+        timeline
+            .skill_years
+            .iter()
+            .filter(|s| names.iter().any(|n| n.eq_ignore_ascii_case(&s.skill)))
+            .map(|s| s.years)
+            .max_by(f32::total_cmp)
+    }
+
+    // ========== 辅助方法 ==========
+
+    /// 行中第一个时间段：（时间段，是否至今，原文）
+    fn parse_range(text: &str, current_month: i32) -> Option<(Period, bool, String)> {
+        let caps = DATE_RANGE.captures(text)?;
+        let start = parse_month(&caps[1], false)?;
+        let end_text = caps[2].to_lowercase();
+        let current = ["至今", "现在", "今", "present", "now", "current"].contains(&end_text.as_str());
+        let end = if current { current_month } else { parse_month(&caps[2], true)? };
+
+        (end >= start).then(|| (Period { start, end }, current, caps[0].to_string()))
+    }
+}
+
+/// 解析单个日期为月序号；未写月份时，开始取 1 月，结束取 12 月
+fn parse_month(text: &str, is_end: bool) -> Option<i32> {
+    let caps = DATE_PARTS.captures(text.trim())?;
+    let default_month = if is_end { 12 } else { 1 };
+
+    let (year, month) = if let Some(name) = caps.name("name") {
+        let name = name.as_str().to_lowercase();
+        let month = MONTH_NAMES.iter().position(|m| *m == name)? as i32 + 1;
+        (caps["y1"].parse().ok()?, month)
+    } else if let Some(month) = caps.name("m2") {
+        (caps["y2"].parse().ok()?, month.as_str().parse().ok()?)
+    } else {
+        let month = caps.name("m3").and_then(|m| m.as_str().parse().ok()).unwrap_or(default_month);
+        (caps["y3"].parse::<i32>().ok()?, month)
+    };
+
+    (1..=12).contains(&month).then(|| year * 12 + month - 1)
+}
+
+fn format_month(month: i32) -> String {
+    format!("{}-{:02}", month.div_euclid(12), month.rem_euclid(12) + 1)
+}
+
+fn to_years(months: u32) -> f32 {
+    (months as f32 / 12.0 * 10.0).round() / 10.0
+}
+
+/// 合并重叠或相邻的时间段
+fn merge(periods: &[Period]) -> Vec<Period> {
+    let mut sorted = periods.to_vec();
+    sorted.sort_by_key(|p| p.start);
+
+    let mut merged: Vec<Period> = Vec::new();
+    for period in sorted {
+        match merged.last_mut() {
+            Some(last) if period.start <= last.end + 1 => last.end = last.end.max(period.end),
+            _ => merged.push(period),
+        }
+    }
+    merged
+}
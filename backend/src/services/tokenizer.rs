@@ -0,0 +1,277 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+/// 内置中文词典
+const ZH_WORDS: &str = include_str!("../../data/dict/zh_words.txt");
+/// 内置技术术语用户词典
+const TECH_TERMS: &str = include_str!("../../data/dict/tech_terms.txt");
+const STOPWORDS_ZH: &str = include_str!("../../data/dict/stopwords_zh.txt");
+const STOPWORDS_EN: &str = include_str!("../../data/dict/stopwords_en.txt");
+
+/// 额外用户词典文件的环境变量，格式与 tech_terms.txt 相同
+const USER_DICT_ENV: &str = "TOKENIZER_USER_DICT";
+
+static GLOBAL: LazyLock<Tokenizer> = LazyLock::new(|| {
+    let mut tokenizer = Tokenizer::new(ZH_WORDS, TECH_TERMS, &[STOPWORDS_ZH, STOPWORDS_EN]);
+
+    if let Ok(path) = std::env::var(USER_DICT_ENV) {
+        match std::fs::read_to_string(&path) {
+            Ok(content) => tokenizer.add_terms(&content),
+            Err(e) => tracing::warn!("Failed to load user dictionary {}: {}", path, e),
+        }
+    }
+
+    tokenizer
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// 中文词典词或单字
+    Word,
+    /// 用户词典中的技术术语
+    Term,
+    /// 英文单词
+    Latin,
+    Number,
+}
+
+#[derive(Debug, Clone)]
+pub struct Token {
+    /// 原文中的写法
+    pub text: String,
+    pub kind: TokenKind,
+}
+
+impl Token {
+    /// 用于比较的小写形式
+    pub fn normalized(&self) -> String {
+        self.text.to_lowercase()
+    }
+}
+
+/// 基于词典的中英文混合分词器
+pub struct Tokenizer {
+    words: HashSet<String>,
+    max_word_len: usize,
+    /// 按首字符（小写）索引的术语，长的在前
+    terms: HashMap<char, Vec<Vec<char>>>,
+    term_set: HashSet<String>,
+    stop_words: HashSet<String>,
+}
+
+impl Tokenizer {
+    pub fn new(words: &str, terms: &str, stop_words: &[&str]) -> Self {
+        let mut tokenizer = Self {
+            words: HashSet::new(),
+            max_word_len: 1,
+            terms: HashMap::new(),
+            term_set: HashSet::new(),
+            stop_words: stop_words
+                .iter()
+                .flat_map(|list| Self::dict_lines(list))
+                .map(|w| w.to_lowercase())
+                .collect(),
+        };
+
+        for word in Self::dict_lines(words) {
+            tokenizer.add_word(word);
+        }
+        tokenizer.add_terms(terms);
+
+        tokenizer
+    }
+
+    /// 全局分词器（内置词典 + 环境变量指定的用户词典）
+    pub fn global() -> &'static Tokenizer {
+        &GLOBAL
+    }
+
+    /// 加入用户词典；纯中文术语并入分词词典，其余按整体匹配
+    pub fn add_terms(&mut self, content: &str) {
+        for term in Self::dict_lines(content) {
+            let lower = term.to_lowercase();
+            if lower.chars().all(is_cjk) {
+                self.add_word(&lower);
+            } else {
+                let chars: Vec<char> = lower.chars().collect();
+                let bucket = self.terms.entry(chars[0]).or_default();
+                if !bucket.contains(&chars) {
+                    bucket.push(chars);
+                    bucket.sort_by_key(|t| std::cmp::Reverse(t.len()));
+                }
+            }
+            self.term_set.insert(lower);
+        }
+    }
+
+    /// 是否为用户词典中的技术术语
+    pub fn is_term(&self, word: &str) -> bool {
+        self.term_set.contains(&word.to_lowercase())
+    }
+
+    pub fn is_stop_word(&self, word: &str) -> bool {
+        self.stop_words.contains(&word.to_lowercase())
+    }
+
+    /// 分词，跳过空白和标点
+    pub fn tokenize(&self, text: &str) -> Vec<Token> {
+        let chars: Vec<char> = text.chars().collect();
+        let lower: Vec<char> = chars.iter().map(|c| c.to_ascii_lowercase()).collect();
+        let mut tokens = Vec::new();
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+
+            if is_cjk(c) {
+                let end = (i..chars.len()).find(|&j| !is_cjk(chars[j])).unwrap_or(chars.len());
+                for word in self.segment(&chars[i..end]) {
+                    let kind = if self.term_set.contains(&word) { TokenKind::Term } else { TokenKind::Word };
+                    tokens.push(Token { text: word, kind });
+                }
+                i = end;
+                continue;
+            }
+
+            if let Some(len) = self.match_term(&lower, i) {
+                tokens.push(Token {
+                    text: chars[i..i + len].iter().collect(),
+                    kind: TokenKind::Term,
+                });
+                i += len;
+                continue;
+            }
+
+            if c.is_alphanumeric() {
+                let end = latin_end(&chars, i);
+                let text: String = chars[i..end].iter().collect();
+                let kind = if text.chars().all(|c| c.is_ascii_digit() || c == '.') {
+                    TokenKind::Number
+                } else {
+                    TokenKind::Latin
+                };
+                tokens.push(Token { text, kind });
+                i = end;
+                continue;
+            }
+
+            i += 1;
+        }
+
+        tokens
+    }
+
+    /// 是否可作为关键词：去掉停用词、数字和单字
+    pub fn is_keyword(&self, token: &Token) -> bool {
+        match token.kind {
+            TokenKind::Number => false,
+            TokenKind::Term => !self.is_stop_word(&token.text),
+            // 以数字开头的英文词多为薪资、日期等（如 30k-50k）
+            TokenKind::Latin if token.text.starts_with(|c: char| c.is_ascii_digit()) => false,
+            TokenKind::Word | TokenKind::Latin => {
+                token.text.chars().count() > 1 && !self.is_stop_word(&token.text)
+            }
+        }
+    }
+
+    // ========== 辅助方法 ==========
+
+    fn add_word(&mut self, word: &str) {
+        self.max_word_len = self.max_word_len.max(word.chars().count());
+        self.words.insert(word.to_string());
+    }
+
+    /// 去掉注释和空行
+    fn dict_lines(content: &str) -> impl Iterator<Item = &str> {
+        content
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+    }
+
+    /// 从 start 处匹配最长的术语，返回字符数；英文边界需完整
+    fn match_term(&self, lower: &[char], start: usize) -> Option<usize> {
+        let candidates = self.terms.get(&lower[start])?;
+        let before = start.checked_sub(1).map(|i| lower[i]);
+
+        candidates.iter().find_map(|term| {
+            let end = start + term.len();
+            if end > lower.len() || lower[start..end] != term[..] {
+                return None;
+            }
+            if term[0].is_ascii_alphanumeric() && before.is_some_and(|c| c.is_ascii_alphanumeric()) {
+                return None;
+            }
+            let after = lower.get(end);
+            if term[term.len() - 1].is_ascii_alphanumeric() && after.is_some_and(|c| c.is_ascii_alphanumeric()) {
+                return None;
+            }
+            Some(term.len())
+        })
+    }
+
+    /// 双向最大匹配：取词数较少的结果，其次取单字较少的，仍相同时取逆向结果
+    fn segment(&self, chars: &[char]) -> Vec<String> {
+        let forward = self.forward_max_match(chars);
+        let backward = self.backward_max_match(chars);
+
+        let singles = |words: &[String]| words.iter().filter(|w| w.chars().count() == 1).count();
+        if forward.len() < backward.len()
+            || (forward.len() == backward.len() && singles(&forward) < singles(&backward))
+        {
+            forward
+        } else {
+            backward
+        }
+    }
+
+    fn forward_max_match(&self, chars: &[char]) -> Vec<String> {
+        let mut words = Vec::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let max = self.max_word_len.min(chars.len() - i);
+            let len = (1..=max)
+                .rev()
+                .find(|&len| len == 1 || self.words.contains(&chars[i..i + len].iter().collect::<String>()))
+                .unwrap_or(1);
+            words.push(chars[i..i + len].iter().collect());
+            i += len;
+        }
+        words
+    }
+
+    fn backward_max_match(&self, chars: &[char]) -> Vec<String> {
+        let mut words = Vec::new();
+        let mut end = chars.len();
+        while end > 0 {
+            let max = self.max_word_len.min(end);
+            let len = (1..=max)
+                .rev()
+                .find(|&len| len == 1 || self.words.contains(&chars[end - len..end].iter().collect::<String>()))
+                .unwrap_or(1);
+            words.push(chars[end - len..end].iter().collect());
+            end -= len;
+        }
+        words.reverse();
+        words
+    }
+}
+
+pub fn is_cjk(c: char) -> bool {
+    matches!(c, '\u{4e00}'..='\u{9fff}' | '\u{3400}'..='\u{4dbf}' | '\u{f900}'..='\u{faff}')
+}
+
+/// 英文/数字词的结束位置；词内的 . - _ 后面紧跟字母数字时视为词的一部分（如 e-commerce、3.5）
+fn latin_end(chars: &[char], start: usize) -> usize {
+    let mut end = start;
+    while end < chars.len() {
+        let c = chars[end];
+        let is_word_char = |c: char| c.is_alphanumeric() && !is_cjk(c);
+        let joins_next = matches!(c, '.' | '-' | '_') && chars.get(end + 1).is_some_and(|n| is_word_char(*n));
+        if !is_word_char(c) && !joins_next {
+            break;
+        }
+        end += 1;
+    }
+    end
+}
@@ -0,0 +1,543 @@
+use std::sync::LazyLock;
+
+use regex::{Captures, Regex};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::models::*;
+use crate::services::analysis::{BUILTIN_MODEL, BUILTIN_PROVIDER};
+use crate::services::document::SectionKind;
+use crate::services::lint::DatePoint;
+use crate::services::llm::{extract_json, LlmClient};
+use crate::services::proofread::is_english_word;
+use crate::services::timeline::DATE_RANGE;
+use crate::services::tokenizer::is_cjk;
+use crate::services::{JdParser, Redactor, ResumeDocument, SkillTaxonomy};
+
+/// 内置译名词表
+const BUNDLED_GLOSSARY: &str = include_str!("../../data/translation_glossary.json");
+
+/// 自定义词表文件的环境变量，格式与 translation_glossary.json 相同；
+/// 文件中的中文词条替换内置译名，其他词条沿用内置词表
+const GLOSSARY_ENV: &str = "TRANSLATION_GLOSSARY_PATH";
+
+/// 小节标题的标准写法（中文，英文）
+const SECTION_TITLES: &[(SectionKind, &str, &str)] = &[
+    (SectionKind::Contact, "联系方式", "Contact Information"),
+    (SectionKind::Summary, "个人简介", "Summary"),
+    (SectionKind::Experience, "工作经历", "Work Experience"),
+    (SectionKind::Projects, "项目经历", "Projects"),
+    (SectionKind::Education, "教育背景", "Education"),
+    (SectionKind::Skills, "专业技能", "Skills"),
+    (SectionKind::Certifications, "证书", "Certifications"),
+    (SectionKind::Awards, "获奖经历", "Awards"),
+];
+
+/// 职位级别前缀，{} 为词表中的职位名称
+const TITLE_LEVELS: &[(&str, &str)] = &[
+    ("高级", "Senior {}"),
+    ("资深", "Senior {}"),
+    ("初级", "Junior {}"),
+    ("首席", "Principal {}"),
+    ("实习", "{} Intern"),
+];
+
+/// 同一行中的字段分隔符，如 “字节跳动 | 高级后端工程师 | 2021.03 - 至今”
+static SEPARATOR: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\s*[|｜]\s*").unwrap());
+
+/// “标签：值” 形式的字段
+static LABELED: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^([^:：]{1,12}?)\s*[:：]\s*(.*)$").unwrap());
+
+/// 发给 LLM 前替换术语的标记，如 [TERM_1]
+static TERM_TOKEN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)\[term_(\d+)\]").unwrap());
+
+const SYSTEM_PROMPT: &str = "你是专业的简历翻译。请把每个片段翻译为 target 指定的语言（zh-CN 为简体中文，en-US 为美式英文），\
+使用该语言简历的地道写法：经历要点以动作动词开头、简洁有力，不增删信息，保留数字、百分比和技术名词。\
+[TERM_1]、[PHONE_1] 等方括号标记代表已处理的术语或个人信息，请原样保留。\
+只输出 JSON，格式为 {\"segments\":[{\"id\":0,\"text\":\"译文\"}]}。";
+
+static GLOBAL: LazyLock<TranslationGlossary> = LazyLock::new(|| {
+    let mut glossary = TranslationGlossary::from_json(BUNDLED_GLOSSARY).expect("invalid bundled translation glossary");
+
+    if let Ok(path) = std::env::var(GLOSSARY_ENV) {
+        match std::fs::read_to_string(&path) {
+            Ok(content) => match TranslationGlossary::from_json(&content) {
+                Ok(custom) => glossary.merge(custom),
+                Err(e) => tracing::warn!("Invalid translation glossary {}: {}", path, e),
+            },
+            Err(e) => tracing::warn!("Failed to load translation glossary {}: {}", path, e),
+        }
+    }
+
+    glossary
+});
+
+#[derive(Debug, Clone, Deserialize)]
+struct GlossaryEntry {
+    zh: String,
+    en: String,
+    kind: TermKind,
+}
+
+#[derive(Deserialize)]
+struct GlossaryFile {
+    entries: Vec<GlossaryEntry>,
+}
+
+/// 职位、学历、学校、专业、公司和字段名的标准译名
+#[derive(Debug, Clone, Default)]
+pub struct TranslationGlossary {
+    entries: Vec<GlossaryEntry>,
+}
+
+impl TranslationGlossary {
+    pub fn from_json(content: &str) -> Result<Self, serde_json::Error> {
+        let file: GlossaryFile = serde_json::from_str(content)?;
+        Ok(Self { entries: file.entries })
+    }
+
+    pub fn global() -> &'static TranslationGlossary {
+        &GLOBAL
+    }
+
+    /// 原文的译名和类别；英译中时同一英文对应多个中文的，取词表中靠前的一条
+    pub fn lookup(&self, text: &str, source: Language) -> Option<(&str, TermKind)> {
+        let text = text.trim();
+        self.entries
+            .iter()
+            .find(|e| match source {
+                Language::ZhCn => e.zh == text,
+                Language::EnUs => e.en.eq_ignore_ascii_case(text),
+            })
+            .map(|e| match source {
+                Language::ZhCn => (e.en.as_str(), e.kind),
+                Language::EnUs => (e.zh.as_str(), e.kind),
+            })
+    }
+
+    fn merge(&mut self, custom: TranslationGlossary) {
+        self.entries.retain(|e| !custom.entries.iter().any(|c| c.zh == e.zh));
+        self.entries.extend(custom.entries);
+    }
+}
+
+/// 按中文字符和英文字母的比例判断简历语言；中文信息密度高，一个汉字按三个字母计
+pub fn detect_language(text: &str) -> Language {
+    let cjk = text.chars().filter(|c| is_cjk(*c)).count();
+    let latin = text.chars().filter(|c| c.is_ascii_alphabetic()).count();
+    if cjk > 0 && cjk * 3 >= latin {
+        Language::ZhCn
+    } else {
+        Language::EnUs
+    }
+}
+
+/// 一行译文的组成部分
+enum Piece {
+    Text(String),
+    /// 需要整句翻译的片段，值为 sentences 中的下标
+    Sentence(usize),
+}
+
+struct Sentence {
+    /// 所在行号（从 1 开始）
+    line: usize,
+    original: String,
+    /// 已转换日期、替换术语并脱敏，发给 LLM 的文本
+    prepared: String,
+}
+
+#[derive(Deserialize)]
+struct LlmReply {
+    segments: Vec<LlmSegment>,
+}
+
+#[derive(Deserialize)]
+struct LlmSegment {
+    id: usize,
+    text: String,
+}
+
+/// 简历中英互译：保持行结构，标题、职位、学历、学校等按词表翻译，
+/// 锁定的术语保持原文，其余句子交给 LLM；未配置或调用失败时保留原文
+pub struct ResumeTranslator<'a> {
+    source: Language,
+    target: Language,
+    glossary: &'a TranslationGlossary,
+    locked_terms: &'a [String],
+    custom: &'a [GlossaryTerm],
+    terms: Vec<TranslatedTerm>,
+    sentences: Vec<Sentence>,
+    /// 句子中替换为 [TERM_n] 的译名，下标为 n - 1
+    protected: Vec<String>,
+}
+
+impl<'a> ResumeTranslator<'a> {
+    pub async fn translate(
+        resume: &str,
+        target: Language,
+        locked_terms: &[String],
+        custom: &[GlossaryTerm],
+    ) -> TranslationResult {
+        let mut translator = ResumeTranslator {
+            source: detect_language(resume),
+            target,
+            glossary: TranslationGlossary::global(),
+            locked_terms,
+            custom,
+            terms: Vec::new(),
+            sentences: Vec::new(),
+            protected: Vec::new(),
+        };
+
+        let lines: Vec<Vec<Piece>> = resume
+            .lines()
+            .enumerate()
+            .map(|(i, line)| translator.plan_line(i + 1, line))
+            .collect();
+
+        let mut redactor = Redactor::new(&[]);
+        redactor.redact_resume(resume);
+        for sentence in &mut translator.sentences {
+            sentence.prepared = redactor.redact_fragment(&sentence.prepared);
+        }
+
+        let client = LlmClient::global();
+        let translated = match client {
+            Some(client) if !translator.sentences.is_empty() => {
+                translator.llm_translate(client, redactor.mapping()).await
+            }
+            _ => Vec::new(),
+        };
+
+        let mut untranslated: Vec<usize> = Vec::new();
+        let text = lines
+            .into_iter()
+            .map(|pieces| {
+                pieces
+                    .into_iter()
+                    .map(|piece| match piece {
+                        Piece::Text(text) => text,
+                        Piece::Sentence(i) => match translated.get(i).cloned().flatten() {
+                            Some(text) => text,
+                            None => {
+                                let sentence = &translator.sentences[i];
+                                if !untranslated.contains(&sentence.line) {
+                                    untranslated.push(sentence.line);
+                                }
+                                sentence.original.clone()
+                            }
+                        },
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let (provider, model) = match client {
+            Some(client) if translated.iter().any(|t| t.is_some()) => {
+                (client.provider().to_string(), client.model().to_string())
+            }
+            _ => (BUILTIN_PROVIDER.to_string(), BUILTIN_MODEL.to_string()),
+        };
+
+        TranslationResult {
+            text,
+            source: translator.source,
+            target,
+            terms: translator.terms,
+            untranslated,
+            provider,
+            model,
+        }
+    }
+
+    // ========== 辅助方法 ==========
+
+    /// 拆分一行：保留项目符号和分隔符，逐段翻译
+    fn plan_line(&mut self, number: usize, line: &str) -> Vec<Piece> {
+        if line.trim().is_empty() {
+            return vec![Piece::Text(line.to_string())];
+        }
+        if let Some(heading) = self.heading(line) {
+            return vec![Piece::Text(heading)];
+        }
+
+        let content = JdParser::strip_bullet(line);
+        let start = line.find(content).unwrap_or(0);
+        let mut pieces = vec![Piece::Text(line[..start].to_string())];
+        let mut last = 0;
+        for separator in SEPARATOR.find_iter(content) {
+            pieces.extend(self.segment(number, &content[last..separator.start()]));
+            pieces.push(Piece::Text(separator.as_str().to_string()));
+            last = separator.end();
+        }
+        pieces.extend(self.segment(number, &content[last..]));
+        pieces.push(Piece::Text(line[start + content.len()..].to_string()));
+        pieces
+    }
+
+    /// 小节标题换成目标语言的标准写法，保留 # 和括号
+    fn heading(&mut self, line: &str) -> Option<String> {
+        let kind = ResumeDocument::heading_kind(line)?;
+        let (_, zh, en) = SECTION_TITLES.iter().find(|(k, _, _)| *k == kind)?;
+        let core = line
+            .trim()
+            .trim_start_matches('#')
+            .trim()
+            .trim_matches(|c| matches!(c, '【' | '】' | '[' | ']'))
+            .trim_end_matches([':', '：'])
+            .trim();
+        let start = line.find(core)?;
+        let (prefix, suffix) = (&line[..start], &line[start + core.len()..]);
+        let (title, suffix) = match self.target {
+            Language::ZhCn => (*zh, suffix.replace(':', "：")),
+            Language::EnUs => (*en, suffix.replace('：', ":")),
+        };
+        self.record(core, title, TermKind::Heading);
+        Some(format!("{}{}{}", prefix, title, suffix))
+    }
+
+    fn segment(&mut self, line: usize, text: &str) -> Vec<Piece> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Vec::new();
+        }
+        if let Some(term) = self.term(text) {
+            return vec![Piece::Text(term)];
+        }
+        if DATE_RANGE.find(text).is_some_and(|m| m.len() == text.len()) {
+            return vec![Piece::Text(self.convert_dates(text))];
+        }
+        if let Some(caps) = LABELED.captures(text) {
+            let value = caps.get(2).map_or("", |m| m.as_str());
+            if let Some(label) = self.term(&caps[1]) {
+                let colon = match self.target {
+                    Language::ZhCn => "：",
+                    Language::EnUs => ": ",
+                };
+                let mut pieces = vec![Piece::Text(format!("{}{}", label, colon))];
+                pieces.extend(self.segment(line, value));
+                return pieces;
+            }
+        }
+        if !self.needs_translation(text) {
+            return vec![Piece::Text(self.convert_dates(text))];
+        }
+
+        let prepared = self.protect(&self.convert_dates(text));
+        self.sentences.push(Sentence {
+            line,
+            original: text.to_string(),
+            prepared,
+        });
+        vec![Piece::Sentence(self.sentences.len() - 1)]
+    }
+
+    /// 整段匹配的术语：锁定术语、请求中的译名、内置词表，最后尝试 “级别 + 职位”
+    fn term(&mut self, text: &str) -> Option<String> {
+        if let Some(locked) = self.locked_terms.iter().find(|t| t.trim().eq_ignore_ascii_case(text)) {
+            let locked = locked.trim().to_string();
+            self.record(text, &locked, TermKind::Locked);
+            return Some(locked);
+        }
+        if let Some(custom) = self.custom.iter().find(|t| t.source.trim().eq_ignore_ascii_case(text)) {
+            let target = custom.target.trim().to_string();
+            self.record(text, &target, TermKind::Custom);
+            return Some(target);
+        }
+        if let Some((target, kind)) = self.glossary.lookup(text, self.source) {
+            let target = target.to_string();
+            self.record(text, &target, kind);
+            return Some(target);
+        }
+        let title = self.compose_title(text)?;
+        self.record(text, &title, TermKind::Title);
+        Some(title)
+    }
+
+    /// 词表中没有的带级别职位，如 “资深数据工程师” → Senior Data Engineer
+    fn compose_title(&self, text: &str) -> Option<String> {
+        let base_title = |base: &str| {
+            self.glossary
+                .lookup(base, self.source)
+                .filter(|(_, kind)| *kind == TermKind::Title)
+                .map(|(title, _)| title)
+        };
+        TITLE_LEVELS.iter().find_map(|(zh, en)| match self.source {
+            Language::ZhCn => {
+                let base = base_title(text.strip_prefix(zh)?)?;
+                Some(en.replace("{}", base))
+            }
+            Language::EnUs => {
+                let (before, after) = en.split_once("{}")?;
+                let lower = text.to_lowercase();
+                if lower.len() <= before.len() + after.len()
+                    || !lower.starts_with(&before.to_lowercase())
+                    || !lower.ends_with(&after.to_lowercase())
+                {
+                    return None;
+                }
+                let base = base_title(&text[before.len()..text.len() - after.len()])?;
+                Some(format!("{}{}", zh, base))
+            }
+        })
+    }
+
+    /// 含源语言正文的片段才需要翻译；英文中只有技能名的（如 Rust, Go）保持原样
+    fn needs_translation(&self, text: &str) -> bool {
+        match self.source {
+            Language::ZhCn => text.chars().any(is_cjk),
+            Language::EnUs => {
+                let taxonomy = SkillTaxonomy::global();
+                text.split(|c: char| !c.is_ascii_alphabetic())
+                    .filter(|w| w.len() > 1)
+                    .any(|w| {
+                        let lower = w.to_lowercase();
+                        is_english_word(&lower) && taxonomy.lookup(&lower).is_none()
+                    })
+            }
+        }
+    }
+
+    /// 时间段统一为目标语言的写法：Mar 2021 – Present / 2021.03 - 至今
+    fn convert_dates(&self, text: &str) -> String {
+        let (style, separator, ongoing) = match self.target {
+            Language::ZhCn => (DateStyle::Dotted, " - ", "至今"),
+            Language::EnUs => (DateStyle::MonthName, " – ", "Present"),
+        };
+        DATE_RANGE
+            .replace_all(text, |caps: &Captures| {
+                let Some(from) = DatePoint::parse(&caps[1]) else {
+                    return caps[0].to_string();
+                };
+                let to = DatePoint::parse(&caps[2])
+                    .map(|p| p.render(style))
+                    .unwrap_or_else(|| ongoing.to_string());
+                format!("{}{}{}", from.render(style), separator, to)
+            })
+            .into_owned()
+    }
+
+    /// 句子中的锁定术语和词表译名替换为 [TERM_n]，翻译后换回，避免 LLM 改写
+    fn protect(&mut self, text: &str) -> String {
+        let mut replacements: Vec<(String, String, TermKind)> = Vec::new();
+        replacements.extend(self.locked_terms.iter().map(|t| (t.trim().to_string(), t.trim().to_string(), TermKind::Locked)));
+        replacements.extend(
+            self.custom
+                .iter()
+                .map(|t| (t.source.trim().to_string(), t.target.trim().to_string(), TermKind::Custom)),
+        );
+        replacements.extend(
+            self.glossary
+                .entries
+                .iter()
+                .filter(|e| e.kind != TermKind::Label)
+                .map(|e| match self.source {
+                    Language::ZhCn => (e.zh.clone(), e.en.clone(), e.kind),
+                    Language::EnUs => (e.en.clone(), e.zh.clone(), e.kind),
+                }),
+        );
+        // 长的先替换，避免 “后端工程师” 抢先匹配 “高级后端工程师”
+        replacements.retain(|(source, _, _)| source.chars().count() > 1);
+        replacements.sort_by_key(|(source, _, _)| std::cmp::Reverse(source.chars().count()));
+
+        let mut text = text.to_string();
+        for (source, target, kind) in replacements {
+            let index = self.protected.iter().position(|t| *t == target).unwrap_or(self.protected.len());
+            let Some(replaced) = replace_term(&text, &source, &format!("[TERM_{}]", index + 1)) else {
+                continue;
+            };
+            if index == self.protected.len() {
+                self.protected.push(target.clone());
+            }
+            text = replaced;
+            self.record(&source, &target, kind);
+        }
+        text
+    }
+
+    /// 一次请求翻译全部句子，按 id 对应；缺少或无法解析的句子为 None
+    async fn llm_translate(&self, client: &LlmClient, mapping: &[RedactionEntry]) -> Vec<Option<String>> {
+        let segments: Vec<serde_json::Value> = self
+            .sentences
+            .iter()
+            .enumerate()
+            .map(|(i, s)| json!({ "id": i, "text": s.prepared }))
+            .collect();
+        let user = json!({ "target": self.target, "segments": segments }).to_string();
+
+        let reply = match client.chat(SYSTEM_PROMPT, &user).await {
+            Ok(reply) => reply,
+            Err(e) => {
+                tracing::warn!("Resume translation via LLM failed, keeping original text: {}", e);
+                return Vec::new();
+            }
+        };
+        let parsed = match serde_json::from_str::<LlmReply>(extract_json(&reply)) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                tracing::warn!("Unparseable LLM translation reply, keeping original text: {}", e);
+                return Vec::new();
+            }
+        };
+
+        let mut translated = vec![None; self.sentences.len()];
+        for segment in parsed.segments {
+            let Some(slot) = translated.get_mut(segment.id) else {
+                continue;
+            };
+            let text = TERM_TOKEN.replace_all(&segment.text, |caps: &Captures| {
+                caps[1]
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|n| self.protected.get(n.wrapping_sub(1)))
+                    .cloned()
+                    .unwrap_or_else(|| caps[0].to_string())
+            });
+            let text = Redactor::restore(&text, mapping).0;
+            if !text.trim().is_empty() {
+                *slot = Some(text.trim().to_string());
+            }
+        }
+        translated
+    }
+
+    fn record(&mut self, source: &str, target: &str, kind: TermKind) {
+        let term = TranslatedTerm {
+            source: source.to_string(),
+            target: target.to_string(),
+            kind,
+        };
+        if !self.terms.contains(&term) {
+            self.terms.push(term);
+        }
+    }
+}
+
+/// 替换文本中出现的术语，没有出现时返回 None；英文术语要求词边界，
+/// 与 JdParser::contains_term 一致，避免 Go 命中 Google、Architect 命中 Architected
+fn replace_term(text: &str, term: &str, replacement: &str) -> Option<String> {
+    let needs_boundary = term.is_ascii();
+    let mut replaced = String::with_capacity(text.len());
+    let mut last = 0;
+    let mut found = false;
+    for (i, _) in text.match_indices(term) {
+        if needs_boundary {
+            let before = text[..i].chars().next_back();
+            let after = text[i + term.len()..].chars().next();
+            if before.is_some_and(|c| c.is_ascii_alphanumeric()) || after.is_some_and(|c| c.is_ascii_alphanumeric()) {
+                continue;
+            }
+        }
+        replaced.push_str(&text[last..i]);
+        replaced.push_str(replacement);
+        last = i + term.len();
+        found = true;
+    }
+    if !found {
+        return None;
+    }
+    replaced.push_str(&text[last..]);
+    Some(replaced)
+}
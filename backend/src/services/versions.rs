@@ -0,0 +1,245 @@
+use similar::{ChangeTag, TextDiff};
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::models::*;
+
+pub struct VersionDiffer;
+
+impl VersionDiffer {
+    /// 对比两个版本的内容，按行或按词输出差异
+    pub fn diff(old: &str, new: &str, granularity: DiffGranularity) -> (Vec<DiffChunk>, DiffStats) {
+        match granularity {
+            DiffGranularity::Line => Self::diff_lines(old, new),
+            DiffGranularity::Word => Self::diff_words(old, new),
+        }
+    }
+
+    fn diff_lines(old: &str, new: &str) -> (Vec<DiffChunk>, DiffStats) {
+        let diff = TextDiff::from_lines(old, new);
+        let mut chunks = Vec::new();
+        let mut stats = DiffStats { insertions: 0, deletions: 0, unchanged: 0 };
+
+        for change in diff.iter_all_changes() {
+            let tag = Self::map_tag(change.tag());
+            match tag {
+                DiffTag::Insert => stats.insertions += 1,
+                DiffTag::Delete => stats.deletions += 1,
+                DiffTag::Equal => stats.unchanged += 1,
+            }
+
+            chunks.push(DiffChunk {
+                tag,
+                text: change.value().trim_end_matches('\n').to_string(),
+                old_line: change.old_index().map(|i| i + 1),
+                new_line: change.new_index().map(|i| i + 1),
+            });
+        }
+
+        (chunks, stats)
+    }
+
+    fn diff_words(old: &str, new: &str) -> (Vec<DiffChunk>, DiffStats) {
+        let old_tokens = Self::split_words(old);
+        let new_tokens = Self::split_words(new);
+        let diff = TextDiff::from_slices(&old_tokens, &new_tokens);
+
+        let old_lines = Self::line_numbers(&old_tokens);
+        let new_lines = Self::line_numbers(&new_tokens);
+
+        let mut chunks: Vec<DiffChunk> = Vec::new();
+        let mut stats = DiffStats { insertions: 0, deletions: 0, unchanged: 0 };
+
+        for change in diff.iter_all_changes() {
+            let tag = Self::map_tag(change.tag());
+            let value = change.value();
+
+            // 空白不计入统计
+            if !value.trim().is_empty() {
+                match tag {
+                    DiffTag::Insert => stats.insertions += 1,
+                    DiffTag::Delete => stats.deletions += 1,
+                    DiffTag::Equal => stats.unchanged += 1,
+                }
+            }
+
+            // 合并相邻的同类片段
+            if let Some(last) = chunks.last_mut() {
+                if last.tag == tag {
+                    last.text.push_str(value);
+                    continue;
+                }
+            }
+
+            chunks.push(DiffChunk {
+                tag,
+                text: value.to_string(),
+                old_line: change.old_index().map(|i| old_lines[i]),
+                new_line: change.new_index().map(|i| new_lines[i]),
+            });
+        }
+
+        (chunks, stats)
+    }
+
+    fn map_tag(tag: ChangeTag) -> DiffTag {
+        match tag {
+            ChangeTag::Equal => DiffTag::Equal,
+            ChangeTag::Insert => DiffTag::Insert,
+            ChangeTag::Delete => DiffTag::Delete,
+        }
+    }
+
+    /// 拆分为词：连续的字母数字为一个词，中文按单字，空白和标点各自独立
+    fn split_words(text: &str) -> Vec<&str> {
+        let mut tokens = Vec::new();
+        let mut start: Option<usize> = None;
+
+        for (i, c) in text.char_indices() {
+            let is_word_char = c.is_ascii_alphanumeric() || c == '_';
+            if is_word_char {
+                if start.is_none() {
+                    start = Some(i);
+                }
+                continue;
+            }
+
+            if let Some(s) = start.take() {
+                tokens.push(&text[s..i]);
+            }
+            tokens.push(&text[i..i + c.len_utf8()]);
+        }
+
+        if let Some(s) = start {
+            tokens.push(&text[s..]);
+        }
+
+        tokens
+    }
+
+    /// 每个词所在的行号（从 1 开始）
+    fn line_numbers(tokens: &[&str]) -> Vec<usize> {
+        let mut line = 1;
+        tokens
+            .iter()
+            .map(|token| {
+                let current = line;
+                line += token.matches('\n').count();
+                current
+            })
+            .collect()
+    }
+}
+
+// ========== 版本持久化 ==========
+
+/// 追加一个新版本，并将其设为简历的当前版本
+pub async fn append_version(
+    pool: &PgPool,
+    resume_id: Uuid,
+    source: VersionSource,
+    content: &str,
+    note: Option<&str>,
+) -> Result<ResumeVersion, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    let version = append_version_in(&mut tx, resume_id, source, content, note).await?;
+    tx.commit().await?;
+
+    Ok(version)
+}
+
+/// 在调用方的事务内追加版本，由调用方负责提交
+pub async fn append_version_in(
+    tx: &mut Transaction<'_, Postgres>,
+    resume_id: Uuid,
+    source: VersionSource,
+    content: &str,
+    note: Option<&str>,
+) -> Result<ResumeVersion, sqlx::Error> {
+    // 锁定简历行，保证版本号递增
+    let parent_id: Option<Uuid> = sqlx::query_scalar(
+        "SELECT current_version_id FROM resumes WHERE id = $1 FOR UPDATE",
+    )
+    .bind(resume_id)
+    .fetch_one(&mut **tx)
+    .await?;
+
+    let version_number: i32 = sqlx::query_scalar(
+        "SELECT COALESCE(MAX(version_number), 0) + 1 FROM resume_versions WHERE resume_id = $1",
+    )
+    .bind(resume_id)
+    .fetch_one(&mut **tx)
+    .await?;
+
+    let version = sqlx::query_as::<_, ResumeVersion>(
+        r#"
+        INSERT INTO resume_versions (id, resume_id, parent_id, version_number, source, content, note)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING *
+        "#,
+    )
+    .bind(Uuid::new_v4())
+    .bind(resume_id)
+    .bind(parent_id)
+    .bind(version_number)
+    .bind(source.as_str())
+    .bind(content)
+    .bind(note)
+    .fetch_one(&mut **tx)
+    .await?;
+
+    sqlx::query("UPDATE resumes SET current_version_id = $1 WHERE id = $2")
+        .bind(version.id)
+        .bind(resume_id)
+        .execute(&mut **tx)
+        .await?;
+
+    Ok(version)
+}
+
+/// 查询属于指定用户的简历
+pub async fn find_resume(
+    pool: &PgPool,
+    user_id: Uuid,
+    resume_id: Uuid,
+) -> Result<Option<Resume>, sqlx::Error> {
+    sqlx::query_as::<_, Resume>("SELECT * FROM resumes WHERE id = $1 AND user_id = $2")
+        .bind(resume_id)
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await
+}
+
+/// 查询简历下的某个版本
+pub async fn find_version(
+    pool: &PgPool,
+    resume_id: Uuid,
+    version_id: Uuid,
+) -> Result<Option<ResumeVersion>, sqlx::Error> {
+    sqlx::query_as::<_, ResumeVersion>(
+        "SELECT * FROM resume_versions WHERE id = $1 AND resume_id = $2",
+    )
+    .bind(version_id)
+    .bind(resume_id)
+    .fetch_optional(pool)
+    .await
+}
+
+/// 查询属于指定用户的任意简历版本
+pub async fn find_user_version(
+    pool: &PgPool,
+    user_id: Uuid,
+    version_id: Uuid,
+) -> Result<Option<ResumeVersion>, sqlx::Error> {
+    sqlx::query_as::<_, ResumeVersion>(
+        r#"
+        SELECT v.* FROM resume_versions v
+        JOIN resumes r ON r.id = v.resume_id
+        WHERE v.id = $1 AND r.user_id = $2
+        "#,
+    )
+    .bind(version_id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await
+}
@@ -1,5 +1,7 @@
+use axum::http::{HeaderMap, StatusCode};
 use jsonwebtoken::{encode, EncodingKey, Header};
 use chrono::{Duration, Utc};
+use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::models::user::{Claims, User};
@@ -37,3 +39,47 @@ pub fn verify_jwt(token: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
     )
     .map(|data| data.claims)
 }
+
+/// 从 Authorization 请求头中解析并校验 JWT
+pub fn claims_from_headers(headers: &HeaderMap) -> Result<Claims, (StatusCode, String)> {
+    let auth_header = headers
+        .get("Authorization")
+        .and_then(|h| h.to_str().ok())
+        .ok_or((StatusCode::UNAUTHORIZED, "Missing auth token".to_string()))?;
+
+    let token = auth_header
+        .strip_prefix("Bearer ")
+        .ok_or((StatusCode::UNAUTHORIZED, "Invalid auth header".to_string()))?;
+
+    verify_jwt(token).map_err(|e| (StatusCode::UNAUTHORIZED, e.to_string()))
+}
+
+/// 当前登录用户 ID
+pub fn user_id_from_headers(headers: &HeaderMap) -> Result<Uuid, (StatusCode, String)> {
+    claims_from_headers(headers).map(|claims| claims.sub)
+}
+
+/// 可选登录：未携带 Authorization 时返回 None，携带但无效时报错
+pub fn optional_user_id_from_headers(headers: &HeaderMap) -> Result<Option<Uuid>, (StatusCode, String)> {
+    if headers.contains_key("Authorization") {
+        user_id_from_headers(headers).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// 当前登录用户必须是管理员；以数据库为准，撤销权限立即生效
+pub async fn require_admin(pool: &PgPool, headers: &HeaderMap) -> Result<Uuid, (StatusCode, String)> {
+    let user_id = user_id_from_headers(headers)?;
+
+    let is_admin: Option<bool> = sqlx::query_scalar("SELECT is_admin FROM users WHERE id = $1")
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    match is_admin {
+        Some(true) => Ok(user_id),
+        _ => Err((StatusCode::FORBIDDEN, "Admin access required".to_string())),
+    }
+}
@@ -1,9 +1,18 @@
 import { useState } from 'react'
 import axios from 'axios'
 
+interface ResumeChange {
+  kind: string
+  section: string
+  original: string | null
+  replacement: string
+  reason: string
+}
+
 interface OptimizeResponse {
   optimized_resume: string
-  changes: string[]
+  changes: ResumeChange[]
+  suggestions: string[]
   match_score: number
 }
 
@@ -93,12 +102,34 @@ function ResumeOptimizer() {
 
             {/* Changes */}
             <div>
-              <h3 className="font-semibold text-gray-900 mb-2">优化建议</h3>
+              <h3 className="font-semibold text-gray-900 mb-2">修改记录</h3>
               <ul className="space-y-2">
                 {result.changes.map((change, index) => (
                   <li key={index} className="flex items-start">
                     <span className="text-primary-600 mr-2">•</span>
-                    <span className="text-gray-700">{change}</span>
+                    <div className="text-gray-700">
+                      <div>
+                        {change.section && <span className="font-medium">[{change.section}] </span>}
+                        {change.reason}
+                      </div>
+                      {change.original && (
+                        <div className="text-sm text-gray-400 line-through">{change.original}</div>
+                      )}
+                      <div className="text-sm text-green-700">{change.replacement}</div>
+                    </div>
+                  </li>
+                ))}
+              </ul>
+            </div>
+
+            {/* Suggestions */}
+            <div>
+              <h3 className="font-semibold text-gray-900 mb-2">优化建议</h3>
+              <ul className="space-y-2">
+                {result.suggestions.map((suggestion, index) => (
+                  <li key={index} className="flex items-start">
+                    <span className="text-primary-600 mr-2">•</span>
+                    <span className="text-gray-700">{suggestion}</span>
                   </li>
                 ))}
               </ul>